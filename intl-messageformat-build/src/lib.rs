@@ -0,0 +1,129 @@
+//! Generate typed Rust functions for the messages of a catalog from a build script, so that
+//! invalid messages fail the build instead of the formatting at runtime:
+//!
+//! ```ignore
+//! // build.rs
+//! fn main() {
+//!     intl_messageformat_build::compile_catalog("locales/en.json", &Default::default());
+//! }
+//!
+//! // src/messages.rs
+//! include!(concat!(env!("OUT_DIR"), "/en.rs"));
+//!
+//! // src/main.rs
+//! mod messages;
+//!
+//! fn main() {
+//!     println!("{}", messages::app_inbox(3.0, |children| format!("**{}**", children)));
+//! }
+//! ```
+//!
+//! The crate must also depend on `intl-messageformat-parser-rs`, which formats the messages.
+//! See `generate_rust_module` for the functions that are generated.
+
+use intl_messageformat_parser_rs::{
+    generate_rust_module, render_diagnostic, DiagnosticOptions, RustError, RustOptions,
+};
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// Generate the functions of the catalog at the path into `$OUT_DIR`, in a file named after the
+/// catalog, e.g. `en.rs` for `locales/en.json`. The catalog is a JSON object of message ids to
+/// messages.
+///
+/// This is meant to be called from a build script: the build is rerun when the catalog changes,
+/// and fails with the diagnostics of the messages if any of them is invalid.
+pub fn compile_catalog(path: impl AsRef<Path>, options: &RustOptions) {
+    let path = path.as_ref();
+    println!("cargo:rerun-if-changed={}", path.display());
+    let result = generate(path, options).and_then(|source| {
+        let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR should be set by cargo");
+        let file_name = Path::new(path.file_stem().unwrap_or_default()).with_extension("rs");
+        let out_path = Path::new(&out_dir).join(file_name);
+        fs::write(&out_path, source).map_err(|error| BuildError::Io { path: out_path, error })
+    });
+    if let Err(error) = result {
+        eprint!("{}", error);
+        process::exit(1);
+    }
+}
+
+/// Read the catalog at the path and generate the source of its functions.
+pub fn generate(path: impl AsRef<Path>, options: &RustOptions) -> Result<String, BuildError> {
+    let path = path.as_ref();
+    let contents = fs::read_to_string(path)
+        .map_err(|error| BuildError::Io { path: path.to_path_buf(), error })?;
+    let catalog: BTreeMap<String, String> = serde_json::from_str(&contents)
+        .map_err(|error| BuildError::Json { path: path.to_path_buf(), error })?;
+    generate_rust_module(catalog, options)
+        .map_err(|errors| BuildError::Messages { path: path.to_path_buf(), errors })
+}
+
+#[derive(Debug)]
+pub enum BuildError {
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    /// The catalog is not a JSON object of message ids to messages.
+    Json {
+        path: PathBuf,
+        error: serde_json::Error,
+    },
+    /// Some messages of the catalog are invalid.
+    Messages {
+        path: PathBuf,
+        errors: Vec<RustError>,
+    },
+}
+
+/// Messages are reported like `intl-messageformat validate` does, with a trailing summary.
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::Io { path, error } => writeln!(f, "{}: {}", path.display(), error),
+            BuildError::Json { path, error } => writeln!(f, "{}: {}", path.display(), error),
+            BuildError::Messages { path, errors } => {
+                for error in errors {
+                    match error {
+                        RustError::Parse { id, error } => {
+                            writeln!(f, "{}: {}", path.display(), id)?;
+                            writeln!(
+                                f,
+                                "{}",
+                                render_diagnostic(error, &DiagnosticOptions::default())
+                            )?;
+                        }
+                        RustError::ConflictingArgument { id, argument } => {
+                            writeln!(f, "{}: {}", path.display(), id)?;
+                            writeln!(
+                                f,
+                                "error: argument `{}` is used as values of different kinds\n",
+                                argument.name
+                            )?;
+                        }
+                        RustError::UnsupportedStyle { id, name, style } => {
+                            writeln!(f, "{}: {}", path.display(), id)?;
+                            writeln!(
+                                f,
+                                "error: argument `{}` cannot be formatted with the style `{}`\n",
+                                name, style
+                            )?;
+                        }
+                    }
+                }
+                match errors.len() {
+                    1 => writeln!(f, "found 1 error"),
+                    count => writeln!(f, "found {} errors", count),
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
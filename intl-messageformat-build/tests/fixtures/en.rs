@@ -0,0 +1,119 @@
+// Generated from the message catalog. Do not edit.
+
+const LOCALE: &str = "en";
+
+/// Format the message with the values, parsing it the first time. The message was parsed
+/// when it was generated and the values have the types of its arguments, so this only fails
+/// when no option of a `select` or `plural` matches, and only panics if the parser of
+/// `intl_messageformat_parser_rs` changed since.
+fn format_message(
+    ast: &'static ::std::sync::OnceLock<::intl_messageformat_parser_rs::Ast<'static>>,
+    message: &'static str,
+    values: Vec<(&str, ::intl_messageformat_parser_rs::FormatValue)>,
+) -> Result<String, ::intl_messageformat_parser_rs::FormatError> {
+    let ast = ast.get_or_init(|| {
+        ::intl_messageformat_parser_rs::Parser::new(message, None)
+            .parse()
+            .expect("the message should parse as it did when it was generated")
+    });
+    ::intl_messageformat_parser_rs::format(ast, LOCALE, &values.into_iter().collect())
+}
+
+/// The message `app.inbox`:
+///
+/// ```text
+/// {count, plural, =0 {No messages} one {# <b>new</b> message} other {# <b>new</b> messages}}
+/// ```
+pub fn app_inbox(count: f64, b: impl Fn(String) -> String) -> String {
+    static AST: ::std::sync::OnceLock<::intl_messageformat_parser_rs::Ast<'static>> =
+        ::std::sync::OnceLock::new();
+    let values = vec![
+        ("count", count.into()),
+        ("b", ::intl_messageformat_parser_rs::FormatValue::Tag(Box::new(b))),
+    ];
+    format_message(&AST, "{count, plural, =0 {No messages} one {# <b>new</b> message} other {# <b>new</b> messages}}", values).unwrap()
+}
+
+/// The message `empty`:
+///
+/// ```text
+/// No arguments
+/// ```
+pub fn empty() -> String {
+    static AST: ::std::sync::OnceLock<::intl_messageformat_parser_rs::Ast<'static>> =
+        ::std::sync::OnceLock::new();
+    let values = vec![];
+    format_message(&AST, "No arguments", values).unwrap()
+}
+
+/// The message `greeting`:
+///
+/// ```text
+/// Hello {userName}!
+/// ```
+pub fn greeting(user_name: &str) -> String {
+    static AST: ::std::sync::OnceLock<::intl_messageformat_parser_rs::Ast<'static>> =
+        ::std::sync::OnceLock::new();
+    let values = vec![
+        ("userName", user_name.into()),
+    ];
+    format_message(&AST, "Hello {userName}!", values).unwrap()
+}
+
+/// The message `progress`:
+///
+/// ```text
+/// {done, number, ::percent} of {total, number}
+/// ```
+pub fn progress(done: f64, total: f64) -> String {
+    static AST: ::std::sync::OnceLock<::intl_messageformat_parser_rs::Ast<'static>> =
+        ::std::sync::OnceLock::new();
+    let values = vec![
+        ("done", done.into()),
+        ("total", total.into()),
+    ];
+    format_message(&AST, "{done, number, ::percent} of {total, number}", values).unwrap()
+}
+
+/// The message `pronoun`:
+///
+/// ```text
+/// {gender, select, female {She} male {He} other {They}} replied
+/// ```
+pub fn pronoun(gender: &str) -> String {
+    static AST: ::std::sync::OnceLock<::intl_messageformat_parser_rs::Ast<'static>> =
+        ::std::sync::OnceLock::new();
+    let values = vec![
+        ("gender", gender.into()),
+    ];
+    format_message(&AST, "{gender, select, female {She} male {He} other {They}} replied", values).unwrap()
+}
+
+/// The message `status`:
+///
+/// ```text
+/// {status, select, on {On} off {Off}}
+/// ```
+pub fn status(status: &str) -> Result<String, ::intl_messageformat_parser_rs::FormatError> {
+    static AST: ::std::sync::OnceLock<::intl_messageformat_parser_rs::Ast<'static>> =
+        ::std::sync::OnceLock::new();
+    let values = vec![
+        ("status", status.into()),
+    ];
+    format_message(&AST, "{status, select, on {On} off {Off}}", values)
+}
+
+/// The message `type`:
+///
+/// ```text
+/// {type} {0}
+/// ```
+pub fn type_(type_: &str, value_0: &str) -> String {
+    static AST: ::std::sync::OnceLock<::intl_messageformat_parser_rs::Ast<'static>> =
+        ::std::sync::OnceLock::new();
+    let values = vec![
+        ("type", type_.into()),
+        ("0", value_0.into()),
+    ];
+    format_message(&AST, "{type} {0}", values).unwrap()
+}
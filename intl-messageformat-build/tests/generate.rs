@@ -0,0 +1,69 @@
+//! `fixtures/en.rs` is generated from `fixtures/en.json`, and is included here to check that the
+//! generated functions compile and format their messages. Regenerate it after changing the
+//! generator, then review the diff.
+
+use indoc::indoc;
+use intl_messageformat_build::generate;
+use intl_messageformat_parser_rs::{FormatError, RustOptions};
+use pretty_assertions::assert_eq;
+use std::fs;
+use std::path::Path;
+
+mod en {
+    include!("fixtures/en.rs");
+}
+
+fn fixture(name: &str) -> String {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name).display().to_string()
+}
+
+#[test]
+fn generated_source_is_up_to_date() {
+    assert_eq!(
+        generate(fixture("en.json"), &RustOptions::default()).unwrap(),
+        fs::read_to_string(fixture("en.rs")).unwrap()
+    );
+}
+
+#[test]
+fn format_messages() {
+    let bold = |children: String| format!("**{}**", children);
+    assert_eq!(en::app_inbox(0.0, bold), "No messages");
+    assert_eq!(en::app_inbox(1.0, bold), "1 **new** message");
+    assert_eq!(en::app_inbox(3.0, bold), "3 **new** messages");
+    assert_eq!(en::greeting("Ann"), "Hello Ann!");
+    assert_eq!(en::pronoun("female"), "She replied");
+    assert_eq!(en::pronoun("unknown"), "They replied");
+    assert_eq!(en::progress(0.5, 2.0), "50% of 2");
+    assert_eq!(en::type_("a", "b"), "a b");
+    assert_eq!(en::empty(), "No arguments");
+
+    assert_eq!(en::status("on"), Ok("On".to_string()));
+    assert_eq!(en::status("unknown"), Err(FormatError::MissingOtherClause("status".to_string())));
+}
+
+#[test]
+fn report_invalid_messages() {
+    let error = generate(fixture("invalid.json"), &RustOptions::default()).unwrap_err();
+    assert_eq!(
+        error.to_string().replace(&fixture("invalid.json"), "invalid.json"),
+        indoc! {"
+            invalid.json: broken
+            error: expected a closing brace `}` for the argument
+             --> 1:1
+              |
+            1 | {b
+              | ^^
+              |
+              = help: add `}` to close the argument
+
+            invalid.json: conflict
+            error: argument `n` is used as values of different kinds
+
+            invalid.json: time
+            error: argument `now` cannot be formatted with the style `time`
+
+            found 3 errors
+        "}
+    );
+}
@@ -0,0 +1,471 @@
+use crate::catalog::{encode, CatalogString};
+use crate::document::Document;
+use intl_messageformat_parser_rs::visit::{walk_ast, walk_options, Visitor};
+use intl_messageformat_parser_rs::{
+    extract_arguments, print_ast, suggest_fix, ArgumentKind, ArgumentType, Ast, AstElement,
+    DateTimeArgStyle, Error, NumberArgStyle, Parser, ParserOptions, PluralOrSelectOptions,
+    PluralType, Span,
+};
+use lsp_types::{
+    Diagnostic, DiagnosticSeverity, Hover, HoverContents, MarkupContent, MarkupKind, Range,
+    SemanticToken, SemanticTokenType, TextEdit,
+};
+use std::borrow::Cow;
+
+/// The semantic token types, indexed by `SemanticToken::token_type`.
+pub const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::KEYWORD,
+    SemanticTokenType::ENUM_MEMBER,
+    // Tags are formatted by functions of their children.
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::OPERATOR,
+];
+
+const VARIABLE: u32 = 0;
+const KEYWORD: u32 = 1;
+const ENUM_MEMBER: u32 = 2;
+const FUNCTION: u32 = 3;
+const OPERATOR: u32 = 4;
+
+fn parse<'s>(string: &'s CatalogString, options: &ParserOptions) -> (Ast<'s>, Vec<Error>) {
+    Parser::new(&string.value, Some(options)).parse_with_recovery()
+}
+
+/// The range in the document of the span in the message of the string.
+fn span_range(document: &Document, string: &CatalogString, span: Span) -> Range {
+    document.range(string.file_offset(span.start.offset)..string.file_offset(span.end.offset))
+}
+
+/// The parse errors of every message of the document.
+pub fn diagnostics(document: &Document, options: &ParserOptions) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    for string in &document.strings {
+        for error in parse(string, options).1 {
+            let mut message = error.kind.to_string();
+            if let Some(suggestion) = suggest_fix(&error) {
+                message.push_str("\nhelp: ");
+                message.push_str(&suggestion);
+            }
+            diagnostics.push(Diagnostic {
+                range: span_range(document, string, error.span),
+                severity: Some(DiagnosticSeverity::ERROR),
+                source: Some("intl-messageformat".to_string()),
+                message,
+                ..Default::default()
+            });
+        }
+    }
+    diagnostics
+}
+
+/// The type of the innermost argument or tag at the offset.
+pub fn hover(document: &Document, offset: usize, options: &ParserOptions) -> Option<Hover> {
+    let (string, offset) = document.string_at(offset)?;
+    let arguments = extract_arguments(&parse(string, options).0);
+    let (argument, argument_use) = arguments
+        .iter()
+        .flat_map(|argument| argument.uses.iter().map(move |argument_use| (argument, argument_use)))
+        .filter(|(_, argument_use)| {
+            argument_use.span.start.offset <= offset && offset < argument_use.span.end.offset
+        })
+        .min_by_key(|(_, argument_use)| {
+            argument_use.span.end.offset - argument_use.span.start.offset
+        })?;
+
+    let signature = match argument.kind {
+        ArgumentKind::Tag => format!("(tag) {}", argument.name),
+        kind => format!("(argument) {}: {}", argument.name, kind_name(kind)),
+    };
+    let mut uses: Vec<&str> = vec![];
+    for argument_use in &argument.uses {
+        let name = argument_type_name(argument_use.arg_type);
+        if !uses.contains(&name) {
+            uses.push(name);
+        }
+    }
+    let mut value = format!("```ts\n{}\n```\nUsed as {}.", signature, uses.join(", "));
+    if argument.is_conflicting() {
+        value.push_str(" These uses need values of different kinds.");
+    }
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent { kind: MarkupKind::Markdown, value }),
+        range: Some(span_range(document, string, argument_use.span)),
+    })
+}
+
+fn kind_name(kind: ArgumentKind) -> &'static str {
+    match kind {
+        ArgumentKind::String => "string",
+        ArgumentKind::Number => "number",
+        ArgumentKind::Date => "Date | number",
+        ArgumentKind::Tag => "tag",
+    }
+}
+
+fn argument_type_name(arg_type: ArgumentType) -> &'static str {
+    match arg_type {
+        ArgumentType::Plain => "`{}`",
+        ArgumentType::Number => "`number`",
+        ArgumentType::Date => "`date`",
+        ArgumentType::Time => "`time`",
+        ArgumentType::Select => "`select`",
+        ArgumentType::Plural => "`plural`",
+        ArgumentType::SelectOrdinal => "`selectordinal`",
+        ArgumentType::Tag => "tag",
+    }
+}
+
+/// The closing tag of the opening tag at the offset, or the other way around.
+pub fn definition(document: &Document, offset: usize, options: &ParserOptions) -> Option<Range> {
+    let (string, offset) = document.string_at(offset)?;
+    let ast = parse(string, options).0;
+    let mut collector = TagCollector { message: &string.value, tags: vec![] };
+    collector.visit_ast(&ast);
+    collector.tags.into_iter().find_map(|(open, close)| {
+        if open.contains(&offset) {
+            Some(close)
+        } else if close.contains(&offset) {
+            Some(open)
+        } else {
+            None
+        }
+        .map(|target| {
+            document.range(string.file_offset(target.start)..string.file_offset(target.end))
+        })
+    })
+}
+
+/// Collects the ranges of the opening and closing tags of the tags that have both.
+struct TagCollector<'m> {
+    message: &'m str,
+    tags: Vec<(std::ops::Range<usize>, std::ops::Range<usize>)>,
+}
+
+impl<'m, 's> Visitor<'s> for TagCollector<'m> {
+    fn visit_tag(&mut self, value: &Cow<'s, str>, children: &[AstElement<'s>], span: &Span) {
+        let (start, end) = (span.start.offset, span.end.offset);
+        if !self.message[start..end].ends_with("/>") {
+            let closing_start = closing_tag_start(self.message, span, value);
+            self.tags.push((start..start + value.len() + 2, closing_start..end));
+        }
+        walk_ast(self, children);
+    }
+}
+
+/// The semantic tokens of every message of the document, in order.
+pub fn semantic_tokens(document: &Document, options: &ParserOptions) -> Vec<SemanticToken> {
+    let mut tokens = vec![];
+    for string in &document.strings {
+        let mut collector = TokenCollector { message: &string.value, tokens: vec![] };
+        collector.visit_ast(&parse(string, options).0);
+        for (start, end, token_type) in collector.tokens {
+            let start = document.position(string.file_offset(start));
+            let end = document.position(string.file_offset(end));
+            // Escapes can split a token over lines of the file, which LSP clients reject.
+            if start.line == end.line {
+                tokens.push((start, end.character - start.character, token_type));
+            }
+        }
+    }
+    tokens.sort_by_key(|(start, _, _)| (start.line, start.character));
+
+    let mut previous = lsp_types::Position::new(0, 0);
+    tokens
+        .into_iter()
+        .map(|(start, length, token_type)| {
+            let delta_line = start.line - previous.line;
+            let delta_start = if delta_line == 0 {
+                start.character - previous.character
+            } else {
+                start.character
+            };
+            previous = start;
+            SemanticToken { delta_line, delta_start, length, token_type, token_modifiers_bitset: 0 }
+        })
+        .collect()
+}
+
+/// Collects the ranges in the message of argument names, argument types, selectors, `#` and
+/// tag names.
+struct TokenCollector<'m> {
+    message: &'m str,
+    tokens: Vec<(usize, usize, u32)>,
+}
+
+impl<'m> TokenCollector<'m> {
+    fn skip_whitespace(&self, offset: usize) -> usize {
+        let rest = &self.message[offset..];
+        offset + (rest.len() - rest.trim_start().len())
+    }
+
+    /// Add the tokens of `{name, type, ...}` at the span.
+    fn add_argument(&mut self, name: &str, span: &Span, has_type: bool) {
+        let start = self.skip_whitespace(span.start.offset + 1);
+        if !self.message[start..].starts_with(name) {
+            return;
+        }
+        self.tokens.push((start, start + name.len(), VARIABLE));
+        let comma = self.skip_whitespace(start + name.len());
+        if !has_type || !self.message[comma..].starts_with(',') {
+            return;
+        }
+        let type_start = self.skip_whitespace(comma + 1);
+        let type_len = self.message[type_start..]
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(self.message.len() - type_start);
+        self.tokens.push((type_start, type_start + type_len, KEYWORD));
+    }
+
+    fn add_selectors(&mut self, options: &PluralOrSelectOptions) {
+        for (selector, option) in &options.0 {
+            let end = self.message[..option.span.start.offset].trim_end().len();
+            if let Some(start) = end.checked_sub(selector.len()) {
+                if self.message.get(start..end) == Some(selector) {
+                    self.tokens.push((start, end, ENUM_MEMBER));
+                }
+            }
+        }
+    }
+}
+
+impl<'m, 's> Visitor<'s> for TokenCollector<'m> {
+    fn visit_argument(&mut self, value: &Cow<'s, str>, span: &Span) {
+        self.add_argument(value, span, false);
+    }
+
+    fn visit_number(
+        &mut self,
+        value: &Cow<'s, str>,
+        _style: &Option<NumberArgStyle<'s>>,
+        span: &Span,
+    ) {
+        self.add_argument(value, span, true);
+    }
+
+    fn visit_date(
+        &mut self,
+        value: &Cow<'s, str>,
+        _style: &Option<DateTimeArgStyle<'s>>,
+        span: &Span,
+    ) {
+        self.add_argument(value, span, true);
+    }
+
+    fn visit_time(
+        &mut self,
+        value: &Cow<'s, str>,
+        _style: &Option<DateTimeArgStyle<'s>>,
+        span: &Span,
+    ) {
+        self.add_argument(value, span, true);
+    }
+
+    fn visit_select(
+        &mut self,
+        value: &Cow<'s, str>,
+        options: &PluralOrSelectOptions<'s>,
+        span: &Span,
+    ) {
+        self.add_argument(value, span, true);
+        self.add_selectors(options);
+        walk_options(self, options);
+    }
+
+    fn visit_plural(
+        &mut self,
+        value: &Cow<'s, str>,
+        _plural_type: &PluralType,
+        _offset: i64,
+        options: &PluralOrSelectOptions<'s>,
+        span: &Span,
+    ) {
+        self.add_argument(value, span, true);
+        self.add_selectors(options);
+        walk_options(self, options);
+    }
+
+    fn visit_pound(&mut self, span: &Span) {
+        self.tokens.push((span.start.offset, span.end.offset, OPERATOR));
+    }
+
+    fn visit_tag(&mut self, value: &Cow<'s, str>, children: &[AstElement<'s>], span: &Span) {
+        let (start, end) = (span.start.offset, span.end.offset);
+        self.tokens.push((start + 1, start + 1 + value.len(), FUNCTION));
+        walk_ast(self, children);
+        if !self.message[start..end].ends_with("/>") {
+            let name_start = closing_tag_start(self.message, span, value) + 2;
+            self.tokens.push((name_start, name_start + value.len(), FUNCTION));
+        }
+    }
+}
+
+/// The offset of the `</name` of the closing tag of the tag, which may be followed by
+/// whitespace before its `>`.
+fn closing_tag_start(message: &str, span: &Span, name: &str) -> usize {
+    let (start, end) = (span.start.offset, span.end.offset);
+    start + message[start..end].rfind(&format!("</{}", name)).unwrap()
+}
+
+/// Replace every message that parses with its canonical form.
+pub fn formatting(document: &Document, options: &ParserOptions) -> Vec<TextEdit> {
+    document
+        .strings
+        .iter()
+        .filter_map(|string| {
+            let ast = Parser::new(&string.value, Some(options)).parse().ok()?;
+            let printed = print_ast(&ast);
+            if printed == string.value {
+                return None;
+            }
+            Some(TextEdit {
+                range: document.range(string.range.clone()),
+                new_text: encode(&printed, string.style),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::CatalogFormat;
+    use indoc::indoc;
+    use lsp_types::Position;
+    use pretty_assertions::assert_eq;
+
+    fn json(text: &str) -> Document {
+        Document::new(text.to_string(), CatalogFormat::Json)
+    }
+
+    fn hover_value(hover: Hover) -> String {
+        match hover.contents {
+            HoverContents::Markup(content) => content.value,
+            contents => panic!("unexpected contents {:?}", contents),
+        }
+    }
+
+    #[test]
+    fn report_errors_in_file_positions() {
+        let document = json(indoc! {r#"
+            {
+              "ok": "Hello {name}",
+              "bad": "\u00e9 {count, plural, one {#}}"
+            }
+        "#});
+        let options = ParserOptions { requires_other_clause: true, ..Default::default() };
+        let diagnostics = diagnostics(&document, &options);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "missing `other` clause\nhelp: add an `other {...}` clause"
+        );
+        // The `{` after the escape, which is 6 characters in the file but one in the message.
+        assert_eq!(diagnostics[0].range.start, Position::new(2, 17));
+    }
+
+    #[test]
+    fn hover_arguments_and_tags() {
+        let document = json(r#"{"a": "{n, number} {n, date} <b>{d, date}</b>"}"#);
+        let options = ParserOptions::default();
+        let at = |needle: &str| document.text.find(needle).unwrap();
+
+        let hover = hover(&document, at("n, number"), &options).unwrap();
+        assert_eq!(document.range(at("{n,")..at(" {n, date}")), hover.range.unwrap());
+        assert_eq!(
+            hover_value(hover),
+            "```ts\n(argument) n: number\n```\nUsed as `number`, `date`. \
+             These uses need values of different kinds."
+        );
+        assert_eq!(
+            hover_value(super::hover(&document, at("d, date"), &options).unwrap()),
+            "```ts\n(argument) d: Date | number\n```\nUsed as `date`."
+        );
+        assert_eq!(
+            hover_value(super::hover(&document, at("<b>"), &options).unwrap()),
+            "```ts\n(tag) b\n```\nUsed as tag."
+        );
+        assert!(super::hover(&document, 1, &options).is_none());
+    }
+
+    #[test]
+    fn jump_between_tags() {
+        let document = json(r#"{"a": "<b>bold <i/></b>"}"#);
+        let options = ParserOptions::default();
+        let at = |needle: &str| document.text.find(needle).unwrap();
+        assert_eq!(
+            definition(&document, at("<b>") + 1, &options),
+            Some(document.range(at("</b>")..at("</b>") + 4))
+        );
+        assert_eq!(
+            definition(&document, at("</b>") + 2, &options),
+            Some(document.range(at("<b>")..at("<b>") + 3))
+        );
+        assert_eq!(definition(&document, at("<i/>") + 1, &options), None);
+
+        let document = json(r#"{"a": "<b>x</b  >"}"#);
+        let at = |needle: &str| document.text.find(needle).unwrap();
+        assert_eq!(
+            definition(&document, at("<b>") + 1, &options),
+            Some(document.range(at("</b")..at("</b") + 6))
+        );
+        assert_eq!(
+            semantic_tokens(&document, &options)
+                .into_iter()
+                .map(|token| (token.delta_start, token.length))
+                .collect::<Vec<_>>(),
+            vec![(8, 1), (5, 1)]
+        );
+    }
+
+    #[test]
+    fn encode_semantic_tokens() {
+        let document = json(indoc! {r#"
+            {
+              "a": "{n, plural, one {# item} other {<b>#</b> items}}",
+              "b": "{name}"
+            }
+        "#});
+        let tokens: Vec<_> = semantic_tokens(&document, &ParserOptions::default())
+            .into_iter()
+            .map(|token| (token.delta_line, token.delta_start, token.length, token.token_type))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (1, 9, 1, VARIABLE),
+                (0, 3, 6, KEYWORD),
+                (0, 8, 3, ENUM_MEMBER),
+                (0, 5, 1, OPERATOR),
+                (0, 8, 5, ENUM_MEMBER),
+                (0, 8, 1, FUNCTION),
+                (0, 2, 1, OPERATOR),
+                (0, 3, 1, FUNCTION),
+                (1, 9, 4, VARIABLE),
+            ]
+        );
+    }
+
+    #[test]
+    fn format_messages_in_place() {
+        let options = ParserOptions::default();
+        let document = json(r#"{"a": "{ n ,number }", "b": "{n, number}", "c": "{"}"#);
+        let edits = formatting(&document, &options);
+        assert_eq!(
+            edits,
+            vec![TextEdit {
+                range: document.range(6..21),
+                new_text: r#""{n, number}""#.to_string(),
+            }]
+        );
+
+        let document = Document::new(
+            "a: '{ n ,select, x {Isn''t {n}} other {} }'\n".to_string(),
+            CatalogFormat::Yaml,
+        );
+        let edits = formatting(&document, &options);
+        assert_eq!(edits.len(), 1);
+        // The apostrophe is escaped for ICU, then each quote is doubled for YAML.
+        assert_eq!(edits[0].new_text, "'{n, select, x {Isn''''t {n}} other {}}'");
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{json, yaml};
+use std::ops::Range;
+
+/// The file formats of catalogs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CatalogFormat {
+    Json,
+    Yaml,
+}
+
+impl CatalogFormat {
+    /// The format of the document with the language id or, failing that, the extension.
+    pub fn detect(language_id: &str, path: &str) -> Option<CatalogFormat> {
+        match language_id {
+            "json" | "jsonc" => Some(CatalogFormat::Json),
+            "yaml" => Some(CatalogFormat::Yaml),
+            _ if path.ends_with(".json") => Some(CatalogFormat::Json),
+            _ if path.ends_with(".yaml") || path.ends_with(".yml") => Some(CatalogFormat::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// How a string is written in the file, which decides how to escape it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScalarStyle {
+    Json,
+    YamlPlain,
+    YamlSingleQuoted,
+    YamlDoubleQuoted,
+}
+
+/// A message of a catalog file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CatalogString {
+    /// The keys leading to the message, joined by `.`.
+    pub id: String,
+    /// The message, with the escapes of the file format decoded.
+    pub value: String,
+    /// The offset in the file of every byte of `value`, followed by the offset of its end.
+    offsets: Vec<usize>,
+    /// The range of the string in the file, including its quotes.
+    pub range: Range<usize>,
+    pub style: ScalarStyle,
+}
+
+impl CatalogString {
+    /// The offset in the file of the offset in the message.
+    pub fn file_offset(&self, offset: usize) -> usize {
+        self.offsets[offset.min(self.value.len())]
+    }
+
+    /// The offset in the message of the char at the offset in the file. The end of the message
+    /// counts too, so that the cursor can be right after the last char.
+    pub fn message_offset(&self, file_offset: usize) -> Option<usize> {
+        if file_offset < self.offsets[0] || file_offset > self.offsets[self.value.len()] {
+            return None;
+        }
+        // The last byte offset that starts at or before the file offset, which is the start of
+        // the char that contains it.
+        let index = self.offsets.partition_point(|&offset| offset <= file_offset) - 1;
+        Some((0..=index).rev().find(|&i| self.value.is_char_boundary(i)).unwrap_or(0))
+    }
+}
+
+/// Collect the string values of the catalog, flattening nested objects. Scanning stops at the
+/// first syntax error, since the file is often invalid while it is edited.
+pub fn scan_catalog(text: &str, format: CatalogFormat) -> Vec<CatalogString> {
+    match format {
+        CatalogFormat::Json => json::scan(text),
+        CatalogFormat::Yaml => yaml::scan(text),
+    }
+}
+
+/// Builds the decoded value of a string along with the offsets of its chars in the file.
+pub(crate) struct StringBuilder {
+    value: String,
+    offsets: Vec<usize>,
+}
+
+impl StringBuilder {
+    pub fn new() -> StringBuilder {
+        StringBuilder { value: String::new(), offsets: vec![] }
+    }
+
+    /// Append the char that was decoded from the file at the offset.
+    pub fn push(&mut self, ch: char, file_offset: usize) {
+        self.offsets.extend(std::iter::repeat_n(file_offset, ch.len_utf8()));
+        self.value.push(ch);
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// * `end` - The offset in the file of the end of the value.
+    /// * `range` - The range of the string in the file, including its quotes.
+    pub fn finish(
+        mut self,
+        id: String,
+        end: usize,
+        range: Range<usize>,
+        style: ScalarStyle,
+    ) -> CatalogString {
+        self.offsets.push(end);
+        CatalogString { id, value: self.value, offsets: self.offsets, range, style }
+    }
+}
+
+/// Write the message as a string of the style, including quotes. Plain YAML scalars are
+/// quoted if the message would not be read back as the same string.
+pub fn encode(message: &str, style: ScalarStyle) -> String {
+    match style {
+        ScalarStyle::Json | ScalarStyle::YamlDoubleQuoted => double_quoted(message),
+        ScalarStyle::YamlPlain if yaml::is_plain_safe(message) => message.to_string(),
+        ScalarStyle::YamlPlain | ScalarStyle::YamlSingleQuoted => {
+            if message.contains(|c: char| c.is_control()) {
+                double_quoted(message)
+            } else {
+                format!("'{}'", message.replace('\'', "''"))
+            }
+        }
+    }
+}
+
+/// A JSON string, which is a valid YAML double-quoted scalar too.
+fn double_quoted(message: &str) -> String {
+    let mut output = String::with_capacity(message.len() + 2);
+    output.push('"');
+    for ch in message.chars() {
+        match ch {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            ch if ch.is_control() => output.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => output.push(ch),
+        }
+    }
+    output.push('"');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn map_offsets() {
+        // `\u00e9` is 6 bytes in the file and `é` is 2 in the message.
+        let text = r#"{"a": "x\u00e9\"y"}"#;
+        let string = &scan_catalog(text, CatalogFormat::Json)[0];
+        assert_eq!(string.value, "xé\"y");
+        assert_eq!(
+            (0..=string.value.len()).map(|i| string.file_offset(i)).collect::<Vec<_>>(),
+            vec![7, 8, 8, 14, 16, 17]
+        );
+        assert_eq!(
+            (6..=18).map(|i| string.message_offset(i)).collect::<Vec<_>>(),
+            vec![
+                None,
+                Some(0),
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(3),
+                Some(3),
+                Some(4),
+                Some(5),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn encode_strings() {
+        assert_eq!(encode("a \"b\"\n\\", ScalarStyle::Json), r#""a \"b\"\n\\""#);
+        assert_eq!(encode("{n, number}", ScalarStyle::YamlPlain), "'{n, number}'");
+        assert_eq!(encode("Hi {name}", ScalarStyle::YamlPlain), "Hi {name}");
+        assert_eq!(encode("it's", ScalarStyle::YamlSingleQuoted), "'it''s'");
+        assert_eq!(encode("a\nb", ScalarStyle::YamlSingleQuoted), r#""a\nb""#);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::catalog::{scan_catalog, CatalogFormat, CatalogString};
+use lsp_types::Position;
+use std::ops::Range;
+
+/// An open catalog file.
+pub struct Document {
+    pub text: String,
+    pub format: CatalogFormat,
+    /// The offset of the start of every line.
+    line_starts: Vec<usize>,
+    pub strings: Vec<CatalogString>,
+}
+
+impl Document {
+    pub fn new(text: String, format: CatalogFormat) -> Document {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        let strings = scan_catalog(&text, format);
+        Document { text, format, line_starts, strings }
+    }
+
+    /// The LSP position of the offset, whose character is in UTF-16 code units.
+    pub fn position(&self, offset: usize) -> Position {
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.line_starts[line];
+        let character = self.text[line_start..offset].encode_utf16().count();
+        Position::new(line as u32, character as u32)
+    }
+
+    /// The offset of the LSP position, clamped to the end of its line.
+    pub fn offset(&self, position: Position) -> usize {
+        let line_start = match self.line_starts.get(position.line as usize) {
+            Some(&line_start) => line_start,
+            None => return self.text.len(),
+        };
+        let mut character = 0;
+        for (index, ch) in self.text[line_start..].char_indices() {
+            if character >= position.character as usize || ch == '\n' {
+                return line_start + index;
+            }
+            character += ch.len_utf16();
+        }
+        self.text.len()
+    }
+
+    pub fn range(&self, range: Range<usize>) -> lsp_types::Range {
+        lsp_types::Range::new(self.position(range.start), self.position(range.end))
+    }
+
+    /// The string that contains the offset, along with the offset in its message.
+    pub fn string_at(&self, offset: usize) -> Option<(&CatalogString, usize)> {
+        self.strings
+            .iter()
+            .find_map(|string| string.message_offset(offset).map(|offset| (string, offset)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_positions() {
+        let document = Document::new("{\n  \"😀\": \"é{a}\"\n}".to_string(), CatalogFormat::Json);
+        // `😀` is 2 UTF-16 code units and 4 bytes.
+        assert_eq!(document.position(11), Position::new(1, 7));
+        assert_eq!(document.offset(Position::new(1, 7)), 11);
+        assert_eq!(document.offset(Position::new(1, 100)), 19);
+        assert_eq!(document.offset(Position::new(5, 0)), document.text.len());
+
+        let (string, offset) = document.string_at(document.text.find("{a}").unwrap()).unwrap();
+        assert_eq!((string.id.as_str(), offset), ("😀", 2));
+        assert!(document.string_at(1).is_none());
+    }
+}
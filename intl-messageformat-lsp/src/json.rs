@@ -0,0 +1,228 @@
+use crate::catalog::{CatalogString, ScalarStyle, StringBuilder};
+
+/// Collect the string values of the members of the objects in the JSON text. Comments are
+/// allowed, as in VS Code's JSON with comments.
+pub fn scan(text: &str) -> Vec<CatalogString> {
+    let mut scanner = Scanner { text, position: 0, array_depth: 0, strings: vec![] };
+    scanner.skip_whitespace();
+    scanner.value(&mut vec![]);
+    scanner.strings
+}
+
+struct Scanner<'t> {
+    text: &'t str,
+    position: usize,
+    /// The number of arrays that contain the current value. Their strings are not messages.
+    array_depth: usize,
+    strings: Vec<CatalogString>,
+}
+
+impl<'t> Scanner<'t> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.position += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn skip_whitespace(&mut self) {
+        loop {
+            let rest = &self.text[self.position..];
+            if rest.starts_with("//") {
+                self.position += rest.find('\n').unwrap_or(rest.len());
+            } else if let Some(comment) = rest.strip_prefix("/*") {
+                self.position += comment.find("*/").map_or(rest.len(), |end| end + 4);
+            } else if rest.starts_with(|c: char| c.is_ascii_whitespace()) {
+                self.position += 1;
+            } else {
+                return;
+            }
+        }
+    }
+
+    /// Scan a value, collecting it if it is a string of an object member at the path. Returns
+    /// `None` at a syntax error.
+    fn value(&mut self, path: &mut Vec<String>) -> Option<()> {
+        match self.peek()? {
+            '{' => self.object(path),
+            '[' => self.array(path),
+            '"' => {
+                let start = self.position;
+                let builder = self.string()?;
+                if !path.is_empty() && self.array_depth == 0 {
+                    let end = self.position;
+                    let string =
+                        builder.finish(path.join("."), end - 1, start..end, ScalarStyle::Json);
+                    self.strings.push(string);
+                }
+                Some(())
+            }
+            _ => {
+                // Numbers, booleans and null.
+                let rest = &self.text[self.position..];
+                let len = rest
+                    .find(|c: char| c == ',' || c == '}' || c == ']' || c.is_ascii_whitespace())
+                    .unwrap_or(rest.len());
+                if len == 0 {
+                    return None;
+                }
+                self.position += len;
+                Some(())
+            }
+        }
+    }
+
+    fn object(&mut self, path: &mut Vec<String>) -> Option<()> {
+        self.bump();
+        self.skip_whitespace();
+        if self.peek()? == '}' {
+            self.bump();
+            return Some(());
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek()? != '"' {
+                return None;
+            }
+            let key = self.string()?;
+            self.skip_whitespace();
+            if self.bump()? != ':' {
+                return None;
+            }
+            self.skip_whitespace();
+            path.push(key.value().to_string());
+            let value = self.value(path);
+            path.pop();
+            value?;
+            self.skip_whitespace();
+            match self.bump()? {
+                ',' => continue,
+                '}' => return Some(()),
+                _ => return None,
+            }
+        }
+    }
+
+    fn array(&mut self, path: &mut Vec<String>) -> Option<()> {
+        self.bump();
+        self.skip_whitespace();
+        if self.peek()? == ']' {
+            self.bump();
+            return Some(());
+        }
+        self.array_depth += 1;
+        loop {
+            self.skip_whitespace();
+            self.value(path)?;
+            self.skip_whitespace();
+            match self.bump()? {
+                ',' => continue,
+                ']' => break,
+                _ => return None,
+            }
+        }
+        self.array_depth -= 1;
+        Some(())
+    }
+
+    /// Scan and decode a string, from its opening quote to its closing quote.
+    fn string(&mut self) -> Option<StringBuilder> {
+        self.bump();
+        let mut builder = StringBuilder::new();
+        loop {
+            let start = self.position;
+            match self.bump()? {
+                '"' => return Some(builder),
+                '\\' => {
+                    let ch = match self.bump()? {
+                        '"' => '"',
+                        '\\' => '\\',
+                        '/' => '/',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'u' => self.unicode_escape()?,
+                        _ => return None,
+                    };
+                    builder.push(ch, start);
+                }
+                '\n' => return None,
+                ch => builder.push(ch, start),
+            }
+        }
+    }
+
+    /// Decode the `XXXX` of a `\uXXXX` escape, and the low surrogate that follows a high one.
+    fn unicode_escape(&mut self) -> Option<char> {
+        let high = self.hex4()?;
+        if !(0xd800..0xdc00).contains(&high) {
+            // Lone low surrogates are replaced like invalid UTF-16.
+            return Some(char::from_u32(high).unwrap_or(char::REPLACEMENT_CHARACTER));
+        }
+        if !self.text[self.position..].starts_with("\\u") {
+            return Some(char::REPLACEMENT_CHARACTER);
+        }
+        self.position += 2;
+        let low = self.hex4()?;
+        if !(0xdc00..0xe000).contains(&low) {
+            return Some(char::REPLACEMENT_CHARACTER);
+        }
+        char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00))
+    }
+
+    fn hex4(&mut self) -> Option<u32> {
+        let hex = self.text.get(self.position..self.position + 4)?;
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        self.position += 4;
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    fn scan_values(text: &str) -> Vec<(String, String)> {
+        scan(text).into_iter().map(|string| (string.id, string.value)).collect()
+    }
+
+    #[test]
+    fn flatten_objects() {
+        let text = indoc! {r#"
+            // Comments are allowed.
+            {
+              "greeting": "Hello {name}",
+              "inbox": { "title": "Inbox", "count": 3, "tags": ["a", {"b": "c"}] },
+              "emoji": "😀 é\n",
+              /* between members */ "empty": ""
+            }
+        "#};
+        assert_eq!(
+            scan_values(text),
+            vec![
+                ("greeting".to_string(), "Hello {name}".to_string()),
+                ("inbox.title".to_string(), "Inbox".to_string()),
+                ("emoji".to_string(), "😀 é\n".to_string()),
+                ("empty".to_string(), "".to_string()),
+            ]
+        );
+        let greeting = &scan(text)[0];
+        assert_eq!(&text[greeting.range.clone()], r#""Hello {name}""#);
+    }
+
+    #[test]
+    fn stop_at_syntax_errors() {
+        assert_eq!(
+            scan_values(r#"{"a": "x", "b": "y" "c": "z"}"#),
+            vec![("a".to_string(), "x".to_string()), ("b".to_string(), "y".to_string())]
+        );
+        assert_eq!(scan_values(r#"{"a": "unterminated"#), vec![]);
+    }
+}
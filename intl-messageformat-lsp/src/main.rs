@@ -0,0 +1,21 @@
+//! Language server for the ICU messages of JSON and YAML catalogs, over stdio. It publishes
+//! parse errors as diagnostics and provides hovers with the types of arguments, go to
+//! definition between opening and closing tags, semantic tokens and formatting.
+
+mod analysis;
+mod catalog;
+mod document;
+mod json;
+mod server;
+mod yaml;
+
+use lsp_server::Connection;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
+    let (connection, io_threads) = Connection::stdio();
+    server::run(&connection)?;
+    drop(connection);
+    io_threads.join()?;
+    Ok(())
+}
@@ -0,0 +1,287 @@
+use crate::analysis;
+use crate::catalog::CatalogFormat;
+use crate::document::Document;
+use intl_messageformat_parser_rs::ParserOptions;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as NotificationTrait, PublishDiagnostics,
+};
+use lsp_types::request::{
+    Formatting, GotoDefinition, HoverRequest, Request as RequestTrait, SemanticTokensFullRequest,
+};
+use lsp_types::{
+    GotoDefinitionResponse, InitializeParams, Location, OneOf, PublishDiagnosticsParams,
+    SemanticTokens, SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions,
+    SemanticTokensResult, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind,
+    Uri,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::error::Error;
+
+pub fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(true.into()),
+        definition_provider: Some(OneOf::Left(true)),
+        semantic_tokens_provider: Some(
+            SemanticTokensOptions {
+                legend: SemanticTokensLegend {
+                    token_types: analysis::TOKEN_TYPES.to_vec(),
+                    token_modifiers: vec![],
+                },
+                full: Some(SemanticTokensFullOptions::Bool(true)),
+                ..Default::default()
+            }
+            .into(),
+        ),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    }
+}
+
+/// The parser options from the initialization options of the client, which are named like the
+/// flags of the `intl-messageformat` command, e.g. `{ "ignoreTag": true, "locale": "fr" }`.
+fn parser_options(initialization_options: Option<&Value>) -> ParserOptions {
+    let option = |name: &str| initialization_options.and_then(|options| options.get(name));
+    ParserOptions {
+        should_ignore_tag: option("ignoreTag").and_then(Value::as_bool).unwrap_or(false),
+        requires_other_clause: option("requiresOther").and_then(Value::as_bool).unwrap_or(false),
+        locale: option("locale").and_then(Value::as_str).map(|locale| locale.to_string()),
+        // Spans must be byte offsets into the messages to be mapped back into the file.
+        ..Default::default()
+    }
+}
+
+/// Serve the client of the connection until it shuts the server down.
+pub fn run(connection: &Connection) -> Result<(), Box<dyn Error + Sync + Send>> {
+    let capabilities = serde_json::to_value(capabilities())?;
+    let params: InitializeParams = serde_json::from_value(connection.initialize(capabilities)?)?;
+    let mut server = Server {
+        documents: HashMap::new(),
+        options: parser_options(params.initialization_options.as_ref()),
+    };
+
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    return Ok(());
+                }
+                connection.sender.send(Message::Response(server.handle_request(request)))?;
+            }
+            Message::Notification(notification) => {
+                if let Some(params) = server.handle_notification(notification) {
+                    let notification = Notification::new(PublishDiagnostics::METHOD.into(), params);
+                    connection.sender.send(Message::Notification(notification))?;
+                }
+            }
+            Message::Response(_) => {}
+        }
+    }
+    Ok(())
+}
+
+struct Server {
+    /// The open catalogs, by URI.
+    documents: HashMap<Uri, Document>,
+    options: ParserOptions,
+}
+
+impl Server {
+    fn handle_request(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            HoverRequest::METHOD => respond::<HoverRequest>(request, |params| {
+                let position = params.text_document_position_params;
+                let document = self.documents.get(&position.text_document.uri)?;
+                let offset = document.offset(position.position);
+                analysis::hover(document, offset, &self.options)
+            }),
+            GotoDefinition::METHOD => respond::<GotoDefinition>(request, |params| {
+                let position = params.text_document_position_params;
+                let uri = position.text_document.uri;
+                let document = self.documents.get(&uri)?;
+                let offset = document.offset(position.position);
+                let range = analysis::definition(document, offset, &self.options)?;
+                Some(GotoDefinitionResponse::Scalar(Location { uri, range }))
+            }),
+            SemanticTokensFullRequest::METHOD => {
+                respond::<SemanticTokensFullRequest>(request, |params| {
+                    let document = self.documents.get(&params.text_document.uri)?;
+                    Some(SemanticTokensResult::Tokens(SemanticTokens {
+                        result_id: None,
+                        data: analysis::semantic_tokens(document, &self.options),
+                    }))
+                })
+            }
+            Formatting::METHOD => respond::<Formatting>(request, |params| {
+                let document = self.documents.get(&params.text_document.uri)?;
+                Some(analysis::formatting(document, &self.options))
+            }),
+            _ => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("unsupported method {}", request.method),
+            ),
+        }
+    }
+
+    /// Update the documents, and return the diagnostics of the document that changed.
+    fn handle_notification(
+        &mut self,
+        notification: Notification,
+    ) -> Option<PublishDiagnosticsParams> {
+        let (uri, text, format) = match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params = notification
+                    .extract::<lsp_types::DidOpenTextDocumentParams>(DidOpenTextDocument::METHOD);
+                let document = params.ok()?.text_document;
+                let format = CatalogFormat::detect(&document.language_id, document.uri.as_str())?;
+                (document.uri, document.text, format)
+            }
+            DidChangeTextDocument::METHOD => {
+                let params = notification.extract::<lsp_types::DidChangeTextDocumentParams>(
+                    DidChangeTextDocument::METHOD,
+                );
+                let mut params = params.ok()?;
+                let uri = params.text_document.uri;
+                // With full sync, the last change has the whole text.
+                let text = params.content_changes.pop()?.text;
+                // Only catalogs are tracked since they were opened.
+                let format = self.documents.get(&uri)?.format;
+                (uri, text, format)
+            }
+            DidCloseTextDocument::METHOD => {
+                let params = notification
+                    .extract::<lsp_types::DidCloseTextDocumentParams>(DidCloseTextDocument::METHOD);
+                let uri = params.ok()?.text_document.uri;
+                self.documents.remove(&uri)?;
+                return Some(PublishDiagnosticsParams::new(uri, vec![], None));
+            }
+            _ => return None,
+        };
+        let document = Document::new(text, format);
+        let diagnostics = analysis::diagnostics(&document, &self.options);
+        self.documents.insert(uri.clone(), document);
+        Some(PublishDiagnosticsParams::new(uri, diagnostics, None))
+    }
+}
+
+fn respond<R: RequestTrait>(
+    request: Request,
+    handler: impl FnOnce(R::Params) -> R::Result,
+) -> Response {
+    match serde_json::from_value(request.params) {
+        Ok(params) => Response::new_ok(request.id, handler(params)),
+        Err(error) => {
+            Response::new_err(request.id, ErrorCode::InvalidParams as i32, error.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lsp_server::RequestId;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn request(client: &Connection, id: i32, method: &str, params: Value) -> Value {
+        let request = Request::new(RequestId::from(id), method.to_string(), params);
+        client.sender.send(Message::Request(request)).unwrap();
+        loop {
+            match client.receiver.recv().unwrap() {
+                Message::Response(response) => {
+                    assert_eq!(response.id, RequestId::from(id));
+                    return response.result.unwrap_or(Value::Null);
+                }
+                _ => continue,
+            }
+        }
+    }
+
+    fn notify(client: &Connection, method: &str, params: Value) {
+        let notification = Notification::new(method.to_string(), params);
+        client.sender.send(Message::Notification(notification)).unwrap();
+    }
+
+    fn diagnostics(client: &Connection) -> Value {
+        match client.receiver.recv().unwrap() {
+            Message::Notification(notification) => {
+                assert_eq!(notification.method, PublishDiagnostics::METHOD);
+                notification.params
+            }
+            message => panic!("unexpected message {:?}", message),
+        }
+    }
+
+    #[test]
+    fn serve_catalogs() {
+        let (server, client) = Connection::memory();
+        let thread = std::thread::spawn(move || run(&server).unwrap());
+
+        let initialize = json!({
+            "capabilities": {},
+            "initializationOptions": { "requiresOther": true },
+        });
+        let result = request(&client, 1, "initialize", initialize);
+        assert_eq!(result["capabilities"]["hoverProvider"], json!(true));
+        notify(&client, "initialized", json!({}));
+
+        let uri = "file:///locales/en.json";
+        notify(
+            &client,
+            DidOpenTextDocument::METHOD,
+            json!({
+                "textDocument": {
+                    "uri": uri,
+                    "languageId": "json",
+                    "version": 1,
+                    "text": r#"{"a": "{n, plural, one {#}}"}"#,
+                },
+            }),
+        );
+        let params = diagnostics(&client);
+        assert_eq!(params["uri"], json!(uri));
+        assert_eq!(params["diagnostics"].as_array().unwrap().len(), 1);
+
+        notify(
+            &client,
+            DidChangeTextDocument::METHOD,
+            json!({
+                "textDocument": { "uri": uri, "version": 2 },
+                "contentChanges": [{ "text": r#"{"a": "{n, plural, one {#} other {#}}"}"# }],
+            }),
+        );
+        assert_eq!(diagnostics(&client)["diagnostics"], json!([]));
+
+        let hover = request(
+            &client,
+            2,
+            HoverRequest::METHOD,
+            json!({ "textDocument": { "uri": uri }, "position": { "line": 0, "character": 8 } }),
+        );
+        assert_eq!(
+            hover["contents"]["value"],
+            json!("```ts\n(argument) n: number\n```\nUsed as `plural`.")
+        );
+
+        // Other files are not catalogs.
+        notify(
+            &client,
+            DidOpenTextDocument::METHOD,
+            json!({
+                "textDocument": {
+                    "uri": "file:///README.md",
+                    "languageId": "markdown",
+                    "version": 1,
+                    "text": "{",
+                },
+            }),
+        );
+        assert_eq!(request(&client, 3, "shutdown", Value::Null), Value::Null);
+        notify(&client, "exit", Value::Null);
+        thread.join().unwrap();
+    }
+}
@@ -0,0 +1,241 @@
+//! A scanner for the subset of YAML that catalogs use: nested block mappings whose values are
+//! plain, single-quoted or double-quoted scalars on a single line. Block scalars, multi-line
+//! scalars, flow collections and sequences are skipped.
+
+use crate::catalog::{CatalogString, ScalarStyle, StringBuilder};
+
+/// Collect the scalar values of the mappings in the YAML text.
+pub fn scan(text: &str) -> Vec<CatalogString> {
+    let mut strings = vec![];
+    // The indentation and key of the mappings that contain the current line.
+    let mut parents: Vec<(usize, String)> = vec![];
+    // Lines indented more than this belong to a block scalar or a multi-line scalar.
+    let mut skip_indent: Option<usize> = None;
+
+    let mut line_start = 0;
+    for line in text.split_inclusive('\n') {
+        let start = line_start;
+        line_start += line.len();
+        let line = line.trim_end_matches('\n').trim_end_matches('\r');
+        let content = line.trim_start_matches(' ');
+        let indent = line.len() - content.len();
+        if content.is_empty() || content.starts_with('#') {
+            continue;
+        }
+        if let Some(skip) = skip_indent {
+            if indent > skip {
+                continue;
+            }
+            skip_indent = None;
+        }
+        if indent == 0 && (content.starts_with("---") || content.starts_with("...")) {
+            parents.clear();
+            continue;
+        }
+        while parents.last().is_some_and(|(parent_indent, _)| *parent_indent >= indent) {
+            parents.pop();
+        }
+
+        let (key, rest) = match split_key(content) {
+            Some(entry) => entry,
+            None => {
+                skip_indent = Some(indent);
+                continue;
+            }
+        };
+        let value_start = start + indent + (content.len() - rest.len());
+        let value = rest.trim_start_matches(' ');
+        let value_start = value_start + (rest.len() - value.len());
+
+        if value.is_empty() || value.starts_with('#') {
+            parents.push((indent, key));
+            continue;
+        }
+        let mut id: Vec<&str> = parents.iter().map(|(_, key)| key.as_str()).collect();
+        id.push(&key);
+        match scalar(value, value_start) {
+            Some(builder) => strings.push(builder(id.join("."))),
+            None => skip_indent = Some(indent),
+        }
+    }
+    strings
+}
+
+/// Split `key: value` into the decoded key and the text after the colon.
+fn split_key(content: &str) -> Option<(String, &str)> {
+    let (key, rest) = match content.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let (builder, len) = quoted(content, 0, quote)?;
+            (builder.value().to_string(), &content[len..])
+        }
+        '-' | '[' | '{' | '?' => return None,
+        _ => {
+            let colon = content.match_indices(':').map(|(index, _)| index).find(|&index| {
+                content[index + 1..].is_empty() || content[index + 1..].starts_with(' ')
+            })?;
+            (content[..colon].trim_end().to_string(), &content[colon..])
+        }
+    };
+    let rest = rest.trim_start_matches(' ');
+    let rest = rest.strip_prefix(':')?;
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    Some((key, rest))
+}
+
+/// Scan the scalar at the start of the value, which is at the offset in the file. Returns a
+/// function that finishes the string with its id.
+fn scalar(value: &str, offset: usize) -> Option<impl FnOnce(String) -> CatalogString> {
+    let (builder, len, style, end) = match value.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let (builder, len) = quoted(value, offset, quote)?;
+            let style = if quote == '"' {
+                ScalarStyle::YamlDoubleQuoted
+            } else {
+                ScalarStyle::YamlSingleQuoted
+            };
+            let rest = value[len..].trim_start_matches(' ');
+            if !rest.is_empty() && !rest.starts_with('#') {
+                return None;
+            }
+            (builder, len, style, offset + len - 1)
+        }
+        // Block scalars, flow collections, aliases, anchors and tags.
+        '|' | '>' | '[' | '{' | '*' | '&' | '!' | '%' | '@' | '`' => return None,
+        _ => {
+            let len = value.find(" #").unwrap_or(value.len());
+            let plain = value[..len].trim_end();
+            let mut builder = StringBuilder::new();
+            for (index, ch) in plain.char_indices() {
+                builder.push(ch, offset + index);
+            }
+            (builder, plain.len(), ScalarStyle::YamlPlain, offset + plain.len())
+        }
+    };
+    Some(move |id| builder.finish(id, end, offset..offset + len, style))
+}
+
+/// Scan and decode a quoted scalar that ends on the same line. Returns the decoded value and
+/// the length of the scalar, including quotes.
+fn quoted(text: &str, offset: usize, quote: char) -> Option<(StringBuilder, usize)> {
+    let mut builder = StringBuilder::new();
+    let mut chars = text.char_indices().skip(1).peekable();
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '\'' if quote == '\'' => {
+                if chars.peek().map(|&(_, next)| next) == Some('\'') {
+                    chars.next();
+                    builder.push('\'', offset + index);
+                } else {
+                    return Some((builder, index + 1));
+                }
+            }
+            '"' if quote == '"' => return Some((builder, index + 1)),
+            '\\' if quote == '"' => {
+                let (_, escape) = chars.next()?;
+                let ch = match escape {
+                    '0' => '\0',
+                    'a' => '\u{7}',
+                    'b' => '\u{8}',
+                    't' | '\t' => '\t',
+                    'n' => '\n',
+                    'v' => '\u{b}',
+                    'f' => '\u{c}',
+                    'r' => '\r',
+                    'e' => '\u{1b}',
+                    ' ' => ' ',
+                    '"' => '"',
+                    '/' => '/',
+                    '\\' => '\\',
+                    'N' => '\u{85}',
+                    '_' => '\u{a0}',
+                    'L' => '\u{2028}',
+                    'P' => '\u{2029}',
+                    'x' | 'u' | 'U' => {
+                        let len = match escape {
+                            'x' => 2,
+                            'u' => 4,
+                            _ => 8,
+                        };
+                        let mut code = 0;
+                        for _ in 0..len {
+                            code = code * 16 + chars.next()?.1.to_digit(16)?;
+                        }
+                        char::from_u32(code)?
+                    }
+                    _ => return None,
+                };
+                builder.push(ch, offset + index);
+            }
+            ch => builder.push(ch, offset + index),
+        }
+    }
+    None
+}
+
+/// Whether the message reads back as the same string as a plain scalar.
+pub fn is_plain_safe(message: &str) -> bool {
+    let first = match message.chars().next() {
+        Some(first) => first,
+        None => return false,
+    };
+    !"-?:,[]{}#&*!|>'\"%@`".contains(first)
+        && !first.is_whitespace()
+        && !message.ends_with(|c: char| c.is_whitespace() || c == ':')
+        && !message.contains(": ")
+        && !message.contains(" #")
+        && !message.contains(|c: char| c.is_control())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn scan_mappings() {
+        let text = indoc! {r#"
+            # Comments are skipped.
+            greeting: Hello <b>{name}</b>  # trailing comment
+            inbox:
+              title: 'It''s {count, number}'
+              "empty":   ""
+              nested:
+                deep: "Tab\there é"
+            block: |
+              Skipped: {a}
+            list:
+              - skipped
+            flow: {a: b}
+            after: Done
+        "#};
+        let strings = scan(text);
+        assert_eq!(
+            strings
+                .iter()
+                .map(|string| (string.id.as_str(), string.value.as_str(), string.style))
+                .collect::<Vec<_>>(),
+            vec![
+                ("greeting", "Hello <b>{name}</b>", ScalarStyle::YamlPlain),
+                ("inbox.title", "It's {count, number}", ScalarStyle::YamlSingleQuoted),
+                ("inbox.empty", "", ScalarStyle::YamlDoubleQuoted),
+                ("inbox.nested.deep", "Tab\there é", ScalarStyle::YamlDoubleQuoted),
+                ("after", "Done", ScalarStyle::YamlPlain),
+            ]
+        );
+        assert_eq!(&text[strings[0].range.clone()], "Hello <b>{name}</b>");
+        assert_eq!(&text[strings[1].range.clone()], "'It''s {count, number}'");
+        // The `{` after the escaped quote.
+        assert_eq!(strings[1].file_offset(5), text.find("{count").unwrap());
+    }
+
+    #[test]
+    fn plain_safe() {
+        assert!(is_plain_safe("Hello <b>{name}</b>"));
+        assert!(!is_plain_safe("{count, number}"));
+        assert!(!is_plain_safe("Note: this"));
+        assert!(!is_plain_safe(""));
+    }
+}
@@ -1,4 +1,4 @@
-use intl_messageformat_parser_rs::Parser;
+use intl_messageformat_parser_rs::{OffsetEncoding, Parser, ParserOptions};
 
 #[macro_use]
 extern crate napi;
@@ -30,7 +30,9 @@ fn parse(ctx: CallContext) -> Result<JsString> {
     let message = ctx.get::<JsString>(0)?;
     let message = message.as_str()?;
 
-    let mut parser = Parser::new(message, None);
+    // JavaScript strings are indexed by UTF-16 code units.
+    let options = ParserOptions { offset_encoding: OffsetEncoding::Utf16, ..Default::default() };
+    let mut parser = Parser::new(message, Some(&options));
     let ast = parser.parse().map_err(|_| {
         // TODO: give better error message.
         napi::Error::from_reason("Invalid message!".to_string())
@@ -0,0 +1,62 @@
+// Regenerate the expected output of the formatjs conformance corpus in `tests/fixtures/formatjs`
+// with the reference parser:
+//
+//     npm install --no-save @formatjs/icu-messageformat-parser
+//     node scripts/generate-formatjs-fixtures.mjs
+//
+// The `message` and `options` of every case are kept, and its `ast` or `error` is replaced by what
+// `parse` returns or throws for them. New cases only need a `message` and optional `options`.
+import {parse} from '@formatjs/icu-messageformat-parser';
+import {readdirSync, readFileSync, writeFileSync} from 'node:fs';
+import {join} from 'node:path';
+
+const dir = join(import.meta.dirname, '../tests/fixtures/formatjs');
+
+function generate({message, options}) {
+    try {
+        return {ast: parse(message, options)};
+    } catch (error) {
+        // `parse` throws a `SyntaxError` named after the `ErrorKind`, anything else is a bug in
+        // the formatjs skeleton parsers (e.g. a `RangeError` for unsupported date fields) that
+        // cannot be expressed in the corpus.
+        if (!(error instanceof SyntaxError)) {
+            throw new Error(`${JSON.stringify(message)}: ${error}`);
+        }
+        return {error: {kind: error.message, location: error.location}};
+    }
+}
+
+// Like `JSON.stringify(value, null, 2)`, but objects of scalars such as positions and options are
+// kept on one line.
+function stringify(value, indent = '') {
+    if (Array.isArray(value)) {
+        if (value.length === 0) {
+            return '[]';
+        }
+        const inner = indent + '  ';
+        return `[\n${value.map((item) => inner + stringify(item, inner)).join(',\n')}\n${indent}]`;
+    }
+    if (value !== null && typeof value === 'object') {
+        const entries = Object.entries(value).filter(([, item]) => item !== undefined);
+        if (entries.every(([, item]) => item === null || typeof item !== 'object')) {
+            const fields = entries.map(([key, item]) => `${JSON.stringify(key)}: ${stringify(item)}`);
+            return `{${fields.join(', ')}}`;
+        }
+        const inner = indent + '  ';
+        const fields = entries.map(
+            ([key, item]) => `${inner}${JSON.stringify(key)}: ${stringify(item, inner)}`,
+        );
+        return `{\n${fields.join(',\n')}\n${indent}}`;
+    }
+    return JSON.stringify(value);
+}
+
+for (const file of readdirSync(dir).filter((file) => file.endsWith('.json'))) {
+    const path = join(dir, file);
+    const cases = JSON.parse(readFileSync(path, 'utf8')).map(({message, options}) => ({
+        message,
+        options,
+        ...generate({message, options}),
+    }));
+    writeFileSync(path, stringify(cases) + '\n');
+}
@@ -0,0 +1,188 @@
+// Generate `src/number_format/data.rs` from the CLDR number symbols and patterns of the
+// `cldr-numbers-full` package and the numbering systems of the `cldr-core` package:
+//
+//     node scripts/generate-number-symbols.mjs [path/to/cldr-core] [path/to/cldr-numbers-full] \
+//         > src/number_format/data.rs
+//     cargo fmt
+//
+// Only the default numbering system of every locale is kept, and locales with the same symbols
+// and patterns are grouped together.
+import {readdirSync, readFileSync} from 'node:fs';
+import {createRequire} from 'node:module';
+import {join} from 'node:path';
+
+const require = createRequire(import.meta.url);
+const resolvePackage = (name) => join(require.resolve(`${name}/package.json`), '..');
+const cldrCore = process.argv[2] ?? resolvePackage('cldr-core');
+const cldrNumbers = process.argv[3] ?? resolvePackage('cldr-numbers-full');
+
+const numberingSystems = JSON.parse(
+    readFileSync(join(cldrCore, 'supplemental', 'numberingSystems.json'), 'utf8'),
+).supplemental.numberingSystems;
+
+// Parse the positive subpattern of a number pattern like `#,##,##0.### %`, with the symbols of
+// its prefix and suffix replaced.
+// See: https://unicode.org/reports/tr35/tr35-numbers.html#Number_Format_Patterns
+function parsePattern(pattern, symbols) {
+    if (pattern.includes(';')) {
+        throw new Error(`Explicit negative subpatterns are not supported: ${pattern}`);
+    }
+    const unquoted = pattern.replace(/'[^']*'/g, (quoted) => '_'.repeat(quoted.length));
+    const number = /[#0-9,.@]+/.exec(unquoted);
+    const affix = (text) =>
+        text.replace(/'([^']*)'|./g, (char, quoted) => {
+            if (quoted !== undefined) {
+                return quoted || "'";
+            } else if (char === '%') {
+                return symbols.percentSign;
+            } else if ('-+‰¤E'.includes(char)) {
+                throw new Error(`Unsupported symbol in number pattern: ${pattern}`);
+            }
+            return char;
+        });
+    const integer = number[0].split('.')[0];
+    const groups = integer.split(',').slice(1).map((group) => group.length);
+    return {
+        prefix: affix(pattern.slice(0, number.index)),
+        suffix: affix(pattern.slice(number.index + number[0].length)),
+        grouping: [groups.at(-1) ?? 0, groups.at(-2) ?? groups.at(-1) ?? 0],
+    };
+}
+
+function readLocale(locale) {
+    const path = join(cldrNumbers, 'main', locale, 'numbers.json');
+    const numbers = JSON.parse(readFileSync(path, 'utf8')).main[locale].numbers;
+    const system = numbers.defaultNumberingSystem;
+    const symbols = numbers[`symbols-numberSystem-${system}`];
+    return {
+        digits: [...numberingSystems[system]._digits],
+        decimal: symbols.decimal,
+        group: symbols.group,
+        plusSign: symbols.plusSign,
+        minusSign: symbols.minusSign,
+        infinity: symbols.infinity,
+        nan: symbols.nan,
+        minimumGroupingDigits: Number(numbers.minimumGroupingDigits),
+        decimalPattern: parsePattern(
+            numbers[`decimalFormats-numberSystem-${system}`].standard,
+            symbols,
+        ),
+        percentPattern: parsePattern(
+            numbers[`percentFormats-numberSystem-${system}`].standard,
+            symbols,
+        ),
+    };
+}
+
+function groupLocales() {
+    const numbersByLocale = new Map();
+    for (const locale of readdirSync(join(cldrNumbers, 'main'))) {
+        // `LocaleNumbers::new` looks up lowercase locales with underscores, e.g. `en_in`.
+        const id = locale === 'und' ? 'root' : locale.toLowerCase().replaceAll('-', '_');
+        numbersByLocale.set(id, JSON.stringify(readLocale(locale)));
+    }
+    const groups = new Map();
+    for (const [locale, key] of numbersByLocale) {
+        // Locales are looked up without their last subtag if they are missing, down to `root`,
+        // so those with the same numbers as their fallback are left out.
+        const fallback = locale.includes('_') ? locale.replace(/_[^_]*$/, '') : 'root';
+        if (locale !== 'root' && numbersByLocale.get(fallback) === key) {
+            continue;
+        }
+        if (!groups.has(key)) {
+            groups.set(key, {numbers: JSON.parse(key), locales: []});
+        }
+        groups.get(key).locales.push(locale);
+    }
+    return [...groups.values()]
+        .map((group) => ({...group, locales: group.locales.sort()}))
+        .sort((a, b) => a.locales[0].localeCompare(b.locales[0]));
+}
+
+// Lay out the table like rustfmt would, except for string literals which rustfmt does not split.
+// The values are functions of the indentation of their line, the column that they start at and
+// the text that follows them on their last line.
+const maxWidth = 100;
+
+// rustfmt counts the width of lines in chars.
+const width = (line) => [...line].length;
+
+function rustString(value) {
+    return (indent, column) => {
+        const lines = [''];
+        for (const word of value.split(' ')) {
+            const line = lines.length - 1;
+            // rustfmt breaks the field if the first line takes the whole width.
+            const lineWidth = line === 0 ? maxWidth - 1 : maxWidth;
+            if (lines[line] && column + lines[line].length + word.length + 4 > lineWidth) {
+                lines[line] += ' \\';
+                lines.push(word);
+            } else {
+                lines[line] += lines[line] ? ` ${word}` : word;
+            }
+        }
+        return `"${lines.join(`\n${' '.repeat(column + 1)}`)}"`;
+    };
+}
+
+function rustStruct(name, fields) {
+    return (indent, column, suffix) => {
+        const values = fields.map(([field, value]) => `${field}: ${value(indent, 0, '')}`);
+        const line = `${name} { ${values.join(', ')} }`;
+        if (!line.includes('\n') && column + width(line) + suffix.length <= maxWidth) {
+            return line;
+        }
+        const inner = ' '.repeat(indent + 4);
+        return [
+            `${name} {`,
+            ...fields.map(
+                ([field, value]) =>
+                    `${inner}${field}: ${value(indent + 4, inner.length + field.length + 2, ',')},`,
+            ),
+            `${' '.repeat(indent)}}`,
+        ].join('\n');
+    };
+}
+
+// Escape the characters that are invisible or look like a space, e.g. the marks of bidi text.
+const escape = (text) =>
+    text.replace(/[^\P{Cf}]|[^\P{Zs} ]/gu, (char) => `\\u{${char.codePointAt(0).toString(16)}}`);
+const rustStr = (text) => () => escape(JSON.stringify(text));
+const rustChar = (char) => `'${escape(char)}'`;
+const constant = (value) => () => value;
+
+function rustPattern({prefix, suffix, grouping}) {
+    return rustStruct('NumberPattern', [
+        ['prefix', rustStr(prefix)],
+        ['suffix', rustStr(suffix)],
+        ['grouping', constant(`(${grouping.join(', ')})`)],
+    ]);
+}
+
+function rustLocaleNumbers({numbers, locales}) {
+    const struct = rustStruct('LocaleNumbers', [
+        ['locales', rustString(locales.join(' '))],
+        ['digits', constant(`[${numbers.digits.map(rustChar).join(', ')}]`)],
+        ['decimal', rustStr(numbers.decimal)],
+        ['group', rustStr(numbers.group)],
+        ['plus_sign', rustStr(numbers.plusSign)],
+        ['minus_sign', rustStr(numbers.minusSign)],
+        ['infinity', rustStr(numbers.infinity)],
+        ['nan', rustStr(numbers.nan)],
+        ['minimum_grouping_digits', constant(String(numbers.minimumGroupingDigits))],
+        ['decimal_pattern', rustPattern(numbers.decimalPattern)],
+        ['percent_pattern', rustPattern(numbers.percentPattern)],
+    ]);
+    return `    ${struct(4, 4, ',')},`;
+}
+
+const version = JSON.parse(readFileSync(join(cldrNumbers, 'main', 'en', 'numbers.json'), 'utf8'))
+    .main.en.identity.version._cldrVersion;
+
+console.log(`// Generated by scripts/generate-number-symbols.mjs from CLDR ${version}, do not edit.
+
+use super::{LocaleNumbers, NumberPattern};
+
+pub(super) static LOCALE_NUMBERS: &[LocaleNumbers] = &[
+${groupLocales().map(rustLocaleNumbers).join('\n')}
+];`);
@@ -0,0 +1,133 @@
+// Generate `src/plural_rules/data.rs` from the CLDR plural rules of the `cldr-core` package:
+//
+//     node scripts/generate-plural-rules.mjs [path/to/cldr-core] > src/plural_rules/data.rs
+//     cargo fmt
+//
+// Locales with the same rules are grouped together, and the samples of the rules are dropped
+// since `PluralRules` does not use them. The rules are parsed here, so that `PluralRules` only
+// evaluates them.
+import {readFileSync} from 'node:fs';
+import {createRequire} from 'node:module';
+import {join} from 'node:path';
+
+const categories = ['zero', 'one', 'two', 'few', 'many'];
+
+const cldrCore =
+    process.argv[2] ?? join(createRequire(import.meta.url).resolve('cldr-core/package.json'), '..');
+
+function readSupplemental(name) {
+    return JSON.parse(readFileSync(join(cldrCore, 'supplemental', `${name}.json`), 'utf8'))
+        .supplemental;
+}
+
+function groupLocales(rulesByLocale) {
+    const groups = new Map();
+    for (const [locale, rules] of Object.entries(rulesByLocale)) {
+        const conditions = categories
+            .filter((category) => `pluralRule-count-${category}` in rules)
+            .map((category) => [
+                category,
+                rules[`pluralRule-count-${category}`].split('@')[0].trim().replace(/\s+/g, ' '),
+            ]);
+        const key = JSON.stringify(conditions);
+        if (!groups.has(key)) {
+            groups.set(key, {conditions, locales: []});
+        }
+        // `PluralRules::try_new` looks up lowercase locales with underscores, e.g. `pt_pt`.
+        const id = locale === 'und' ? 'root' : locale.toLowerCase().replaceAll('-', '_');
+        groups.get(key).locales.push(id);
+    }
+    return [...groups.values()]
+        .map((group) => ({...group, locales: group.locales.sort()}))
+        .sort(
+            (a, b) =>
+                a.conditions.length - b.conditions.length ||
+                a.locales[0].localeCompare(b.locales[0]),
+        );
+}
+
+// Parse a relation of a condition, e.g. `i % 10 != 2..4,6`. `c` is a synonym of `e`, and `w` is
+// left out as no rule of CLDR uses it.
+// See: https://unicode.org/reports/tr35/tr35-numbers.html#Plural_rules_syntax
+const relationPattern = /^([nivftce])(?: % ([1-9]\d*))? (!?=) (\d+(?:\.\.\d+)?(?:,\d+(?:\.\.\d+)?)*)$/;
+
+function parseRelation(relation) {
+    const match = relationPattern.exec(relation);
+    if (!match) {
+        throw new Error(`Unsupported plural relation: ${relation}`);
+    }
+    const [, operand, modulus, operator, rangeList] = match;
+    return {
+        operand: operand === 'c' ? 'E' : operand.toUpperCase(),
+        modulus: modulus === undefined ? 'None' : `Some(${modulus})`,
+        isEqual: operator === '=',
+        ranges: rangeList.split(',').map((range) => {
+            const [start, end = start] = range.split('..');
+            return `(${start}, ${end})`;
+        }),
+    };
+}
+
+// The comment lines of the text, wrapped to fit in the table.
+function comment(text) {
+    const lines = [];
+    for (const word of text.split(' ')) {
+        const line = lines.at(-1);
+        if (line !== undefined && `    ${line} ${word}`.length <= maxWidth) {
+            lines[lines.length - 1] = `${line} ${word}`;
+        } else {
+            lines.push(line === undefined ? `// ${word}` : `//     ${word}`);
+        }
+    }
+    return lines;
+}
+
+const maxWidth = 100;
+
+// The tables are laid out by `cargo fmt`, with the rules as comments.
+function rustRules(conditions) {
+    const rules = conditions.map(([category, condition]) => {
+        const orConditions = condition.split(' or ').map((andCondition) => {
+            const relations = andCondition.split(' and ').map((relation) => {
+                const {operand, modulus, isEqual, ranges} = parseRelation(relation);
+                return `Relation { operand: ${operand}, modulus: ${modulus}, is_equal: ${isEqual}, ranges: &[${ranges.join(', ')}] }`;
+            });
+            return `&[${relations.join(', ')}]`;
+        });
+        const name = `${category[0].toUpperCase()}${category.slice(1)}`;
+        return `(${name}, &[${orConditions.join(', ')}])`;
+    });
+    return `&[${rules.join(', ')}]`;
+}
+
+function rustTable(name, rulesByLocale) {
+    const lines = [
+        '/// `(languages, rules)`, where the rules are checked in order and `other` is the fallback.',
+        `pub(super) static ${name}: &[(&[&str], Rules)] = &[`,
+    ];
+    for (const {conditions, locales} of groupLocales(rulesByLocale)) {
+        for (const [category, condition] of conditions) {
+            lines.push(...comment(`${category}: ${condition}`));
+        }
+        const languages = locales.map((locale) => `"${locale}"`).join(', ');
+        lines.push(`(&[${languages}], ${rustRules(conditions)}),`);
+    }
+    lines.push('];');
+    return lines.join('\n');
+}
+
+const plurals = readSupplemental('plurals');
+const ordinals = readSupplemental('ordinals');
+
+console.log(`// Generated by scripts/generate-plural-rules.mjs from CLDR ${plurals.version._cldrVersion}, do not edit.
+
+use super::Operand::*;
+use super::PluralCategory::{self, *};
+use super::Relation;
+
+/// The rule of a category matches if all the relations of any of its \`and\` conditions match.
+type Rules = &'static [(PluralCategory, &'static [&'static [Relation]])];
+
+${rustTable('CARDINAL_RULES', plurals['plurals-type-cardinal'])}
+
+${rustTable('ORDINAL_RULES', ordinals['plurals-type-ordinal'])}`);
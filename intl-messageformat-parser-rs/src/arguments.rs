@@ -0,0 +1,282 @@
+use crate::ast::*;
+use crate::visit::{walk_options, Visitor};
+use std::borrow::Cow;
+
+/// The kind of value that an argument of a message is formatted from.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ArgumentKind {
+    /// `{arg}` and `{arg, select, ...}`
+    String,
+    /// `{arg, number}`, `{arg, plural, ...}` and `{arg, selectordinal, ...}`
+    Number,
+    /// `{arg, date}` and `{arg, time}`
+    Date,
+    /// `<arg>...</arg>`, formatted by a function of the children
+    Tag,
+}
+
+/// How an argument is used in a message.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ArgumentType {
+    /// `{arg}`, which accepts a value of any kind.
+    Plain,
+    Number,
+    Date,
+    Time,
+    Select,
+    Plural,
+    SelectOrdinal,
+    Tag,
+}
+
+impl ArgumentType {
+    pub fn kind(self) -> ArgumentKind {
+        match self {
+            ArgumentType::Plain | ArgumentType::Select => ArgumentKind::String,
+            ArgumentType::Number | ArgumentType::Plural | ArgumentType::SelectOrdinal => {
+                ArgumentKind::Number
+            }
+            ArgumentType::Date | ArgumentType::Time => ArgumentKind::Date,
+            ArgumentType::Tag => ArgumentKind::Tag,
+        }
+    }
+}
+
+/// A single use of an argument.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ArgumentUse {
+    pub arg_type: ArgumentType,
+    /// The span of the argument or tag element.
+    pub span: Span,
+}
+
+/// An argument of a message, merged from all its uses.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MessageArgument<'s> {
+    pub name: Cow<'s, str>,
+    /// The kind of the first use that is not a plain `{arg}`, or `String` if there is none.
+    pub kind: ArgumentKind,
+    /// The uses in the order they appear in the message.
+    pub uses: Vec<ArgumentUse>,
+    /// The selectors of all `select` uses in order, without duplicates.
+    pub select_keys: Vec<Cow<'s, str>>,
+}
+
+impl<'s> MessageArgument<'s> {
+    /// Whether the argument is used as values of different kinds, e.g. `{d, date}` and
+    /// `{d, plural, ...}`. A plain `{arg}` accepts any value and conflicts with nothing.
+    pub fn is_conflicting(&self) -> bool {
+        self.uses
+            .iter()
+            .filter(|argument_use| argument_use.arg_type != ArgumentType::Plain)
+            .any(|argument_use| argument_use.arg_type.kind() != self.kind)
+    }
+
+    /// Convert the argument into one that does not borrow from the message.
+    pub fn into_owned(self) -> MessageArgument<'static> {
+        MessageArgument {
+            name: Cow::Owned(self.name.into_owned()),
+            kind: self.kind,
+            uses: self.uses,
+            select_keys: self
+                .select_keys
+                .into_iter()
+                .map(|key| Cow::Owned(key.into_owned()))
+                .collect(),
+        }
+    }
+}
+
+/// Return the arguments and tags that the message uses, in the order of their first use.
+pub fn extract_arguments<'s>(ast: &[AstElement<'s>]) -> Vec<MessageArgument<'s>> {
+    let mut extractor = Extractor { arguments: vec![] };
+    extractor.visit_ast(ast);
+    extractor.arguments
+}
+
+struct Extractor<'s> {
+    arguments: Vec<MessageArgument<'s>>,
+}
+
+impl<'s> Extractor<'s> {
+    fn add(
+        &mut self,
+        name: &Cow<'s, str>,
+        arg_type: ArgumentType,
+        span: &Span,
+    ) -> &mut MessageArgument<'s> {
+        let index = match self.arguments.iter().position(|argument| argument.name == *name) {
+            Some(index) => index,
+            None => {
+                self.arguments.push(MessageArgument {
+                    name: name.clone(),
+                    kind: arg_type.kind(),
+                    uses: vec![],
+                    select_keys: vec![],
+                });
+                self.arguments.len() - 1
+            }
+        };
+        let argument = &mut self.arguments[index];
+        if argument.uses.iter().all(|argument_use| argument_use.arg_type == ArgumentType::Plain) {
+            argument.kind = arg_type.kind();
+        }
+        argument.uses.push(ArgumentUse { arg_type, span: *span });
+        argument
+    }
+}
+
+impl<'s> Visitor<'s> for Extractor<'s> {
+    fn visit_argument(&mut self, value: &Cow<'s, str>, span: &Span) {
+        self.add(value, ArgumentType::Plain, span);
+    }
+
+    fn visit_number(
+        &mut self,
+        value: &Cow<'s, str>,
+        _style: &Option<NumberArgStyle<'s>>,
+        span: &Span,
+    ) {
+        self.add(value, ArgumentType::Number, span);
+    }
+
+    fn visit_date(
+        &mut self,
+        value: &Cow<'s, str>,
+        _style: &Option<DateTimeArgStyle<'s>>,
+        span: &Span,
+    ) {
+        self.add(value, ArgumentType::Date, span);
+    }
+
+    fn visit_time(
+        &mut self,
+        value: &Cow<'s, str>,
+        _style: &Option<DateTimeArgStyle<'s>>,
+        span: &Span,
+    ) {
+        self.add(value, ArgumentType::Time, span);
+    }
+
+    fn visit_select(
+        &mut self,
+        value: &Cow<'s, str>,
+        options: &PluralOrSelectOptions<'s>,
+        span: &Span,
+    ) {
+        let argument = self.add(value, ArgumentType::Select, span);
+        for (selector, _) in &options.0 {
+            if !argument.select_keys.iter().any(|key| key == selector) {
+                argument.select_keys.push(selector.clone());
+            }
+        }
+        walk_options(self, options);
+    }
+
+    fn visit_plural(
+        &mut self,
+        value: &Cow<'s, str>,
+        plural_type: &PluralType,
+        _offset: i64,
+        options: &PluralOrSelectOptions<'s>,
+        span: &Span,
+    ) {
+        let arg_type = match plural_type {
+            PluralType::Cardinal => ArgumentType::Plural,
+            PluralType::Ordinal => ArgumentType::SelectOrdinal,
+        };
+        self.add(value, arg_type, span);
+        walk_options(self, options);
+    }
+
+    fn visit_tag(&mut self, value: &Cow<'s, str>, children: &[AstElement<'s>], span: &Span) {
+        self.add(value, ArgumentType::Tag, span);
+        self.visit_ast(children);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use pretty_assertions::assert_eq;
+
+    fn extract(message: &str) -> Vec<MessageArgument<'_>> {
+        extract_arguments(&Parser::new(message, None).parse().unwrap())
+    }
+
+    fn span(start: usize, end: usize) -> Span {
+        Span::new(Position::new(start, 1, start + 1), Position::new(end, 1, end + 1))
+    }
+
+    #[test]
+    fn infer_kinds() {
+        assert_eq!(
+            extract(
+                "{name} {g, select, male {<b>{count, plural, one {#} other {#}}</b>} other {}} \
+                 {d, time, short}"
+            )
+            .into_iter()
+            .map(|argument| (argument.name.into_owned(), argument.kind))
+            .collect::<Vec<_>>(),
+            vec![
+                ("name".to_string(), ArgumentKind::String),
+                ("g".to_string(), ArgumentKind::String),
+                ("b".to_string(), ArgumentKind::Tag),
+                ("count".to_string(), ArgumentKind::Number),
+                ("d".to_string(), ArgumentKind::Date),
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_duplicates() {
+        assert_eq!(
+            extract("{n} {n, number} {g, select, a {} b {}} {g, select, b {} c {}}"),
+            vec![
+                MessageArgument {
+                    name: "n".into(),
+                    kind: ArgumentKind::Number,
+                    uses: vec![
+                        ArgumentUse { arg_type: ArgumentType::Plain, span: span(0, 3) },
+                        ArgumentUse { arg_type: ArgumentType::Number, span: span(4, 15) },
+                    ],
+                    select_keys: vec![],
+                },
+                MessageArgument {
+                    name: "g".into(),
+                    kind: ArgumentKind::String,
+                    uses: vec![
+                        ArgumentUse { arg_type: ArgumentType::Select, span: span(16, 38) },
+                        ArgumentUse { arg_type: ArgumentType::Select, span: span(39, 61) },
+                    ],
+                    select_keys: vec!["a".into(), "b".into(), "c".into()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn flag_conflicts() {
+        let arguments =
+            extract("{n, date} {n, plural, other {{m}}} {m, number} {x} {x, select, other {}}");
+        assert_eq!(
+            arguments
+                .iter()
+                .map(|argument| (argument.name.as_ref(), argument.is_conflicting()))
+                .collect::<Vec<_>>(),
+            vec![("n", true), ("m", false), ("x", false)]
+        );
+        assert_eq!(arguments[0].kind, ArgumentKind::Date);
+    }
+
+    #[test]
+    fn borrow_names() {
+        let arguments = extract("{n, select, a {<b>{m}</b>} other {}}");
+        assert!(arguments.iter().all(|argument| matches!(argument.name, Cow::Borrowed(_))));
+        assert!(matches!(
+            arguments[0].select_keys[..],
+            [Cow::Borrowed("a"), Cow::Borrowed("other")]
+        ));
+    }
+}
@@ -1,6 +1,9 @@
 use super::js_intl::*;
+use serde::de::{self, MapAccess, Visitor};
 use serde::ser::{SerializeMap, SerializeStruct};
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+use std::error;
 use std::fmt;
 
 /// The type of an error that occurred while building an AST.
@@ -49,6 +52,9 @@ pub enum ErrorKind {
 
     /// Selector in `plural` or `selectordinal` is malformed (e.g. `{foo, plural, =x {#}}`)
     InvalidPluralArgumentSelector,
+    /// Selector in `plural` or `selectordinal` is not a plural category of the locale in
+    /// `ParserOptions::locale` (e.g. `{foo, plural, few {#}}` in English)
+    InvalidPluralArgumentCategory,
 
     /// Duplicate selectors in `plural` or `selectordinal` argument.
     /// (e.g. {foo, plural, one {#} one {#}})
@@ -58,6 +64,7 @@ pub enum ErrorKind {
     DuplicateSelectArgumentSelector,
 
     /// Plural or select argument option must have `other` clause.
+    /// Only checked if `ParserOptions::requires_other_clause` is set.
     MissingOtherClause,
 
     /// The tag is malformed. (e.g. `<bold!>foo</bold!>)
@@ -72,7 +79,7 @@ pub enum ErrorKind {
 ///
 /// A position encodes one half of a span, and include the code unit offset, line
 /// number and column number.
-#[derive(Clone, Copy, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Position {
     pub offset: usize,
@@ -94,14 +101,19 @@ impl fmt::Debug for Position {
 
 /// Span represents the position information of a single AST item.
 ///
-/// All span positions are absolute byte offsets that can be used on the
-/// original regular expression that was parsed.
-#[derive(Clone, Copy, Eq, PartialEq, Serialize)]
+/// All span positions are absolute offsets into the original message that was
+/// parsed, in the unit of `ParserOptions::offset_encoding` (UTF-8 bytes by
+/// default).
+///
+/// The default span (all zeros) stands for a missing location, e.g. when a
+/// deserialized element has no `location` or `ParserOptions::should_omit_location`
+/// is set. A missing location is not serialized.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Span {
-    /// The start byte offset.
+    /// The start position.
     pub start: Position,
-    /// The end byte offset.
+    /// The end position.
     pub end: Position,
 }
 
@@ -116,6 +128,11 @@ impl Span {
     pub fn new(start: Position, end: Position) -> Span {
         Span { start, end }
     }
+
+    /// Whether this is the default span, which stands for a missing location.
+    pub fn is_missing(&self) -> bool {
+        *self == Span::default()
+    }
 }
 
 /// An error that occurred while parsing an ICU message into an abstract
@@ -124,18 +141,72 @@ impl Span {
 pub struct Error {
     /// The kind of error.
     pub kind: ErrorKind,
-    /// The original message that the parser generated the error from. Every
-    /// span in an error is a valid range into this string.
+    /// The original message that the parser generated the error from. With the
+    /// default `OffsetEncoding::Utf8`, every span in an error is a valid range
+    /// into this string.
     pub message: String,
     /// The span of this error.
     pub span: Span,
 }
 
+impl error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.kind, self.span.start.line, self.span.start.column
+        )
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::ErrorKind::*;
+        f.write_str(match self {
+            ExpectArgumentClosingBrace => "expected a closing brace `}` for the argument",
+            EmptyArgument => "argument is empty",
+            MalformedArgument => "argument name is malformed",
+            ExpectArgumentType => "expected an argument type",
+            InvalidArgumentType => {
+                "invalid argument type, expected `number`, `date`, `time`, `plural`, \
+                 `selectordinal` or `select`"
+            }
+            ExpectArgumentStyle => "expected an argument style",
+            InvalidNumberSkeleton => "invalid number skeleton",
+            InvalidDateTimeSkeleton => "invalid date time skeleton",
+            ExpectNumberSkeleton => "expected a number skeleton after `::`",
+            ExpectDateTimeSkeleton => "expected a date time skeleton after `::`",
+            UnclosedQuoteInArgumentStyle => "unclosed quote in argument style",
+            ExpectSelectArgumentOptions => "expected options for the argument",
+            ExpectPluralArgumentOffsetValue => "expected an integer offset value",
+            InvalidPluralArgumentOffsetValue => "invalid offset value",
+            ExpectSelectArgumentSelector => "expected a selector for the `select` argument",
+            ExpectPluralArgumentSelector => "expected a selector for the plural argument",
+            ExpectSelectArgumentSelectorFragment => {
+                "expected a message fragment `{...}` after the `select` selector"
+            }
+            ExpectPluralArgumentSelectorFragment => {
+                "expected a message fragment `{...}` after the plural selector"
+            }
+            InvalidPluralArgumentSelector => "invalid plural selector",
+            InvalidPluralArgumentCategory => "not a plural category of the locale",
+            DuplicatePluralArgumentSelector => "duplicate plural selector",
+            DuplicateSelectArgumentSelector => "duplicate `select` selector",
+            MissingOtherClause => "missing `other` clause",
+            InvalidTag => "invalid tag",
+            UnmatchedClosingTag => "closing tag does not match the opening tag",
+            UnclosedTag => "unclosed tag",
+        })
+    }
+}
+
 /// An abstract syntax tree for a ICU message. Adapted from:
 /// https://github.com/formatjs/formatjs/blob/c03d4989323a33765798acdd74fb4f5b01f0bdcd/packages/intl-messageformat-parser/src/types.ts
 pub type Ast<'s> = Vec<AstElement<'s>>;
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum PluralType {
     Cardinal,
@@ -147,18 +218,18 @@ pub enum AstElement<'s> {
     /// Raw text
     Literal { value: String, span: Span },
     /// Variable w/o any format, e.g `var` in `this is a {var}`
-    Argument { value: &'s str, span: Span },
+    Argument { value: Cow<'s, str>, span: Span },
     /// Variable w/ number format
-    Number { value: &'s str, span: Span, style: Option<NumberArgStyle<'s>> },
+    Number { value: Cow<'s, str>, span: Span, style: Option<NumberArgStyle<'s>> },
     /// Variable w/ date format
-    Date { value: &'s str, span: Span, style: Option<DateTimeArgStyle<'s>> },
+    Date { value: Cow<'s, str>, span: Span, style: Option<DateTimeArgStyle<'s>> },
     /// Variable w/ time format
-    Time { value: &'s str, span: Span, style: Option<DateTimeArgStyle<'s>> },
+    Time { value: Cow<'s, str>, span: Span, style: Option<DateTimeArgStyle<'s>> },
     /// Variable w/ select format
-    Select { value: &'s str, span: Span, options: PluralOrSelectOptions<'s> },
+    Select { value: Cow<'s, str>, span: Span, options: PluralOrSelectOptions<'s> },
     /// Variable w/ plural format
     Plural {
-        value: &'s str,
+        value: Cow<'s, str>,
         plural_type: PluralType,
         span: Span,
         // TODO: want to use double here but it does not implement Eq trait.
@@ -169,10 +240,15 @@ pub enum AstElement<'s> {
     /// This is the `#` symbol that will be substituted with the count.
     Pound(Span),
     /// XML-like tag
-    Tag { value: &'s str, span: Span, children: Box<Ast<'s>> },
+    Tag { value: Cow<'s, str>, span: Span, children: Box<Ast<'s>> },
+    /// Placeholder for a source range that failed to parse.
+    /// Only produced by `Parser::parse_with_recovery`.
+    Invalid(Span),
 }
 
 // Until this is resolved, we have to roll our own serialization: https://github.com/serde-rs/serde/issues/745
+//
+// The fields are in the same order as the output of the formatjs parser.
 impl<'s> Serialize for AstElement<'s> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -183,21 +259,21 @@ impl<'s> Serialize for AstElement<'s> {
                 let mut state = serializer.serialize_struct("Literal", 3)?;
                 state.serialize_field("type", &0)?;
                 state.serialize_field("value", value)?;
-                state.serialize_field("location", span)?;
+                serialize_location(&mut state, span)?;
                 state.end()
             }
             AstElement::Argument { ref value, ref span } => {
                 let mut state = serializer.serialize_struct("Argument", 3)?;
                 state.serialize_field("type", &1)?;
                 state.serialize_field("value", value)?;
-                state.serialize_field("location", span)?;
+                serialize_location(&mut state, span)?;
                 state.end()
             }
             AstElement::Number { ref value, ref span, ref style } => {
                 let mut state = serializer.serialize_struct("Number", 4)?;
                 state.serialize_field("type", &2)?;
                 state.serialize_field("value", value)?;
-                state.serialize_field("location", span)?;
+                serialize_location(&mut state, span)?;
                 state.serialize_field("style", style)?;
                 state.end()
             }
@@ -205,7 +281,7 @@ impl<'s> Serialize for AstElement<'s> {
                 let mut state = serializer.serialize_struct("Date", 4)?;
                 state.serialize_field("type", &3)?;
                 state.serialize_field("value", value)?;
-                state.serialize_field("location", span)?;
+                serialize_location(&mut state, span)?;
                 state.serialize_field("style", style)?;
                 state.end()
             }
@@ -213,7 +289,7 @@ impl<'s> Serialize for AstElement<'s> {
                 let mut state = serializer.serialize_struct("Time", 4)?;
                 state.serialize_field("type", &4)?;
                 state.serialize_field("value", value)?;
-                state.serialize_field("location", span)?;
+                serialize_location(&mut state, span)?;
                 state.serialize_field("style", style)?;
                 state.end()
             }
@@ -221,8 +297,8 @@ impl<'s> Serialize for AstElement<'s> {
                 let mut state = serializer.serialize_struct("Select", 4)?;
                 state.serialize_field("type", &5)?;
                 state.serialize_field("value", value)?;
-                state.serialize_field("location", span)?;
-                state.serialize_field("style", options)?;
+                state.serialize_field("options", options)?;
+                serialize_location(&mut state, span)?;
                 state.end()
             }
             AstElement::Plural {
@@ -235,33 +311,237 @@ impl<'s> Serialize for AstElement<'s> {
                 let mut state = serializer.serialize_struct("Plural", 6)?;
                 state.serialize_field("type", &6)?;
                 state.serialize_field("value", value)?;
-                state.serialize_field("type", plural_type)?;
-                state.serialize_field("location", span)?;
+                state.serialize_field("options", options)?;
                 state.serialize_field("offset", offset)?;
-                state.serialize_field("style", options)?;
+                state.serialize_field("pluralType", plural_type)?;
+                serialize_location(&mut state, span)?;
                 state.end()
             }
             AstElement::Pound(ref span) => {
                 let mut state = serializer.serialize_struct("Pound", 2)?;
                 state.serialize_field("type", &7)?;
-                state.serialize_field("location", span)?;
+                serialize_location(&mut state, span)?;
                 state.end()
             }
             AstElement::Tag { ref value, ref span, ref children } => {
-                let mut state = serializer.serialize_struct("Pound", 2)?;
+                let mut state = serializer.serialize_struct("Tag", 4)?;
                 state.serialize_field("type", &8)?;
-                state.serialize_field("location", span)?;
                 state.serialize_field("value", value)?;
                 state.serialize_field("children", children)?;
+                serialize_location(&mut state, span)?;
+                state.end()
+            }
+            AstElement::Invalid(ref span) => {
+                // formatjs numbers its element types 0 to 8 (`TYPE.tag`) and has no invalid
+                // element, so the placeholder takes the next free number, which tells it apart
+                // from every formatjs element.
+                let mut state = serializer.serialize_struct("Invalid", 2)?;
+                state.serialize_field("type", &9)?;
+                serialize_location(&mut state, span)?;
                 state.end()
             }
         }
     }
 }
 
+/// Serialize the span as the `location` field, unless the location is missing.
+fn serialize_location<S: SerializeStruct>(state: &mut S, span: &Span) -> Result<(), S::Error> {
+    if span.is_missing() {
+        state.skip_field("location")
+    } else {
+        state.serialize_field("location", span)
+    }
+}
+
+/// The JSON representation of an `AstElement`, before it is validated against its `type`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawAstElement<'s> {
+    #[serde(rename = "type")]
+    element_type: u8,
+    #[serde(borrow, default)]
+    value: Option<RawValue<'s>>,
+    #[serde(default)]
+    location: Span,
+    #[serde(borrow, default)]
+    style: Option<RawArgStyle<'s>>,
+    #[serde(borrow, default)]
+    options: Option<PluralOrSelectOptions<'s>>,
+    #[serde(default)]
+    offset: i64,
+    #[serde(default)]
+    plural_type: Option<PluralType>,
+    #[serde(borrow, default)]
+    children: Option<Ast<'s>>,
+}
+
+/// Borrows the value from the input whenever possible, which `Option<Cow<str>>` would not do.
+#[derive(Deserialize)]
+struct RawValue<'s>(#[serde(borrow)] Cow<'s, str>);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawArgStyle<'s> {
+    Style(#[serde(borrow)] Cow<'s, str>),
+    NumberSkeleton(#[serde(borrow)] NumberSkeleton<'s>),
+    DateTimeSkeleton(#[serde(borrow)] DateTimeSkeleton<'s>),
+}
+
+impl<'de: 's, 's> Deserialize<'de> for AstElement<'s> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let RawAstElement {
+            element_type,
+            value,
+            location: span,
+            style,
+            options,
+            offset,
+            plural_type,
+            children,
+        } = RawAstElement::deserialize(deserializer)?;
+
+        // Every element but `#` and the invalid placeholder has a value.
+        let value = || match value {
+            Some(RawValue(value)) => Ok(value),
+            None => Err(de::Error::missing_field("value")),
+        };
+
+        let date_time_style = |style: Option<RawArgStyle<'s>>| match style {
+            None => Ok(None),
+            Some(RawArgStyle::Style(style)) => Ok(Some(DateTimeArgStyle::Style(style))),
+            Some(RawArgStyle::DateTimeSkeleton(skeleton)) => {
+                Ok(Some(DateTimeArgStyle::Skeleton(skeleton)))
+            }
+            Some(RawArgStyle::NumberSkeleton(_)) => {
+                Err(de::Error::custom("expected a date time skeleton, found a number skeleton"))
+            }
+        };
+
+        Ok(match element_type {
+            0 => AstElement::Literal { value: value()?.into_owned(), span },
+            1 => AstElement::Argument { value: value()?, span },
+            2 => AstElement::Number {
+                value: value()?,
+                span,
+                style: match style {
+                    None => None,
+                    Some(RawArgStyle::Style(style)) => Some(NumberArgStyle::Style(style)),
+                    Some(RawArgStyle::NumberSkeleton(skeleton)) => {
+                        Some(NumberArgStyle::Skeleton(skeleton))
+                    }
+                    Some(RawArgStyle::DateTimeSkeleton(_)) => {
+                        return Err(de::Error::custom(
+                            "expected a number skeleton, found a date time skeleton",
+                        ))
+                    }
+                },
+            },
+            3 => AstElement::Date { value: value()?, span, style: date_time_style(style)? },
+            4 => AstElement::Time { value: value()?, span, style: date_time_style(style)? },
+            5 => AstElement::Select {
+                value: value()?,
+                span,
+                options: options.ok_or_else(|| de::Error::missing_field("options"))?,
+            },
+            6 => AstElement::Plural {
+                value: value()?,
+                span,
+                plural_type: plural_type.unwrap_or(PluralType::Cardinal),
+                offset,
+                options: options.ok_or_else(|| de::Error::missing_field("options"))?,
+            },
+            7 => AstElement::Pound(span),
+            8 => AstElement::Tag {
+                value: value()?,
+                span,
+                children: Box::new(children.unwrap_or_default()),
+            },
+            9 => AstElement::Invalid(span),
+            _ => {
+                return Err(de::Error::invalid_value(
+                    de::Unexpected::Unsigned(element_type.into()),
+                    &"an element type between 0 and 9",
+                ))
+            }
+        })
+    }
+}
+
+impl<'s> AstElement<'s> {
+    /// Convert the element into one that does not borrow from the original message.
+    pub fn into_owned(self) -> AstElement<'static> {
+        match self {
+            AstElement::Literal { value, span } => AstElement::Literal { value, span },
+            AstElement::Argument { value, span } => {
+                AstElement::Argument { value: into_owned_str(value), span }
+            }
+            AstElement::Number { value, span, style } => AstElement::Number {
+                value: into_owned_str(value),
+                span,
+                style: style.map(NumberArgStyle::into_owned),
+            },
+            AstElement::Date { value, span, style } => AstElement::Date {
+                value: into_owned_str(value),
+                span,
+                style: style.map(DateTimeArgStyle::into_owned),
+            },
+            AstElement::Time { value, span, style } => AstElement::Time {
+                value: into_owned_str(value),
+                span,
+                style: style.map(DateTimeArgStyle::into_owned),
+            },
+            AstElement::Select { value, span, options } => AstElement::Select {
+                value: into_owned_str(value),
+                span,
+                options: options.into_owned(),
+            },
+            AstElement::Plural { value, plural_type, span, offset, options } => {
+                AstElement::Plural {
+                    value: into_owned_str(value),
+                    plural_type,
+                    span,
+                    offset,
+                    options: options.into_owned(),
+                }
+            }
+            AstElement::Pound(span) => AstElement::Pound(span),
+            AstElement::Tag { value, span, children } => AstElement::Tag {
+                value: into_owned_str(value),
+                span,
+                children: Box::new(into_owned_ast(*children)),
+            },
+            AstElement::Invalid(span) => AstElement::Invalid(span),
+        }
+    }
+}
+
+/// Convert the AST into one that does not borrow from the original message, so that it can
+/// outlive the message.
+pub fn into_owned_ast(ast: Ast) -> Ast<'static> {
+    ast.into_iter().map(AstElement::into_owned).collect()
+}
+
+fn into_owned_str(s: Cow<str>) -> Cow<'static, str> {
+    Cow::Owned(s.into_owned())
+}
+
 /// Workaround of Rust's orphan impl rule
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PluralOrSelectOptions<'s>(pub Vec<(&'s str, PluralOrSelectOption<'s>)>);
+pub struct PluralOrSelectOptions<'s>(pub Vec<(Cow<'s, str>, PluralOrSelectOption<'s>)>);
+
+impl<'s> PluralOrSelectOptions<'s> {
+    pub fn into_owned(self) -> PluralOrSelectOptions<'static> {
+        PluralOrSelectOptions(
+            self.0
+                .into_iter()
+                .map(|(selector, option)| (into_owned_str(selector), option.into_owned()))
+                .collect(),
+        )
+    }
+}
 
 impl<'s> Serialize for PluralOrSelectOptions<'s> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -277,66 +557,212 @@ impl<'s> Serialize for PluralOrSelectOptions<'s> {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+impl<'de: 's, 's> Deserialize<'de> for PluralOrSelectOptions<'s> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(PluralOrSelectOptionsVisitor)
+    }
+}
+
+/// Deserialize the options from a map, preserving the order of the selectors.
+struct PluralOrSelectOptionsVisitor;
+
+impl<'de> Visitor<'de> for PluralOrSelectOptionsVisitor {
+    type Value = PluralOrSelectOptions<'de>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of selectors to message fragments")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        /// Borrows the selector from the input whenever possible.
+        #[derive(Deserialize)]
+        struct Selector<'s>(#[serde(borrow)] Cow<'s, str>);
+
+        let mut options = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some((Selector(selector), option)) = map.next_entry()? {
+            options.push((selector, option));
+        }
+        Ok(PluralOrSelectOptions(options))
+    }
+}
+
+// The skeleton is much larger than a style, but boxing it would make pattern matching clunky.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum NumberArgStyle<'s> {
-    Style(&'s str),
-    Skeleton(NumberSkeleton<'s>),
+    Style(#[serde(borrow)] Cow<'s, str>),
+    Skeleton(#[serde(borrow)] NumberSkeleton<'s>),
+}
+
+impl<'s> NumberArgStyle<'s> {
+    pub fn into_owned(self) -> NumberArgStyle<'static> {
+        match self {
+            NumberArgStyle::Style(style) => NumberArgStyle::Style(into_owned_str(style)),
+            NumberArgStyle::Skeleton(skeleton) => NumberArgStyle::Skeleton(skeleton.into_owned()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NumberSkeleton<'s> {
+    #[serde(borrow)]
     pub tokens: Vec<NumberSkeletonToken<'s>>,
+    #[serde(default, rename = "location")]
     pub span: Span,
+    #[serde(default)]
     pub parsed_options: Option<JsIntlNumberFormatOptions>,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+/// Serialized with the `type` of the formatjs `SKELETON_TYPE`.
+impl<'s> Serialize for NumberSkeleton<'s> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("NumberSkeleton", 4)?;
+        state.serialize_field("type", &0)?;
+        state.serialize_field("tokens", &self.tokens)?;
+        serialize_location(&mut state, &self.span)?;
+        state.serialize_field("parsedOptions", &self.parsed_options)?;
+        state.end()
+    }
+}
+
+impl<'s> NumberSkeleton<'s> {
+    pub fn into_owned(self) -> NumberSkeleton<'static> {
+        NumberSkeleton {
+            tokens: self.tokens.into_iter().map(NumberSkeletonToken::into_owned).collect(),
+            span: self.span,
+            parsed_options: self.parsed_options,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NumberSkeletonToken<'s> {
-    pub stem: &'s str,
-    pub options: Vec<&'s str>,
+    #[serde(borrow)]
+    pub stem: Cow<'s, str>,
+    #[serde(borrow)]
+    pub options: Vec<Cow<'s, str>>,
+}
+
+impl<'s> NumberSkeletonToken<'s> {
+    pub fn into_owned(self) -> NumberSkeletonToken<'static> {
+        NumberSkeletonToken {
+            stem: into_owned_str(self.stem),
+            options: self.options.into_iter().map(into_owned_str).collect(),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum DateTimeArgStyle<'s> {
-    Style(&'s str),
-    Skeleton(DateTimeSkeleton<'s>),
+    Style(#[serde(borrow)] Cow<'s, str>),
+    Skeleton(#[serde(borrow)] DateTimeSkeleton<'s>),
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+impl<'s> DateTimeArgStyle<'s> {
+    pub fn into_owned(self) -> DateTimeArgStyle<'static> {
+        match self {
+            DateTimeArgStyle::Style(style) => DateTimeArgStyle::Style(into_owned_str(style)),
+            DateTimeArgStyle::Skeleton(skeleton) => {
+                DateTimeArgStyle::Skeleton(skeleton.into_owned())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DateTimeSkeleton<'s> {
-    pub pattern: &'s str,
+    #[serde(borrow)]
+    pub pattern: Cow<'s, str>,
+    #[serde(default, rename = "location")]
     pub span: Span,
+    #[serde(default)]
     pub parsed_options: Option<JsIntlDateTimeFormatOptions>,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+/// Serialized with the `type` of the formatjs `SKELETON_TYPE`.
+impl<'s> Serialize for DateTimeSkeleton<'s> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DateTimeSkeleton", 4)?;
+        state.serialize_field("type", &1)?;
+        state.serialize_field("pattern", &self.pattern)?;
+        serialize_location(&mut state, &self.span)?;
+        state.serialize_field("parsedOptions", &self.parsed_options)?;
+        state.end()
+    }
+}
+
+impl<'s> DateTimeSkeleton<'s> {
+    pub fn into_owned(self) -> DateTimeSkeleton<'static> {
+        DateTimeSkeleton {
+            pattern: into_owned_str(self.pattern),
+            span: self.span,
+            parsed_options: self.parsed_options,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PluralOrSelectOption<'s> {
+    #[serde(borrow)]
     pub value: Ast<'s>,
+    #[serde(default, rename = "location", skip_serializing_if = "Span::is_missing")]
     pub span: Span,
+    /// The span of the selector, e.g. `one` or `=0`. The formatjs AST has no location for
+    /// selectors, so it is not serialized and is missing once deserialized.
+    #[serde(skip)]
+    pub selector_span: Span,
+}
+
+impl<'s> PluralOrSelectOption<'s> {
+    pub fn into_owned(self) -> PluralOrSelectOption<'static> {
+        PluralOrSelectOption {
+            value: into_owned_ast(self.value),
+            span: self.span,
+            selector_span: self.selector_span,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::js_intl::JsIntlNumberFormatOptions;
+    use crate::js_intl::{JsIntlNumberFormatOptions, NumberFormatStyle};
+    use crate::parser::{Parser, ParserOptions};
+    use crate::visit::{walk_option_mut, VisitorMut};
     use serde_json::json;
 
     #[test]
     fn serialize_number_arg_style_with_skeleton() {
         assert_eq!(
             serde_json::to_value(NumberArgStyle::Skeleton(NumberSkeleton {
-                tokens: vec![NumberSkeletonToken { stem: "foo", options: vec!["bar", "baz"] }],
+                tokens: vec![NumberSkeletonToken {
+                    stem: "foo".into(),
+                    options: vec!["bar".into(), "baz".into()],
+                }],
                 span: Span::new(Position::new(0, 1, 1), Position::new(11, 1, 12)),
-                parsed_options: Some(JsIntlNumberFormatOptions {}),
+                parsed_options: Some(JsIntlNumberFormatOptions::default()),
             }))
             .unwrap(),
             json!({
+                "type": 0,
                 "tokens": [{
                     "stem": "foo",
                     "options": [
@@ -344,7 +770,7 @@ mod tests {
                         "baz"
                     ]
                 }],
-                "span": {
+                "location": {
                     "start": {
                         "offset": 0,
                         "line": 1,
@@ -364,7 +790,7 @@ mod tests {
     #[test]
     fn serialize_number_arg_style_string() {
         assert_eq!(
-            serde_json::to_value(NumberArgStyle::Style("percent")).unwrap(),
+            serde_json::to_value(NumberArgStyle::Style("percent".into())).unwrap(),
             json!("percent")
         )
     }
@@ -373,4 +799,157 @@ mod tests {
     fn serialize_plural_type() {
         assert_eq!(serde_json::to_value(PluralType::Cardinal).unwrap(), json!("cardinal"))
     }
+
+    #[test]
+    fn round_trip_through_json() {
+        let message = "{gender, select, male {He has {n, plural, offset:1 one {# <b>cat</b>} \
+                       other {# cats}}} other {{n, number, ::percent .00} {d, date, ::yMMMd} \
+                       {t, time, short} {a}}}";
+        let mut ast = Parser::new(message, None).parse().unwrap();
+        let json = serde_json::to_string(&ast).unwrap();
+        let deserialized: Ast = serde_json::from_str(&json).unwrap();
+
+        // Only the spans of the selectors are not serialized.
+        struct StripSelectorSpans;
+
+        impl<'s> VisitorMut<'s> for StripSelectorSpans {
+            fn visit_option_mut(
+                &mut self,
+                _selector: &mut Cow<'s, str>,
+                option: &mut PluralOrSelectOption<'s>,
+            ) {
+                option.selector_span = Span::default();
+                walk_option_mut(self, option)
+            }
+        }
+
+        StripSelectorSpans.visit_ast_mut(&mut ast);
+        assert_eq!(deserialized, ast);
+    }
+
+    #[test]
+    fn serialize_without_location() {
+        let options = ParserOptions { should_omit_location: true, ..Default::default() };
+        let ast = Parser::new("Hi {n, plural, one {#}} {p, number, ::percent}", Some(&options))
+            .parse()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&ast).unwrap(),
+            json!([
+                {"type": 0, "value": "Hi "},
+                {"type": 6, "value": "n", "pluralType": "cardinal", "offset": 0, "options": {
+                    "one": {"value": [{"type": 7}]}
+                }},
+                {"type": 0, "value": " "},
+                {"type": 2, "value": "p", "style": {
+                    "type": 0,
+                    "tokens": [{"stem": "percent", "options": []}],
+                    "parsedOptions": {"style": "percent"}
+                }}
+            ])
+        );
+    }
+
+    #[test]
+    fn deserialize_without_location() {
+        let json = r#"[
+            {"type": 0, "value": "Hello "},
+            {"type": 1, "value": "name"},
+            {"type": 6, "value": "n", "offset": 0, "pluralType": "ordinal", "options": {
+                "few": {"value": [{"type": 7}]},
+                "other": {"value": []}
+            }},
+            {"type": 2, "value": "n", "style": {
+                "type": 0,
+                "tokens": [{"stem": "percent", "options": []}],
+                "parsedOptions": {"style": "percent"}
+            }},
+            {"type": 8, "value": "b", "children": [{"type": 0, "value": "bold"}]}
+        ]"#;
+        let ast: Ast = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            ast,
+            vec![
+                AstElement::Literal { value: "Hello ".to_string(), span: Span::default() },
+                AstElement::Argument { value: "name".into(), span: Span::default() },
+                AstElement::Plural {
+                    value: "n".into(),
+                    plural_type: PluralType::Ordinal,
+                    span: Span::default(),
+                    offset: 0,
+                    options: PluralOrSelectOptions(vec![
+                        (
+                            "few".into(),
+                            PluralOrSelectOption {
+                                value: vec![AstElement::Pound(Span::default())],
+                                span: Span::default(),
+                                selector_span: Span::default(),
+                            }
+                        ),
+                        (
+                            "other".into(),
+                            PluralOrSelectOption {
+                                value: vec![],
+                                span: Span::default(),
+                                selector_span: Span::default()
+                            }
+                        ),
+                    ]),
+                },
+                AstElement::Number {
+                    value: "n".into(),
+                    span: Span::default(),
+                    style: Some(NumberArgStyle::Skeleton(NumberSkeleton {
+                        tokens: vec![NumberSkeletonToken {
+                            stem: "percent".into(),
+                            options: vec![],
+                        }],
+                        span: Span::default(),
+                        parsed_options: Some(JsIntlNumberFormatOptions {
+                            style: Some(NumberFormatStyle::Percent),
+                            ..Default::default()
+                        }),
+                    })),
+                },
+                AstElement::Tag {
+                    value: "b".into(),
+                    span: Span::default(),
+                    children: Box::new(vec![AstElement::Literal {
+                        value: "bold".to_string(),
+                        span: Span::default(),
+                    }]),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn deserialize_invalid_element_type() {
+        let result = serde_json::from_str::<Ast>(r#"[{"type": 42, "value": "a"}]"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_without_value() {
+        let result = serde_json::from_str::<Ast>(r#"[{"type": 1}]"#);
+        assert_eq!(result.unwrap_err().to_string(), "missing field `value` at line 1 column 13");
+        assert!(serde_json::from_str::<Ast>(r#"[{"type": 8, "children": []}]"#).is_err());
+        assert!(serde_json::from_str::<Ast>(r#"[{"type": 7}]"#).is_ok());
+    }
+
+    #[test]
+    fn deserialize_mismatched_skeleton() {
+        let json = r#"[{"type": 3, "value": "d", "style": {"tokens": []}}]"#;
+        assert!(serde_json::from_str::<Ast>(json).is_err());
+    }
+
+    #[test]
+    fn into_owned_outlives_message() {
+        let ast: Ast<'static> = {
+            let message = String::from("{a} {b, select, x {<i>{c, date, ::yMd}</i>}}");
+            into_owned_ast(Parser::new(&message, None).parse().unwrap())
+        };
+        let expected = Parser::new("{a} {b, select, x {<i>{c, date, ::yMd}</i>}}", None).parse();
+        assert_eq!(Ok(ast), expected);
+    }
 }
@@ -0,0 +1,229 @@
+use crate::ast::*;
+use crate::parser::{Parser, ParserOptions};
+use crate::translation::{check_translation_ast, TranslationIssue};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The messages of a locale, by message id.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Catalog {
+    pub locale: String,
+    pub messages: BTreeMap<String, String>,
+}
+
+/// The issues found in the catalog of a locale.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CatalogReport {
+    pub locale: String,
+    /// The issues sorted by message id.
+    pub issues: Vec<CatalogIssue>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CatalogIssue {
+    pub id: String,
+    pub kind: CatalogIssueKind,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CatalogIssueKind {
+    /// The message failed to parse, including because of plural selectors that are not plural
+    /// categories of the locale of the source catalog.
+    Parse(Error),
+    /// The message of the source catalog is not in the translated catalog.
+    MissingMessage,
+    /// The message of the translated catalog is not in the source catalog.
+    ExtraMessage,
+    /// The translated message is inconsistent with the source message.
+    Translation(TranslationIssue),
+}
+
+impl fmt::Display for CatalogIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CatalogIssueKind::Parse(error) => error.fmt(f),
+            CatalogIssueKind::MissingMessage => f.write_str("message is missing"),
+            CatalogIssueKind::ExtraMessage => f.write_str("message is not in the source"),
+            CatalogIssueKind::Translation(issue) => issue.kind.fmt(f),
+        }
+    }
+}
+
+/// Check the source catalog and its translations in one pass.
+///
+/// Every message is parsed with the options and every parse error is reported. Each translated
+/// catalog is then compared with the source catalog, message by message, with
+/// `check_translation_ast`. Messages that fail to parse on either side are not compared.
+///
+/// The locale of the options is ignored: the plural selectors of each catalog are checked
+/// against the locale of the catalog instead.
+///
+/// Returns the report of the source catalog followed by those of the translations, in order.
+pub fn check_catalogs(
+    source: &Catalog,
+    translations: &[Catalog],
+    options: &ParserOptions,
+) -> Vec<CatalogReport> {
+    let mut source_issues = vec![];
+    let source_asts = parse_catalog(source, Some(&source.locale), options, &mut source_issues);
+    let mut reports = vec![CatalogReport { locale: source.locale.clone(), issues: source_issues }];
+
+    for translation in translations {
+        let mut issues = vec![];
+        // The plural categories of the translation are checked when comparing it with the
+        // source instead, which points into both messages.
+        let translation_asts = parse_catalog(translation, None, options, &mut issues);
+
+        for id in source.messages.keys() {
+            if !translation.messages.contains_key(id) {
+                issues
+                    .push(CatalogIssue { id: id.clone(), kind: CatalogIssueKind::MissingMessage });
+            }
+        }
+        for id in translation.messages.keys() {
+            if !source.messages.contains_key(id) {
+                issues.push(CatalogIssue { id: id.clone(), kind: CatalogIssueKind::ExtraMessage });
+            }
+        }
+        for (id, translation_ast) in &translation_asts {
+            if let Some(source_ast) = source_asts.get(id) {
+                let translation_issues =
+                    check_translation_ast(source_ast, translation_ast, Some(&translation.locale));
+                issues.extend(translation_issues.into_iter().map(|issue| CatalogIssue {
+                    id: id.to_string(),
+                    kind: CatalogIssueKind::Translation(issue),
+                }));
+            }
+        }
+
+        // Stable sort keeps the issues of each message in the order they were found.
+        issues.sort_by(|a, b| a.id.cmp(&b.id));
+        reports.push(CatalogReport { locale: translation.locale.clone(), issues });
+    }
+    reports
+}
+
+/// Parse every message of the catalog, recording the parse errors as issues. Returns the ASTs
+/// of the messages without errors.
+fn parse_catalog<'s>(
+    catalog: &'s Catalog,
+    locale: Option<&str>,
+    options: &ParserOptions,
+    issues: &mut Vec<CatalogIssue>,
+) -> BTreeMap<&'s str, Ast<'s>> {
+    let options =
+        ParserOptions { locale: locale.map(|locale| locale.to_string()), ..options.clone() };
+    let mut asts = BTreeMap::new();
+    for (id, message) in &catalog.messages {
+        let (ast, errors) = Parser::new(message, Some(&options)).parse_with_recovery();
+        if errors.is_empty() {
+            asts.insert(id.as_str(), ast);
+        }
+        issues.extend(
+            errors
+                .into_iter()
+                .map(|error| CatalogIssue { id: id.clone(), kind: CatalogIssueKind::Parse(error) }),
+        );
+    }
+    asts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::translation::TranslationIssueKind;
+    use pretty_assertions::assert_eq;
+
+    fn catalog(locale: &str, messages: &[(&str, &str)]) -> Catalog {
+        Catalog {
+            locale: locale.to_string(),
+            messages: messages
+                .iter()
+                .map(|(id, message)| (id.to_string(), message.to_string()))
+                .collect(),
+        }
+    }
+
+    fn summarize(reports: &[CatalogReport]) -> Vec<(&str, &str, String)> {
+        reports
+            .iter()
+            .flat_map(|report| {
+                report.issues.iter().map(move |issue| {
+                    (report.locale.as_str(), issue.id.as_str(), issue.kind.to_string())
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn check() {
+        let source = catalog(
+            "en",
+            &[
+                ("files", "{n, plural, one {# file} other {# files}}"),
+                ("greeting", "Hello {name}"),
+                ("broken", "{n, plural, few {#} other {#}}"),
+                ("title", "Title"),
+            ],
+        );
+        let translations = [
+            catalog(
+                "fr",
+                &[
+                    ("files", "{n, plural, one {# fichier} other {# fichiers}}"),
+                    ("greeting", "Bonjour {nom}"),
+                    ("broken", "{n, plural, one {#} other {#}}"),
+                    ("unused", "{oops"),
+                ],
+            ),
+            catalog(
+                "ja",
+                &[
+                    ("files", "{n, plural, one {#} other {#}}"),
+                    ("greeting", "{name}さん"),
+                    ("broken", "{n}"),
+                    ("title", "題名"),
+                ],
+            ),
+        ];
+        let reports = check_catalogs(&source, &translations, &ParserOptions::default());
+        assert_eq!(
+            reports.iter().map(|report| report.locale.as_str()).collect::<Vec<_>>(),
+            vec!["en", "fr", "ja"]
+        );
+        assert_eq!(
+            summarize(&reports),
+            vec![
+                ("en", "broken", "not a plural category of the locale at line 1, column 13".into()),
+                ("fr", "greeting", "argument `name` is missing".into()),
+                ("fr", "greeting", "argument `nom` is not in the source".into()),
+                ("fr", "title", "message is missing".into()),
+                (
+                    "fr",
+                    "unused",
+                    "expected a closing brace `}` for the argument at line 1, column 1".into()
+                ),
+                ("fr", "unused", "message is not in the source".into()),
+                (
+                    "ja",
+                    "files",
+                    "`one` of argument `n` is not a plural category of the locale".into()
+                ),
+            ]
+        );
+        assert_eq!(
+            reports[2].issues[0].kind,
+            CatalogIssueKind::Translation(TranslationIssue {
+                kind: TranslationIssueKind::InvalidPluralCategory {
+                    argument: "n".to_string(),
+                    category: "one".to_string(),
+                },
+                source_span: Some(Span::new(Position::new(0, 1, 1), Position::new(41, 1, 42))),
+                translation_span: Some(Span::new(
+                    Position::new(12, 1, 13),
+                    Position::new(15, 1, 16)
+                )),
+            })
+        );
+    }
+}
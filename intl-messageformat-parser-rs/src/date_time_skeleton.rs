@@ -0,0 +1,230 @@
+use crate::js_intl::*;
+use std::ops::Range;
+use std::result;
+
+/// On failure, this is the byte range of the unsupported field within the skeleton pattern.
+type Result<T> = result::Result<T, Range<usize>>;
+
+/// Convert an ICU date time skeleton pattern into `Intl.DateTimeFormatOptions`. Adapted from:
+/// https://github.com/formatjs/formatjs/blob/main/packages/icu-skeleton-parser/date-time.ts
+///
+/// A field is a run of the same pattern letter (e.g. `MMM`). Text inside apostrophes and
+/// non-letter characters are literals and ignored. Fields that cannot be expressed with
+/// `Intl.DateTimeFormatOptions` are rejected.
+/// See: https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
+pub fn parse_date_time_skeleton(pattern: &str) -> Result<JsIntlDateTimeFormatOptions> {
+    let mut result = JsIntlDateTimeFormatOptions::default();
+
+    let mut chars = pattern.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c == '\'' {
+            // `''` is an escaped apostrophe, otherwise skip to the closing apostrophe.
+            if chars.next_if(|&(_, next)| next == '\'').is_none() {
+                while chars.next_if(|&(_, next)| next != '\'').is_some() {}
+                chars.next();
+            }
+            continue;
+        }
+        if !c.is_ascii_alphabetic() {
+            continue;
+        }
+
+        let mut len = 1;
+        while chars.next_if(|&(_, next)| next == c).is_some() {
+            len += 1;
+        }
+        let field = start..start + len;
+
+        match c {
+            // Era
+            'G' => {
+                result.era = Some(text_style(len, field)?);
+            }
+            // Year
+            'y' => {
+                result.year = Some(if len == 2 {
+                    DateTimeNumericStyle::TwoDigit
+                } else {
+                    DateTimeNumericStyle::Numeric
+                });
+            }
+            // Month
+            'M' | 'L' => {
+                result.month = Some(match len {
+                    1 => MonthStyle::Numeric,
+                    2 => MonthStyle::TwoDigit,
+                    3 => MonthStyle::Short,
+                    4 => MonthStyle::Long,
+                    5 => MonthStyle::Narrow,
+                    _ => return Err(field),
+                });
+            }
+            // Day
+            'd' => {
+                result.day = Some(numeric_style(len, field)?);
+            }
+            // Weekday
+            'E' => {
+                result.weekday = Some(match len {
+                    1..=3 | 6 => DateTimeTextStyle::Short,
+                    _ => text_style(len, field)?,
+                });
+            }
+            'e' | 'c' => {
+                // The numeric local day of week variants are not supported.
+                result.weekday = Some(match len {
+                    4 => DateTimeTextStyle::Long,
+                    5 => DateTimeTextStyle::Narrow,
+                    6 => DateTimeTextStyle::Short,
+                    _ => return Err(field),
+                });
+            }
+            // Period
+            'a' => {
+                result.hour12 = Some(true);
+            }
+            // Hour
+            'h' | 'H' | 'K' | 'k' => {
+                result.hour_cycle = Some(match c {
+                    'h' => HourCycle::H12,
+                    'H' => HourCycle::H23,
+                    'K' => HourCycle::H11,
+                    _ => HourCycle::H24,
+                });
+                result.hour = Some(numeric_style(len, field)?);
+            }
+            // Locale preferred hour cycle
+            'j' => {
+                result.hour = Some(numeric_style(len, field)?);
+            }
+            // Minute
+            'm' => {
+                result.minute = Some(numeric_style(len, field)?);
+            }
+            // Second
+            's' => {
+                result.second = Some(numeric_style(len, field)?);
+            }
+            // Zone
+            'z' => {
+                result.time_zone_name = Some(match len {
+                    1..=3 => TimeZoneNameStyle::Short,
+                    4 => TimeZoneNameStyle::Long,
+                    _ => return Err(field),
+                });
+            }
+            'O' => {
+                result.time_zone_name = Some(match len {
+                    1 => TimeZoneNameStyle::ShortOffset,
+                    4 => TimeZoneNameStyle::LongOffset,
+                    _ => return Err(field),
+                });
+            }
+            'v' => {
+                result.time_zone_name = Some(match len {
+                    1 => TimeZoneNameStyle::ShortGeneric,
+                    4 => TimeZoneNameStyle::LongGeneric,
+                    _ => return Err(field),
+                });
+            }
+            'Z' => {
+                // `ZZZZ` is the localized GMT format, the ISO 8601 variants are approximated with
+                // the short offset.
+                result.time_zone_name = Some(match len {
+                    1..=3 | 5 => TimeZoneNameStyle::ShortOffset,
+                    4 => TimeZoneNameStyle::LongOffset,
+                    _ => return Err(field),
+                });
+            }
+            // Year variants (`Y/u/U/r`), quarter (`q/Q`), week (`w/W`), day of year and week of
+            // month (`D/F/g`), flexible day periods (`b/B`), hour variants (`J/C`), fractional
+            // seconds (`S/A`), ISO time zones (`V/X/x`) and unknown letters.
+            _ => return Err(field),
+        }
+    }
+
+    Ok(result)
+}
+
+fn numeric_style(len: usize, field: Range<usize>) -> Result<DateTimeNumericStyle> {
+    match len {
+        1 => Ok(DateTimeNumericStyle::Numeric),
+        2 => Ok(DateTimeNumericStyle::TwoDigit),
+        _ => Err(field),
+    }
+}
+
+fn text_style(len: usize, field: Range<usize>) -> Result<DateTimeTextStyle> {
+    match len {
+        1..=3 => Ok(DateTimeTextStyle::Short),
+        4 => Ok(DateTimeTextStyle::Long),
+        5 => Ok(DateTimeTextStyle::Narrow),
+        _ => Err(field),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn parse(pattern: &str) -> Result<serde_json::Value> {
+        parse_date_time_skeleton(pattern).map(|options| serde_json::to_value(options).unwrap())
+    }
+
+    /// Convenient macro to help parametrize tests with date time skeleton strings.
+    macro_rules! date_time_skeleton_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (pattern, expected) = $value;
+                    assert_eq!(parse(pattern), expected);
+                }
+            )*
+        }
+    }
+
+    date_time_skeleton_tests! {
+        year_month_day: (
+            "yMMMd",
+            Ok(json!({"year": "numeric", "month": "short", "day": "numeric"}))
+        ),
+        two_digit_fields: (
+            "yy-MM-dd HH:mm:ss",
+            Ok(json!({
+                "year": "2-digit",
+                "month": "2-digit",
+                "day": "2-digit",
+                "hour": "2-digit",
+                "hourCycle": "h23",
+                "minute": "2-digit",
+                "second": "2-digit",
+            }))
+        ),
+        weekday_long: ("EEEE", Ok(json!({"weekday": "long"}))),
+        weekday_narrow: ("ccccc", Ok(json!({"weekday": "narrow"}))),
+        numeric_weekday: ("ee", Err(0..2)),
+        twelve_hour: (
+            "hh:mm a",
+            Ok(json!({"hour": "2-digit", "hourCycle": "h12", "minute": "2-digit", "hour12": true}))
+        ),
+        locale_preferred_hour: ("jmm", Ok(json!({"hour": "numeric", "minute": "2-digit"}))),
+        era: ("GGGG y", Ok(json!({"era": "long", "year": "numeric"}))),
+        time_zone_specific: ("zzzz", Ok(json!({"timeZoneName": "long"}))),
+        time_zone_generic: ("v", Ok(json!({"timeZoneName": "shortGeneric"}))),
+        time_zone_offset: ("Z", Ok(json!({"timeZoneName": "shortOffset"}))),
+        quoted_literal: (
+            "d 'of' MMMM",
+            Ok(json!({"day": "numeric", "month": "long"}))
+        ),
+        escaped_apostrophe: ("MMM d, ''yy", Ok(json!({"month": "short", "day": "numeric", "year": "2-digit"}))),
+        quarter: ("y QQQ", Err(2..5)),
+        week_of_year: ("w", Err(0..1)),
+        fractional_seconds: ("ss.SSS", Err(3..6)),
+        too_long_day: ("ddd", Err(0..3)),
+        unknown_letter: ("yMn", Err(2..3)),
+        multi_byte_literal: ("年y", Ok(json!({"year": "numeric"}))),
+    }
+}
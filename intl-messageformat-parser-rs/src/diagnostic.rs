@@ -0,0 +1,349 @@
+use crate::ast::*;
+use std::fmt::Write;
+
+/// Options of `render_diagnostic`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DiagnosticOptions {
+    /// If true, the diagnostic is colored with ANSI escape codes.
+    pub color: bool,
+}
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const BOLD_RED: &str = "\x1b[1;31m";
+const BOLD_BLUE: &str = "\x1b[1;34m";
+const BOLD_CYAN: &str = "\x1b[1;36m";
+
+/// Render the error like rustc does, with the offending lines of the message, the span
+/// underlined with carets and a suggested fix if there is one:
+///
+/// ```text
+/// error: not a plural category of the locale
+///  --> 1:21
+///   |
+/// 1 | {n, plural, one {#} others {#}}
+///   |                     ^^^^^^
+///   |
+///   = help: did you mean `other`?
+/// ```
+///
+/// The carets are placed by the line and column of the span, so this works regardless of how
+/// the offsets are encoded.
+pub fn render_diagnostic(error: &Error, options: &DiagnosticOptions) -> String {
+    let style = |code: &'static str| if options.color { code } else { "" };
+    let (reset, bold, red, blue, cyan) =
+        (style(RESET), style(BOLD), style(BOLD_RED), style(BOLD_BLUE), style(BOLD_CYAN));
+
+    let Span { start, end } = error.span;
+    // A missing location (e.g. `Span::default()`) points at the start of the message.
+    let (first_line, first_column) = (start.line.max(1), start.column.max(1));
+    let (end_line, end_column) = (end.line, end.column).max((first_line, first_column));
+    let lines: Vec<&str> = error.message.split('\n').collect();
+    let last_line = end_line.min(lines.len());
+    let gutter_width = last_line.to_string().len();
+    let gutter = " ".repeat(gutter_width);
+
+    let mut output = String::new();
+    writeln!(output, "{}error{}{}: {}{}", red, reset, bold, error.kind, reset).unwrap();
+    writeln!(output, "{}{}-->{} {}:{}", gutter, blue, reset, first_line, first_column).unwrap();
+    writeln!(output, "{} {}|{}", gutter, blue, reset).unwrap();
+
+    for line_number in first_line..=last_line {
+        let line = lines[line_number - 1].trim_end_matches('\r');
+        let line_len = line.chars().count();
+        // 0-based range of the underlined chars on this line
+        let underline_start = if line_number == first_line { first_column - 1 } else { 0 };
+        let underline_end =
+            if line_number == end_line { end_column.saturating_sub(1) } else { line_len };
+        // Point spans and spans ending right after a line break still get a caret.
+        let underline_len = underline_end.saturating_sub(underline_start);
+        if underline_len == 0 && line_number != first_line {
+            continue;
+        }
+
+        writeln!(
+            output,
+            "{}{:>width$} |{} {}",
+            blue,
+            line_number,
+            reset,
+            line,
+            width = gutter_width
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "{} {}|{} {}{}{}{}",
+            gutter,
+            blue,
+            reset,
+            " ".repeat(underline_start),
+            red,
+            "^".repeat(underline_len.max(1)),
+            reset
+        )
+        .unwrap();
+    }
+
+    if let Some(suggestion) = suggest_fix(error) {
+        writeln!(output, "{} {}|{}", gutter, blue, reset).unwrap();
+        writeln!(output, "{} {}={} {}help{}: {}", gutter, blue, reset, cyan, reset, suggestion)
+            .unwrap();
+    }
+    output
+}
+
+/// A suggested fix for the error, if there is an obvious one. This is the `help` of
+/// `render_diagnostic`.
+pub fn suggest_fix(error: &Error) -> Option<String> {
+    let text = span_text(&error.message, error.span);
+    match error.kind {
+        ErrorKind::ExpectArgumentClosingBrace => Some("add `}` to close the argument".to_string()),
+        ErrorKind::EmptyArgument => Some("add an argument name, e.g. `{name}`".to_string()),
+        ErrorKind::InvalidArgumentType => {
+            let types = ["number", "date", "time", "plural", "selectordinal", "select"];
+            closest_match(text, &types).map(|t| format!("did you mean `{}`?", t))
+        }
+        ErrorKind::UnclosedQuoteInArgumentStyle => {
+            Some("add `'` to close the quote, or use `''` for a literal apostrophe".to_string())
+        }
+        ErrorKind::InvalidPluralArgumentSelector | ErrorKind::InvalidPluralArgumentCategory => {
+            // A category that the locale does not use (e.g. `few` in English) is no typo.
+            let categories = ["zero", "one", "two", "few", "many", "other"];
+            if categories.contains(&text) {
+                return None;
+            }
+            closest_match(text, &categories).map(|c| format!("did you mean `{}`?", c))
+        }
+        ErrorKind::DuplicatePluralArgumentSelector | ErrorKind::DuplicateSelectArgumentSelector => {
+            Some(format!("remove the duplicate `{}` clause", text))
+        }
+        ErrorKind::MissingOtherClause => Some("add an `other {...}` clause".to_string()),
+        ErrorKind::UnclosedTag => {
+            let name = tag_name(text.strip_prefix('<')?);
+            Some(format!("close tag `</{}>` at the end of its content", name))
+        }
+        ErrorKind::UnmatchedClosingTag => {
+            let before_closing_tag = &error.message[..byte_index(&error.message, error.span.start)];
+            innermost_open_tag(before_closing_tag)
+                .map(|name| format!("close tag `</{}>` here", name))
+        }
+        _ => None,
+    }
+}
+
+/// The text of the message within the span.
+fn span_text(message: &str, span: Span) -> &str {
+    let start = byte_index(message, span.start);
+    let end = byte_index(message, span.end).max(start);
+    &message[start..end]
+}
+
+/// The byte index of the line and column of the position in the message.
+fn byte_index(message: &str, position: Position) -> usize {
+    let line_start: usize = message
+        .split_inclusive('\n')
+        .take(position.line.saturating_sub(1))
+        .map(|line| line.len())
+        .sum();
+    let line = &message[line_start..];
+    line_start
+        + line
+            .char_indices()
+            .nth(position.column.saturating_sub(1))
+            .map_or(line.len(), |(index, _)| index)
+}
+
+/// The name of the tag at the start of the text, e.g. `b` for `b>bold</b>`.
+fn tag_name(text: &str) -> &str {
+    let end = text.find(|c: char| c.is_whitespace() || c == '>' || c == '/').unwrap_or(text.len());
+    &text[..end]
+}
+
+/// The name of the innermost tag that is still open at the end of the text.
+///
+/// This is a rough scan for hints that does not take quoting into account.
+fn innermost_open_tag(text: &str) -> Option<&str> {
+    let mut open_tags = vec![];
+    for (index, _) in text.match_indices('<') {
+        let rest = &text[index + 1..];
+        if let Some(closing) = rest.strip_prefix('/') {
+            if open_tags.last() == Some(&tag_name(closing)) {
+                open_tags.pop();
+            }
+        } else if rest.starts_with(|c: char| c.is_ascii_lowercase()) {
+            let name = tag_name(rest);
+            let is_self_closing = rest[name.len()..].trim_start().starts_with("/>");
+            if !is_self_closing {
+                open_tags.push(name);
+            }
+        }
+    }
+    open_tags.pop()
+}
+
+/// The candidate closest to the text, if it is at most 2 edits away.
+fn closest_match<'a>(text: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(text, candidate), *candidate))
+        .filter(|(distance, _)| *distance > 0 && *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between the strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + if a_char == *b_char { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{Parser, ParserOptions};
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    fn render(message: &str, options: &ParserOptions) -> String {
+        let error = Parser::new(message, Some(options)).parse().unwrap_err();
+        render_diagnostic(&error, &DiagnosticOptions::default())
+    }
+
+    #[test]
+    fn suggest_plural_category() {
+        let options = ParserOptions { locale: Some("en".to_string()), ..Default::default() };
+        assert_eq!(
+            render("{n, plural, one {#} others {#}}", &options),
+            indoc! {"
+                error: not a plural category of the locale
+                 --> 1:21
+                  |
+                1 | {n, plural, one {#} others {#}}
+                  |                     ^^^^^^
+                  |
+                  = help: did you mean `other`?
+            "}
+        );
+    }
+
+    #[test]
+    fn unused_plural_category() {
+        let options = ParserOptions { locale: Some("en".to_string()), ..Default::default() };
+        assert_eq!(
+            render("{n, plural, few {#} other {#}}", &options),
+            indoc! {"
+                error: not a plural category of the locale
+                 --> 1:13
+                  |
+                1 | {n, plural, few {#} other {#}}
+                  |             ^^^
+            "}
+        );
+    }
+
+    #[test]
+    fn suggest_argument_type() {
+        assert_eq!(
+            render("Hi {n, numbr}", &ParserOptions::default()),
+            indoc! {"
+                error: invalid argument type, expected `number`, `date`, `time`, `plural`, `selectordinal` or `select`
+                 --> 1:8
+                  |
+                1 | Hi {n, numbr}
+                  |        ^^^^^
+                  |
+                  = help: did you mean `number`?
+            "}
+        );
+    }
+
+    #[test]
+    fn suggest_closing_tag() {
+        assert_eq!(
+            render("<b>bold <i>italic</b>", &ParserOptions::default()),
+            indoc! {"
+                error: closing tag does not match the opening tag
+                 --> 1:20
+                  |
+                1 | <b>bold <i>italic</b>
+                  |                    ^
+                  |
+                  = help: close tag `</i>` here
+            "}
+        );
+    }
+
+    #[test]
+    fn multiline_span() {
+        let options = ParserOptions { requires_other_clause: true, ..Default::default() };
+        assert_eq!(
+            render("Hello\n{g, select,\n  male {He}\n}!", &options),
+            indoc! {"
+                error: missing `other` clause
+                 --> 2:1
+                  |
+                2 | {g, select,
+                  | ^^^^^^^^^^^
+                3 |   male {He}
+                  | ^^^^^^^^^^^
+                4 | }!
+                  | ^
+                  |
+                  = help: add an `other {...}` clause
+            "}
+        );
+    }
+
+    #[test]
+    fn point_span_without_suggestion() {
+        assert_eq!(
+            render("{n, plural, offset}", &ParserOptions::default()),
+            indoc! {"
+                error: expected an integer offset value
+                 --> 1:19
+                  |
+                1 | {n, plural, offset}
+                  |                   ^
+            "}
+        );
+    }
+
+    #[test]
+    fn missing_location() {
+        let error = Error {
+            kind: ErrorKind::EmptyArgument,
+            message: "{}".to_string(),
+            span: Span::default(),
+        };
+        assert_eq!(
+            render_diagnostic(&error, &DiagnosticOptions::default()),
+            indoc! {"
+                error: argument is empty
+                 --> 1:1
+                  |
+                1 | {}
+                  | ^
+                  |
+                  = help: add an argument name, e.g. `{name}`
+            "}
+        );
+    }
+
+    #[test]
+    fn color() {
+        let error = Parser::new("{}", None).parse().unwrap_err();
+        let rendered = render_diagnostic(&error, &DiagnosticOptions { color: true });
+        assert!(rendered.starts_with("\x1b[1;31merror\x1b[0m\x1b[1m: argument is empty\x1b[0m\n"));
+        assert!(rendered.contains("\x1b[1;31m^^\x1b[0m"));
+    }
+}
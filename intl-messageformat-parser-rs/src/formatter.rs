@@ -0,0 +1,476 @@
+use crate::ast::*;
+use crate::js_intl::*;
+use crate::number_format::format_number;
+use crate::plural_rules::PluralRules;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+/// The value of an argument that is passed to `format` or `format_to_rich_text`.
+pub enum FormatValue<'v> {
+    String(Cow<'v, str>),
+    Number(f64),
+    /// Renders a tag, e.g. `<b>...</b>`, from its formatted children.
+    Tag(Box<dyn Fn(String) -> String + 'v>),
+}
+
+impl fmt::Debug for FormatValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatValue::String(value) => f.debug_tuple("String").field(value).finish(),
+            FormatValue::Number(value) => f.debug_tuple("Number").field(value).finish(),
+            FormatValue::Tag(_) => f.write_str("Tag(..)"),
+        }
+    }
+}
+
+impl<'v> From<&'v str> for FormatValue<'v> {
+    fn from(value: &'v str) -> Self {
+        FormatValue::String(value.into())
+    }
+}
+
+impl From<String> for FormatValue<'_> {
+    fn from(value: String) -> Self {
+        FormatValue::String(value.into())
+    }
+}
+
+impl From<f64> for FormatValue<'_> {
+    fn from(value: f64) -> Self {
+        FormatValue::Number(value)
+    }
+}
+
+impl From<i64> for FormatValue<'_> {
+    fn from(value: i64) -> Self {
+        FormatValue::Number(value as f64)
+    }
+}
+
+impl From<i32> for FormatValue<'_> {
+    fn from(value: i32) -> Self {
+        FormatValue::Number(value.into())
+    }
+}
+
+/// A node of a formatted message whose tags are kept as a tree, so that the caller can render
+/// them, e.g. into HTML or a UI framework's elements.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RichText {
+    Text(String),
+    Tag { name: String, children: Vec<RichText> },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FormatError {
+    /// No value was provided for the argument or tag with this name.
+    MissingValue(String),
+    /// The value of the argument or tag has the wrong type.
+    InvalidValueType { name: String, expected: &'static str },
+    /// No option matched the value of the `select` or `plural` argument and there is no
+    /// `other` option to fall back to.
+    MissingOtherClause(String),
+    /// The argument has a style that cannot be formatted, e.g. `currency` or `date`.
+    UnsupportedStyle { name: String, style: &'static str },
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::MissingValue(name) => {
+                write!(f, "The intl string context variable \"{}\" was not provided", name)
+            }
+            FormatError::InvalidValueType { name, expected } => {
+                write!(f, "Invalid value for \"{}\", expected {}", name, expected)
+            }
+            FormatError::MissingOtherClause(name) => {
+                write!(f, "No matching option or \"other\" option for \"{}\"", name)
+            }
+            FormatError::UnsupportedStyle { name, style } => {
+                write!(f, "Unsupported style \"{}\" for \"{}\"", style, name)
+            }
+        }
+    }
+}
+
+impl error::Error for FormatError {}
+
+type Result<T> = std::result::Result<T, FormatError>;
+
+/// Format the message with the argument values. Adapted from:
+/// https://github.com/formatjs/formatjs/blob/main/packages/intl-messageformat/src/formatters.ts
+///
+/// Tags are rendered by the `FormatValue::Tag` callback with the same name.
+///
+/// Numbers are formatted like `Intl.NumberFormat`, with the symbols and grouping of the locale.
+/// The `currency` and `unit` styles, the compact, scientific and engineering notations, and the
+/// `date` and `time` arguments are not supported and return `FormatError::UnsupportedStyle`.
+pub fn format(
+    ast: &[AstElement],
+    locale: &str,
+    values: &HashMap<&str, FormatValue>,
+) -> Result<String> {
+    let parts = format_to_rich_text(ast, locale, values)?;
+    let mut output = String::new();
+    render_rich_text(&mut output, &parts, values)?;
+    Ok(output)
+}
+
+/// Format the message like `format`, but keep the tags as `RichText::Tag` nodes instead of
+/// rendering them. Adjacent text is merged into one `RichText::Text` node.
+pub fn format_to_rich_text(
+    ast: &[AstElement],
+    locale: &str,
+    values: &HashMap<&str, FormatValue>,
+) -> Result<Vec<RichText>> {
+    let mut parts = vec![];
+    format_message(&mut parts, ast, locale, values, None)?;
+    Ok(parts)
+}
+
+/// * `plural_value` - The value of the closest enclosing `plural` argument, minus its offset.
+///   This is what `#` is replaced with.
+fn format_message(
+    parts: &mut Vec<RichText>,
+    ast: &[AstElement],
+    locale: &str,
+    values: &HashMap<&str, FormatValue>,
+    plural_value: Option<f64>,
+) -> Result<()> {
+    for element in ast {
+        match element {
+            AstElement::Literal { value, .. } => push_text(parts, value),
+            AstElement::Argument { value, .. } => match get_value(values, value)? {
+                FormatValue::String(s) => push_text(parts, s),
+                FormatValue::Number(n) => {
+                    push_text(parts, &format_number(*n, locale, &Default::default()))
+                }
+                FormatValue::Tag(_) => return Err(invalid_value_type(value, "a string or number")),
+            },
+            AstElement::Number { value, style, .. } => {
+                let n = get_number(values, value)?;
+                let options = number_format_options(style.as_ref())
+                    .map_err(|style| unsupported_style(value, style))?;
+                push_text(parts, &format_number(n, locale, &options));
+            }
+            AstElement::Date { value, .. } => return Err(unsupported_style(value, "date")),
+            AstElement::Time { value, .. } => return Err(unsupported_style(value, "time")),
+            AstElement::Select { value, options, .. } => {
+                let selector = match get_value(values, value)? {
+                    FormatValue::String(s) => s.to_string(),
+                    FormatValue::Number(n) => js_number_to_string(*n),
+                    FormatValue::Tag(_) => {
+                        return Err(invalid_value_type(value, "a string or number"))
+                    }
+                };
+                let option = find_option(options, &selector)
+                    .or_else(|| find_option(options, "other"))
+                    .ok_or_else(|| FormatError::MissingOtherClause(value.to_string()))?;
+                format_message(parts, &option.value, locale, values, None)?;
+            }
+            AstElement::Plural { value, plural_type, offset, options, .. } => {
+                let n = get_number(values, value)?;
+                let n_minus_offset = n - *offset as f64;
+                let option = find_exact_option(options, n)
+                    .or_else(|| {
+                        let rules = PluralRules::new(locale, plural_type.clone());
+                        find_option(options, rules.select(n_minus_offset).as_str())
+                    })
+                    .or_else(|| find_option(options, "other"))
+                    .ok_or_else(|| FormatError::MissingOtherClause(value.to_string()))?;
+                format_message(parts, &option.value, locale, values, Some(n_minus_offset))?;
+            }
+            AstElement::Pound(_) => match plural_value {
+                Some(n) => push_text(parts, &format_number(n, locale, &Default::default())),
+                // Parsed ASTs only have `#` in plural arguments, otherwise it is a literal.
+                None => push_text(parts, "#"),
+            },
+            AstElement::Tag { value, children, .. } => {
+                let mut tag_children = vec![];
+                format_message(&mut tag_children, children, locale, values, plural_value)?;
+                parts.push(RichText::Tag { name: value.to_string(), children: tag_children });
+            }
+            AstElement::Invalid(_) => (),
+        }
+    }
+    Ok(())
+}
+
+fn render_rich_text(
+    output: &mut String,
+    parts: &[RichText],
+    values: &HashMap<&str, FormatValue>,
+) -> Result<()> {
+    for part in parts {
+        match part {
+            RichText::Text(text) => output.push_str(text),
+            RichText::Tag { name, children } => match get_value(values, name)? {
+                FormatValue::Tag(render) => {
+                    let mut rendered_children = String::new();
+                    render_rich_text(&mut rendered_children, children, values)?;
+                    output.push_str(&render(rendered_children));
+                }
+                _ => return Err(invalid_value_type(name, "a tag")),
+            },
+        }
+    }
+    Ok(())
+}
+
+fn push_text(parts: &mut Vec<RichText>, text: &str) {
+    if let Some(RichText::Text(last)) = parts.last_mut() {
+        last.push_str(text);
+    } else {
+        parts.push(RichText::Text(text.to_string()));
+    }
+}
+
+fn get_value<'a, 'v>(
+    values: &'a HashMap<&str, FormatValue<'v>>,
+    name: &str,
+) -> Result<&'a FormatValue<'v>> {
+    values.get(name).ok_or_else(|| FormatError::MissingValue(name.to_string()))
+}
+
+fn get_number(values: &HashMap<&str, FormatValue>, name: &str) -> Result<f64> {
+    match get_value(values, name)? {
+        FormatValue::Number(n) => Ok(*n),
+        _ => Err(invalid_value_type(name, "a number")),
+    }
+}
+
+fn invalid_value_type(name: &str, expected: &'static str) -> FormatError {
+    FormatError::InvalidValueType { name: name.to_string(), expected }
+}
+
+fn unsupported_style(name: &str, style: &'static str) -> FormatError {
+    FormatError::UnsupportedStyle { name: name.to_string(), style }
+}
+
+fn find_option<'a, 's>(
+    options: &'a PluralOrSelectOptions<'s>,
+    selector: &str,
+) -> Option<&'a PluralOrSelectOption<'s>> {
+    options.0.iter().find(|(s, _)| s == selector).map(|(_, option)| option)
+}
+
+/// The option whose `=N` selector equals the number.
+fn find_exact_option<'a, 's>(
+    options: &'a PluralOrSelectOptions<'s>,
+    n: f64,
+) -> Option<&'a PluralOrSelectOption<'s>> {
+    options
+        .0
+        .iter()
+        .find(|(s, _)| s.strip_prefix('=').and_then(|s| s.parse::<f64>().ok()) == Some(n))
+        .map(|(_, option)| option)
+}
+
+/// Convert the number to a string like `String(n)` in JS, which is how formatjs looks up the
+/// option of a `select` argument.
+fn js_number_to_string(n: f64) -> String {
+    if n.is_nan() {
+        "NaN".to_string()
+    } else if n.is_infinite() {
+        if n > 0.0 { "Infinity" } else { "-Infinity" }.to_string()
+    } else if n == 0.0 {
+        "0".to_string()
+    } else {
+        n.to_string()
+    }
+}
+
+/// The `Intl.NumberFormat` options of a `number` argument like formatjs resolves them, or the
+/// style that `format_number` does not support.
+pub(crate) fn number_format_options(
+    style: Option<&NumberArgStyle>,
+) -> std::result::Result<JsIntlNumberFormatOptions, &'static str> {
+    let options = match style {
+        Some(NumberArgStyle::Style(style)) if style == "percent" => JsIntlNumberFormatOptions {
+            style: Some(NumberFormatStyle::Percent),
+            ..Default::default()
+        },
+        Some(NumberArgStyle::Style(style)) if style == "integer" => {
+            JsIntlNumberFormatOptions { maximum_fraction_digits: Some(0), ..Default::default() }
+        }
+        Some(NumberArgStyle::Style(style)) if style == "currency" => {
+            return Err(NumberFormatStyle::Currency.as_str())
+        }
+        Some(NumberArgStyle::Skeleton(NumberSkeleton {
+            parsed_options: Some(options), ..
+        })) => options.clone(),
+        // Like formatjs, unknown styles are formatted as plain numbers.
+        _ => JsIntlNumberFormatOptions::default(),
+    };
+    if let Some(style @ (NumberFormatStyle::Currency | NumberFormatStyle::Unit)) = options.style {
+        return Err(style.as_str());
+    }
+    match options.notation {
+        Some(notation @ (Notation::Compact | Notation::Scientific | Notation::Engineering)) => {
+            Err(notation.as_str())
+        }
+        _ => Ok(options),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use pretty_assertions::assert_eq;
+
+    fn format_str(message: &str, values: &HashMap<&str, FormatValue>) -> Result<String> {
+        let ast = Parser::new(message, None).parse().unwrap();
+        format(&ast, "en", values)
+    }
+
+    macro_rules! values {
+        ($($name:expr => $value:expr),* $(,)?) => {{
+            #[allow(unused_mut)]
+            let mut values: HashMap<&str, FormatValue> = HashMap::new();
+            $(values.insert($name, $value.into());)*
+            values
+        }};
+    }
+
+    #[test]
+    fn format_arguments() {
+        assert_eq!(
+            format_str("Hello {name}, you are {age}!", &values! {"name" => "Ann", "age" => 42}),
+            Ok("Hello Ann, you are 42!".to_string())
+        );
+        assert_eq!(
+            format_str("Hello {name}!", &values! {}),
+            Err(FormatError::MissingValue("name".to_string()))
+        );
+    }
+
+    #[test]
+    fn format_numbers() {
+        let values = values! {"n" => 0.1234};
+        assert_eq!(format_str("{n}", &values), Ok("0.123".to_string()));
+        assert_eq!(format_str("{n, number}", &values), Ok("0.123".to_string()));
+        assert_eq!(format_str("{n, number, percent}", &values), Ok("12%".to_string()));
+        assert_eq!(format_str("{n, number, ::percent .0}", &values), Ok("12.3%".to_string()));
+        assert_eq!(format_str("{n, number, ::.00##}", &values), Ok("0.1234".to_string()));
+        assert_eq!(format_str("{n, number, ::.00##}", &values! {"n" => 2}), Ok("2.00".to_string()));
+        assert_eq!(format_str("{n}", &values! {"n" => f64::INFINITY}), Ok("∞".to_string()));
+        assert_eq!(
+            format_str("{n, plural, other {#}}", &values! {"n" => f64::NAN}),
+            Ok("NaN".to_string())
+        );
+        assert_eq!(
+            format_str("{n, number, ::percent .00}", &values! {"n" => f64::NEG_INFINITY}),
+            Ok("-∞%".to_string())
+        );
+        assert_eq!(
+            format_str("{n, number, ::.00}", &values! {"n" => f64::NAN}),
+            Ok("NaN".to_string())
+        );
+        assert_eq!(
+            format_str("{n, number}", &values! {"n" => "x"}),
+            Err(FormatError::InvalidValueType { name: "n".to_string(), expected: "a number" })
+        );
+    }
+
+    #[test]
+    fn format_locale_numbers() {
+        let ast =
+            Parser::new("{n} {n, number, percent} {n, plural, other {#}}", None).parse().unwrap();
+        let values = values! {"n" => 1234.5};
+        assert_eq!(format(&ast, "en", &values), Ok("1,234.5 123,450% 1,234.5".to_string()));
+        assert_eq!(format(&ast, "de-CH", &values), Ok("1'234.5 123'450% 1'234.5".to_string()));
+        assert_eq!(format(&ast, "ar-EG", &values), Ok("١٬٢٣٤٫٥ ١٢٣٬٤٥٠٪؜ ١٬٢٣٤٫٥".to_string()));
+    }
+
+    #[test]
+    fn format_unsupported_styles() {
+        let values = values! {"n" => 1};
+        let unsupported =
+            |style| Err(FormatError::UnsupportedStyle { name: "n".to_string(), style });
+        assert_eq!(format_str("{n, number, currency}", &values), unsupported("currency"));
+        assert_eq!(format_str("{n, number, ::currency/EUR}", &values), unsupported("currency"));
+        assert_eq!(format_str("{n, number, ::unit/meter}", &values), unsupported("unit"));
+        assert_eq!(format_str("{n, number, ::compact-short}", &values), unsupported("compact"));
+        assert_eq!(format_str("{n, number, ::scientific}", &values), unsupported("scientific"));
+        assert_eq!(format_str("{n, date, short}", &values), unsupported("date"));
+        assert_eq!(format_str("{n, time}", &values! {"n" => "12:00"}), unsupported("time"));
+    }
+
+    #[test]
+    fn format_select() {
+        let message = "{gender, select, female {She} male {He} other {They}} left";
+        assert_eq!(format_str(message, &values! {"gender" => "male"}), Ok("He left".to_string()));
+        assert_eq!(format_str(message, &values! {"gender" => "x"}), Ok("They left".to_string()));
+        assert_eq!(
+            format_str("{g, select, a {A}}", &values! {"g" => "b"}),
+            Err(FormatError::MissingOtherClause("g".to_string()))
+        );
+        assert_eq!(
+            format_str("{n, select, 1 {one} other {other}}", &values! {"n" => 1}),
+            Ok("one".to_string())
+        );
+    }
+
+    #[test]
+    fn format_plural() {
+        let message = "{n, plural, offset:1 =0 {nobody} =1 {you} one {you and # other} \
+                       other {you and # others}}";
+        assert_eq!(format_str(message, &values! {"n" => 0}), Ok("nobody".to_string()));
+        assert_eq!(format_str(message, &values! {"n" => 1}), Ok("you".to_string()));
+        assert_eq!(format_str(message, &values! {"n" => 2}), Ok("you and 1 other".to_string()));
+        assert_eq!(format_str(message, &values! {"n" => 5}), Ok("you and 4 others".to_string()));
+        // `=1` only matches 1 exactly, even if the number is rounded to 1 when formatted.
+        assert_eq!(
+            format_str("{n, plural, =1 {one} other {#}}", &values! {"n" => 1.0004}),
+            Ok("1".to_string())
+        );
+    }
+
+    #[test]
+    fn format_selectordinal() {
+        let message = "{n, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}";
+        for (n, expected) in &[(1, "1st"), (2, "2nd"), (3, "3rd"), (11, "11th"), (22, "22nd")] {
+            assert_eq!(format_str(message, &values! {"n" => *n}), Ok(expected.to_string()));
+        }
+    }
+
+    #[test]
+    fn format_tags() {
+        let values = values! {
+            "n" => 3,
+            "b" => FormatValue::Tag(Box::new(|children| format!("<strong>{}</strong>", children))),
+        };
+        assert_eq!(
+            format_str("{n, plural, other {<b>#</b> items}}", &values),
+            Ok("<strong>3</strong> items".to_string())
+        );
+        assert_eq!(
+            format_str("<i>x</i>", &values),
+            Err(FormatError::MissingValue("i".to_string()))
+        );
+    }
+
+    #[test]
+    fn format_rich_text() {
+        let ast = Parser::new("Hi <b>{name} <i>!</i></b>", None).parse().unwrap();
+        assert_eq!(
+            format_to_rich_text(&ast, "en", &values! {"name" => "Ann"}),
+            Ok(vec![
+                RichText::Text("Hi ".to_string()),
+                RichText::Tag {
+                    name: "b".to_string(),
+                    children: vec![
+                        RichText::Text("Ann ".to_string()),
+                        RichText::Tag {
+                            name: "i".to_string(),
+                            children: vec![RichText::Text("!".to_string())]
+                        },
+                    ]
+                },
+            ])
+        );
+    }
+}
@@ -0,0 +1,1034 @@
+use crate::ast::{
+    AstElement, DateTimeArgStyle, DateTimeSkeleton, NumberArgStyle, NumberSkeleton,
+    PluralOrSelectOptions, PluralType,
+};
+use crate::js_intl::{
+    CompactDisplay, CurrencyDisplay, CurrencySign, DateTimeNumericStyle, DateTimeTextStyle,
+    HourCycle, JsIntlDateTimeFormatOptions, JsIntlNumberFormatOptions, MonthStyle, Notation,
+    NumberFormatStyle, SignDisplay, TimeZoneNameStyle, TrailingZeroDisplay, UnitDisplay,
+};
+use crate::typescript::{is_identifier, string_literal};
+use std::collections::HashSet;
+use std::fmt::{Display, Write};
+
+/// Words that cannot name the functions of the module, and the names of its constants that are
+/// not numbered like the formatters.
+const RESERVED_NAMES: &[&str] = &[
+    "arguments",
+    "await",
+    "break",
+    "cardinal",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "locale",
+    "new",
+    "null",
+    "ordinal",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Compile the parsed messages of a catalog of message ids to an ES module of JavaScript
+/// functions for the locale, so that they can be formatted without parsing or interpreting
+/// the messages at runtime.
+///
+/// Each message becomes an exported function of its values, named after its id in camelCase,
+/// e.g. for the message `app.inbox` = `{count, plural, one {# <b>new</b> message} other {...}}`:
+///
+/// ```js
+/// export function appInbox(values) {
+///   const parts = [];
+///   const n0 = values.count;
+///   switch (cardinal.select(n0)) {
+///     case 'one':
+///       parts.push(`${numberFormat.format(n0)} `, values.b(['new']), ' message');
+///       break;
+///   ...
+/// ```
+///
+/// The functions of messages with tags return an array of parts, where the tags are rendered
+/// by the functions in the values with the same name, and the other functions return a string.
+/// Other values are converted to strings.
+/// Plurals use `Intl.PluralRules` and numbers, dates and times use the `Intl` formatters,
+/// which are created once for the module with the options of their style or skeleton. Like
+/// formatjs, a plural or select without a matching option or `other` option throws.
+///
+/// The default export maps the ids to the functions. The messages are sorted by id so that the
+/// output is stable.
+pub fn compile_to_javascript<'a, 's: 'a, I, K>(messages: I, locale: &str) -> String
+where
+    I: IntoIterator<Item = (K, &'a [AstElement<'s>])>,
+    K: AsRef<str>,
+{
+    let mut messages: Vec<(K, &[AstElement])> = messages.into_iter().collect();
+    messages.sort_by(|(a, _), (b, _)| a.as_ref().cmp(b.as_ref()));
+
+    let mut module = Module {
+        names: RESERVED_NAMES.iter().map(|name| name.to_string()).collect(),
+        cardinal: false,
+        ordinal: false,
+        formats: vec![],
+    };
+    // Name the functions first, so that the formatters are named around them.
+    let function_names: Vec<String> =
+        messages.iter().map(|(id, _)| module.unique_name(&function_name(id.as_ref()))).collect();
+
+    let mut functions = String::new();
+    for ((_, ast), name) in messages.iter().zip(&function_names) {
+        writeln!(functions).unwrap();
+        let mut function = Function { module: &mut module, body: String::new(), variables: 0 };
+        function.compile(ast);
+        writeln!(functions, "export function {}(values) {{", name).unwrap();
+        functions.push_str(&function.body);
+        writeln!(functions, "}}").unwrap();
+    }
+
+    let mut output = String::new();
+    writeln!(output, "// Generated from the message catalog. Do not edit.").unwrap();
+    writeln!(output, "const locale = {};", string_literal(locale)).unwrap();
+    if module.cardinal {
+        writeln!(output, "const cardinal = new Intl.PluralRules(locale);").unwrap();
+    }
+    if module.ordinal {
+        writeln!(output, "const ordinal = new Intl.PluralRules(locale, {{ type: 'ordinal' }});")
+            .unwrap();
+    }
+    for format in &module.formats {
+        if format.options == "{}" {
+            writeln!(output, "const {} = new Intl.{}(locale);", format.name, format.constructor)
+        } else {
+            writeln!(
+                output,
+                "const {} = new Intl.{}(locale, {});",
+                format.name, format.constructor, format.options
+            )
+        }
+        .unwrap();
+    }
+    output.push_str(&functions);
+
+    writeln!(output).unwrap();
+    writeln!(output, "export default {{").unwrap();
+    for ((id, _), name) in messages.iter().zip(&function_names) {
+        writeln!(output, "  {}: {},", string_literal(id.as_ref()), name).unwrap();
+    }
+    writeln!(output, "}};").unwrap();
+    output
+}
+
+/// The camelCase function name of the message id, e.g. `appInbox` for `app.inbox`.
+fn function_name(id: &str) -> String {
+    let mut name = String::new();
+    for word in id.split(|c: char| !c.is_ascii_alphanumeric()).filter(|word| !word.is_empty()) {
+        let mut chars = word.chars();
+        let first = chars.next().unwrap();
+        if name.is_empty() {
+            name.push(first.to_ascii_lowercase());
+        } else {
+            name.push(first.to_ascii_uppercase());
+        }
+        name.extend(chars);
+    }
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name.insert(0, '_');
+    }
+    name
+}
+
+/// The constants shared by the functions of the module.
+struct Module {
+    /// The names of the functions and constants of the module.
+    names: HashSet<String>,
+    cardinal: bool,
+    ordinal: bool,
+    formats: Vec<Format>,
+}
+
+/// An `Intl` formatter.
+struct Format {
+    name: String,
+    constructor: &'static str,
+    /// The options as a JavaScript object literal.
+    options: String,
+}
+
+impl Module {
+    fn unique_name(&mut self, name: &str) -> String {
+        let mut unique_name = name.to_string();
+        let mut suffix = 2;
+        while !self.names.insert(unique_name.clone()) {
+            unique_name = format!("{}{}", name, suffix);
+            suffix += 1;
+        }
+        unique_name
+    }
+
+    /// The name of the formatter with the options, which is added if it does not exist yet.
+    fn format(&mut self, constructor: &'static str, options: String) -> String {
+        if let Some(format) = self
+            .formats
+            .iter()
+            .find(|format| format.constructor == constructor && format.options == options)
+        {
+            return format.name.clone();
+        }
+        let name = match constructor {
+            "NumberFormat" => self.unique_name("numberFormat"),
+            _ => self.unique_name("dateTimeFormat"),
+        };
+        self.formats.push(Format { name: name.clone(), constructor, options });
+        name
+    }
+
+    fn number_format(&mut self, options: &JsIntlNumberFormatOptions) -> String {
+        let options = ObjectLiteral::default()
+            .string("style", options.style.map(NumberFormatStyle::as_str))
+            .string("currency", options.currency.as_deref())
+            .string("currencyDisplay", options.currency_display.map(CurrencyDisplay::as_str))
+            .string("currencySign", options.currency_sign.map(CurrencySign::as_str))
+            .string("unit", options.unit.as_deref())
+            .string("unitDisplay", options.unit_display.map(UnitDisplay::as_str))
+            .value("useGrouping", options.use_grouping)
+            .string("notation", options.notation.map(Notation::as_str))
+            .string("compactDisplay", options.compact_display.map(CompactDisplay::as_str))
+            .string("signDisplay", options.sign_display.map(SignDisplay::as_str))
+            .value("minimumIntegerDigits", options.minimum_integer_digits)
+            .value("minimumFractionDigits", options.minimum_fraction_digits)
+            .value("maximumFractionDigits", options.maximum_fraction_digits)
+            .value("minimumSignificantDigits", options.minimum_significant_digits)
+            .value("maximumSignificantDigits", options.maximum_significant_digits)
+            .string(
+                "trailingZeroDisplay",
+                options.trailing_zero_display.map(TrailingZeroDisplay::as_str),
+            )
+            .value("scale", options.scale)
+            .finish();
+        self.format("NumberFormat", options)
+    }
+
+    fn date_time_format(&mut self, options: &JsIntlDateTimeFormatOptions) -> String {
+        let options = ObjectLiteral::default()
+            .string("era", options.era.map(DateTimeTextStyle::as_str))
+            .string("year", options.year.map(DateTimeNumericStyle::as_str))
+            .string("month", options.month.map(MonthStyle::as_str))
+            .string("day", options.day.map(DateTimeNumericStyle::as_str))
+            .string("weekday", options.weekday.map(DateTimeTextStyle::as_str))
+            .string("hour", options.hour.map(DateTimeNumericStyle::as_str))
+            .value("hour12", options.hour12)
+            .string("hourCycle", options.hour_cycle.map(HourCycle::as_str))
+            .string("minute", options.minute.map(DateTimeNumericStyle::as_str))
+            .string("second", options.second.map(DateTimeNumericStyle::as_str))
+            .string("timeZoneName", options.time_zone_name.map(TimeZoneNameStyle::as_str))
+            .finish();
+        self.format("DateTimeFormat", options)
+    }
+}
+
+/// An object literal of options, with the fields in the order they are added. Missing options
+/// are left out.
+#[derive(Default)]
+struct ObjectLiteral(Vec<String>);
+
+impl ObjectLiteral {
+    fn string(mut self, key: &str, value: Option<&str>) -> Self {
+        if let Some(value) = value {
+            self.0.push(format!("{}: {}", key, string_literal(value)));
+        }
+        self
+    }
+
+    /// A number or a boolean.
+    fn value(mut self, key: &str, value: Option<impl Display>) -> Self {
+        if let Some(value) = value {
+            self.0.push(format!("{}: {}", key, value));
+        }
+        self
+    }
+
+    fn finish(self) -> String {
+        if self.0.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{ {} }}", self.0.join(", "))
+        }
+    }
+}
+
+/// Where a sequence of statements appends the formatted message.
+enum Target {
+    /// A string variable.
+    Text(String),
+    /// An array variable of parts.
+    Parts(String),
+}
+
+/// Compiles the body of the function of a message.
+struct Function<'m> {
+    module: &'m mut Module,
+    body: String,
+    /// The number of local variables so far, which numbers their names.
+    variables: usize,
+}
+
+impl<'m> Function<'m> {
+    fn compile(&mut self, ast: &[AstElement]) {
+        let has_tags = has_tags(ast);
+        if is_flat(ast) {
+            let value = if has_tags {
+                format!("[{}]", self.parts(ast, None).join(", "))
+            } else {
+                self.template(ast, None).unwrap_or_else(|| "''".to_string())
+            };
+            self.line(1, &format!("return {};", value));
+            return;
+        }
+
+        let target = if has_tags {
+            self.line(1, "const parts = [];");
+            Target::Parts("parts".to_string())
+        } else {
+            self.line(1, "let s = '';");
+            Target::Text("s".to_string())
+        };
+        self.statements(ast, &target, None, 1);
+        match target {
+            Target::Text(name) | Target::Parts(name) => self.line(1, &format!("return {};", name)),
+        }
+    }
+
+    fn line(&mut self, indent: usize, line: &str) {
+        for _ in 0..indent {
+            self.body.push_str("  ");
+        }
+        self.body.push_str(line);
+        self.body.push('\n');
+    }
+
+    fn variable(&mut self, prefix: &str) -> String {
+        let name = format!("{}{}", prefix, self.variables);
+        self.variables += 1;
+        name
+    }
+
+    /// Append the elements to the target.
+    ///
+    /// * `plural_value` - The expression of the value of the closest enclosing `plural`
+    ///   argument, minus its offset. This is what `#` is replaced with.
+    fn statements(
+        &mut self,
+        elements: &[AstElement],
+        target: &Target,
+        plural_value: Option<&str>,
+        indent: usize,
+    ) {
+        let mut start = 0;
+        for (index, element) in elements.iter().enumerate() {
+            if is_flat(std::slice::from_ref(element)) {
+                continue;
+            }
+            self.append(&elements[start..index], target, plural_value, indent);
+            start = index + 1;
+            match element {
+                AstElement::Select { value, options, .. } => {
+                    self.select(value, options, target, plural_value, indent)
+                }
+                AstElement::Plural { value, plural_type, offset, options, .. } => {
+                    self.plural(value, plural_type, *offset, options, target, indent)
+                }
+                AstElement::Tag { value, children, .. } => {
+                    let children_name = self.variable("children");
+                    self.line(indent, &format!("const {} = [];", children_name));
+                    let children_target = Target::Parts(children_name.clone());
+                    self.statements(children, &children_target, plural_value, indent);
+                    let part = format!("{}({})", argument(value), children_name);
+                    self.append_parts(target, &[part], indent);
+                }
+                _ => unreachable!(),
+            }
+        }
+        self.append(&elements[start..], target, plural_value, indent);
+    }
+
+    /// Append the flat elements to the target.
+    fn append(
+        &mut self,
+        elements: &[AstElement],
+        target: &Target,
+        plural_value: Option<&str>,
+        indent: usize,
+    ) {
+        match target {
+            Target::Text(name) => {
+                if let Some(template) = self.template(elements, plural_value) {
+                    self.line(indent, &format!("{} += {};", name, template));
+                }
+            }
+            Target::Parts(_) => {
+                let parts = self.parts(elements, plural_value);
+                self.append_parts(target, &parts, indent);
+            }
+        }
+    }
+
+    fn append_parts(&mut self, target: &Target, parts: &[String], indent: usize) {
+        if parts.is_empty() {
+            return;
+        }
+        match target {
+            Target::Parts(name) => {
+                self.line(indent, &format!("{}.push({});", name, parts.join(", ")))
+            }
+            // Only messages with tags have parts.
+            Target::Text(_) => unreachable!(),
+        }
+    }
+
+    fn select(
+        &mut self,
+        value: &str,
+        options: &PluralOrSelectOptions,
+        target: &Target,
+        plural_value: Option<&str>,
+        indent: usize,
+    ) {
+        // Like formatjs, which looks the option up by the value converted to a string.
+        self.line(indent, &format!("switch (String({})) {{", argument(value)));
+        for (selector, option) in &options.0 {
+            if selector != "other" {
+                self.line(indent + 1, &format!("case {}:", string_literal(selector)));
+                self.statements(&option.value, target, plural_value, indent + 2);
+                self.line(indent + 2, "break;");
+            }
+        }
+        self.line(indent + 1, "default:");
+        self.other(value, options, target, plural_value, indent + 2);
+        self.line(indent, "}");
+    }
+
+    fn plural(
+        &mut self,
+        value: &str,
+        plural_type: &PluralType,
+        offset: i64,
+        options: &PluralOrSelectOptions,
+        target: &Target,
+        indent: usize,
+    ) {
+        let n = self.variable("n");
+        self.line(indent, &format!("const {} = {};", n, argument(value)));
+        let value_minus_offset =
+            if offset == 0 { n.clone() } else { format!("{} - {}", n, offset) };
+        let plural_value = Some(value_minus_offset.as_str());
+
+        // Exact matches take precedence over the plural categories.
+        let mut indent = indent;
+        let mut has_exact_matches = false;
+        for (selector, option) in &options.0 {
+            let exact_value = match selector.strip_prefix('=') {
+                Some(exact_value) => exact_value,
+                None => continue,
+            };
+            // The value is printed as parsed, since e.g. `=01` would be an octal literal in JS.
+            // Values that do not fit in `i64`, which only ASTs built without the parser have,
+            // are converted by JS like the formatter does.
+            let exact_value = match exact_value.parse::<i64>() {
+                Ok(exact_value) => exact_value.to_string(),
+                Err(_) => format!("Number({})", string_literal(exact_value)),
+            };
+            let condition = format!("if ({} === {}) {{", n, exact_value);
+            if has_exact_matches {
+                self.line(indent, &format!("}} else {}", condition));
+            } else {
+                self.line(indent, &condition);
+            }
+            self.statements(&option.value, target, plural_value, indent + 1);
+            has_exact_matches = true;
+        }
+        if has_exact_matches {
+            self.line(indent, "} else {");
+            indent += 1;
+        }
+
+        let categories: Vec<_> = options
+            .0
+            .iter()
+            .filter(|(selector, _)| !selector.starts_with('=') && selector != "other")
+            .collect();
+        if categories.is_empty() {
+            self.other(value, options, target, plural_value, indent);
+        } else {
+            let rules = match plural_type {
+                PluralType::Cardinal => {
+                    self.module.cardinal = true;
+                    "cardinal"
+                }
+                PluralType::Ordinal => {
+                    self.module.ordinal = true;
+                    "ordinal"
+                }
+            };
+            self.line(indent, &format!("switch ({}.select({})) {{", rules, value_minus_offset));
+            for (selector, option) in categories {
+                self.line(indent + 1, &format!("case {}:", string_literal(selector)));
+                self.statements(&option.value, target, plural_value, indent + 2);
+                self.line(indent + 2, "break;");
+            }
+            self.line(indent + 1, "default:");
+            self.other(value, options, target, plural_value, indent + 2);
+            self.line(indent, "}");
+        }
+
+        if has_exact_matches {
+            self.line(indent - 1, "}");
+        }
+    }
+
+    /// Append the `other` option, or throw like formatjs if there is none.
+    fn other(
+        &mut self,
+        value: &str,
+        options: &PluralOrSelectOptions,
+        target: &Target,
+        plural_value: Option<&str>,
+        indent: usize,
+    ) {
+        match options.0.iter().find(|(selector, _)| selector == "other") {
+            Some((_, option)) => self.statements(&option.value, target, plural_value, indent),
+            None => {
+                let message = format!("No matching option or \"other\" option for \"{}\"", value);
+                self.line(indent, &format!("throw new Error({});", string_literal(&message)));
+            }
+        }
+    }
+
+    /// The template literal of the elements without tags, or `None` if they are empty.
+    fn template(&mut self, elements: &[AstElement], plural_value: Option<&str>) -> Option<String> {
+        // The text of the elements if they have no substitutions, and the body of their template
+        // literal otherwise.
+        let mut text = String::new();
+        let mut template = String::new();
+        let mut has_substitutions = false;
+        for element in elements {
+            let substitution = match element {
+                AstElement::Literal { value, .. } => {
+                    text.push_str(value);
+                    push_template_text(&mut template, value);
+                    continue;
+                }
+                AstElement::Argument { value, .. } => argument(value),
+                AstElement::Number { value, style, .. } => self.number(value, style.as_ref()),
+                AstElement::Date { value, style, .. } => {
+                    let options = match style {
+                        Some(style) => date_time_options(style, date_style),
+                        None => JsIntlDateTimeFormatOptions::default(),
+                    };
+                    format!(
+                        "{}.format({})",
+                        self.module.date_time_format(&options),
+                        argument(value)
+                    )
+                }
+                AstElement::Time { value, style, .. } => {
+                    let options = match style {
+                        Some(style) => date_time_options(style, time_style),
+                        None => time_style("medium").unwrap(),
+                    };
+                    format!(
+                        "{}.format({})",
+                        self.module.date_time_format(&options),
+                        argument(value)
+                    )
+                }
+                AstElement::Pound(_) => match plural_value {
+                    Some(plural_value) => {
+                        let format =
+                            self.module.number_format(&JsIntlNumberFormatOptions::default());
+                        format!("{}.format({})", format, plural_value)
+                    }
+                    // Parsed ASTs only have `#` in plural arguments, otherwise it is a literal.
+                    None => {
+                        text.push('#');
+                        template.push('#');
+                        continue;
+                    }
+                },
+                AstElement::Invalid(_) => continue,
+                AstElement::Select { .. } | AstElement::Plural { .. } | AstElement::Tag { .. } => {
+                    unreachable!()
+                }
+            };
+            write!(template, "${{{}}}", substitution).unwrap();
+            has_substitutions = true;
+        }
+        if has_substitutions {
+            Some(format!("`{}`", template))
+        } else if text.is_empty() {
+            None
+        } else {
+            Some(string_literal(&text))
+        }
+    }
+
+    /// The parts of the flat elements: the template literals of the text between tags, and
+    /// the calls of the tags.
+    fn parts(&mut self, elements: &[AstElement], plural_value: Option<&str>) -> Vec<String> {
+        let mut parts = vec![];
+        let mut start = 0;
+        for (index, element) in elements.iter().enumerate() {
+            if let AstElement::Tag { value, children, .. } = element {
+                parts.extend(self.template(&elements[start..index], plural_value));
+                let children = self.parts(children, plural_value).join(", ");
+                parts.push(format!("{}([{}])", argument(value), children));
+                start = index + 1;
+            }
+        }
+        parts.extend(self.template(&elements[start..], plural_value));
+        parts
+    }
+
+    fn number(&mut self, value: &str, style: Option<&NumberArgStyle>) -> String {
+        let mut options = JsIntlNumberFormatOptions::default();
+        let mut scale = None;
+        match style {
+            Some(NumberArgStyle::Style(style)) if style == "percent" => {
+                options.style = Some(NumberFormatStyle::Percent)
+            }
+            Some(NumberArgStyle::Style(style)) if style == "integer" => {
+                options.maximum_fraction_digits = Some(0)
+            }
+            Some(NumberArgStyle::Skeleton(NumberSkeleton {
+                parsed_options: Some(parsed_options),
+                ..
+            })) => {
+                options = parsed_options.clone();
+                // `scale` is not an `Intl.NumberFormat` option.
+                scale = options.scale.take();
+            }
+            // Like formatjs, unknown styles are formatted as plain numbers.
+            _ => {}
+        }
+        let format = self.module.number_format(&options);
+        match scale {
+            Some(scale) => format!("{}.format({} * {})", format, argument(value), scale),
+            None => format!("{}.format({})", format, argument(value)),
+        }
+    }
+}
+
+fn push_template_text(template: &mut String, text: &str) {
+    for ch in text.chars() {
+        match ch {
+            '`' => template.push_str("\\`"),
+            '$' => template.push_str("\\$"),
+            '\\' => template.push_str("\\\\"),
+            '\n' => template.push_str("\\n"),
+            '\r' => template.push_str("\\r"),
+            ch => template.push(ch),
+        }
+    }
+}
+
+/// The expression of the value of the argument.
+fn argument(name: &str) -> String {
+    if is_identifier(name) {
+        format!("values.{}", name)
+    } else {
+        format!("values[{}]", string_literal(name))
+    }
+}
+
+/// Whether the elements have no `select` or `plural` arguments, so that they can be compiled
+/// to an expression.
+fn is_flat(elements: &[AstElement]) -> bool {
+    elements.iter().all(|element| match element {
+        AstElement::Select { .. } | AstElement::Plural { .. } => false,
+        AstElement::Tag { children, .. } => is_flat(children),
+        _ => true,
+    })
+}
+
+fn has_tags(elements: &[AstElement]) -> bool {
+    elements.iter().any(|element| match element {
+        AstElement::Tag { .. } => true,
+        AstElement::Select { options, .. } | AstElement::Plural { options, .. } => {
+            options.0.iter().any(|(_, option)| has_tags(&option.value))
+        }
+        _ => false,
+    })
+}
+
+fn date_time_options(
+    style: &DateTimeArgStyle,
+    named_style: fn(&str) -> Option<JsIntlDateTimeFormatOptions>,
+) -> JsIntlDateTimeFormatOptions {
+    match style {
+        DateTimeArgStyle::Style(style) => named_style(style).unwrap_or_default(),
+        DateTimeArgStyle::Skeleton(DateTimeSkeleton { parsed_options, .. }) => {
+            parsed_options.clone().unwrap_or_default()
+        }
+    }
+}
+
+/// The options of the date styles of formatjs.
+fn date_style(style: &str) -> Option<JsIntlDateTimeFormatOptions> {
+    let (weekday, month, year) = match style {
+        "short" => (None, MonthStyle::Numeric, DateTimeNumericStyle::TwoDigit),
+        "medium" => (None, MonthStyle::Short, DateTimeNumericStyle::Numeric),
+        "long" => (None, MonthStyle::Long, DateTimeNumericStyle::Numeric),
+        "full" => (Some(DateTimeTextStyle::Long), MonthStyle::Long, DateTimeNumericStyle::Numeric),
+        _ => return None,
+    };
+    Some(JsIntlDateTimeFormatOptions {
+        weekday,
+        month: Some(month),
+        day: Some(DateTimeNumericStyle::Numeric),
+        year: Some(year),
+        ..Default::default()
+    })
+}
+
+/// The options of the time styles of formatjs.
+fn time_style(style: &str) -> Option<JsIntlDateTimeFormatOptions> {
+    let (second, time_zone_name) = match style {
+        "short" => (None, None),
+        "medium" => (Some(DateTimeNumericStyle::Numeric), None),
+        "long" | "full" => (Some(DateTimeNumericStyle::Numeric), Some(TimeZoneNameStyle::Short)),
+        _ => return None,
+    };
+    Some(JsIntlDateTimeFormatOptions {
+        hour: Some(DateTimeNumericStyle::Numeric),
+        minute: Some(DateTimeNumericStyle::Numeric),
+        second,
+        time_zone_name,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{Parser, ParserOptions};
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    fn compile(messages: &[(&str, &str)], locale: &str) -> String {
+        let options = ParserOptions { locale: Some(locale.to_string()), ..Default::default() };
+        let asts: Vec<_> = messages
+            .iter()
+            .map(|(id, message)| (*id, Parser::new(message, Some(&options)).parse().unwrap()))
+            .collect();
+        compile_to_javascript(asts.iter().map(|(id, ast)| (*id, ast.as_slice())), locale)
+    }
+
+    #[test]
+    fn compile_catalog() {
+        let messages = [
+            (
+                "app.inbox",
+                "{count, plural, =0 {No messages} one {# <b>new</b> message} other {# <b>new</b> messages}}",
+            ),
+            ("greeting", "Hello {name}, it is {now, time, short} on {now, date, full}"),
+            ("pronoun", "{gender, select, female {She} male {He} other {They}} replied"),
+            ("rank", "{place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}"),
+            ("progress", "{done, number, ::percent} of {total, number}"),
+            ("empty", "No arguments"),
+        ];
+        assert_eq!(
+            compile(&messages, "en"),
+            indoc! {"
+                // Generated from the message catalog. Do not edit.
+                const locale = 'en';
+                const cardinal = new Intl.PluralRules(locale);
+                const ordinal = new Intl.PluralRules(locale, { type: 'ordinal' });
+                const numberFormat = new Intl.NumberFormat(locale);
+                const dateTimeFormat = new Intl.DateTimeFormat(locale, { hour: 'numeric', minute: 'numeric' });
+                const dateTimeFormat2 = new Intl.DateTimeFormat(locale, { year: 'numeric', month: 'long', day: 'numeric', weekday: 'long' });
+                const numberFormat2 = new Intl.NumberFormat(locale, { style: 'percent' });
+
+                export function appInbox(values) {
+                  const parts = [];
+                  const n0 = values.count;
+                  if (n0 === 0) {
+                    parts.push('No messages');
+                  } else {
+                    switch (cardinal.select(n0)) {
+                      case 'one':
+                        parts.push(`${numberFormat.format(n0)} `, values.b(['new']), ' message');
+                        break;
+                      default:
+                        parts.push(`${numberFormat.format(n0)} `, values.b(['new']), ' messages');
+                    }
+                  }
+                  return parts;
+                }
+
+                export function empty(values) {
+                  return 'No arguments';
+                }
+
+                export function greeting(values) {
+                  return `Hello ${values.name}, it is ${dateTimeFormat.format(values.now)} on ${dateTimeFormat2.format(values.now)}`;
+                }
+
+                export function progress(values) {
+                  return `${numberFormat2.format(values.done)} of ${numberFormat.format(values.total)}`;
+                }
+
+                export function pronoun(values) {
+                  let s = '';
+                  switch (String(values.gender)) {
+                    case 'female':
+                      s += 'She';
+                      break;
+                    case 'male':
+                      s += 'He';
+                      break;
+                    default:
+                      s += 'They';
+                  }
+                  s += ' replied';
+                  return s;
+                }
+
+                export function rank(values) {
+                  let s = '';
+                  const n0 = values.place;
+                  switch (ordinal.select(n0)) {
+                    case 'one':
+                      s += `${numberFormat.format(n0)}st`;
+                      break;
+                    case 'two':
+                      s += `${numberFormat.format(n0)}nd`;
+                      break;
+                    case 'few':
+                      s += `${numberFormat.format(n0)}rd`;
+                      break;
+                    default:
+                      s += `${numberFormat.format(n0)}th`;
+                  }
+                  return s;
+                }
+
+                export default {
+                  'app.inbox': appInbox,
+                  'empty': empty,
+                  'greeting': greeting,
+                  'progress': progress,
+                  'pronoun': pronoun,
+                  'rank': rank,
+                };
+            "}
+        );
+    }
+
+    #[test]
+    fn compile_offsets_and_nested_tags() {
+        let messages = [
+            (
+                "guests",
+                "{host} invited {count, plural, offset:1 =0 {nobody} =1 {{guest}} other {{guest} and # others}}",
+            ),
+            ("list", "<ul>{n, plural, one {<li>one</li>} other {<li>{n, number, ::scale/100}</li>}}</ul>"),
+            ("status", "{status, select, on {On} off {Off}}"),
+        ];
+        assert_eq!(
+            compile(&messages, "fr"),
+            indoc! {"
+                // Generated from the message catalog. Do not edit.
+                const locale = 'fr';
+                const cardinal = new Intl.PluralRules(locale);
+                const numberFormat = new Intl.NumberFormat(locale);
+
+                export function guests(values) {
+                  let s = '';
+                  s += `${values.host} invited `;
+                  const n0 = values.count;
+                  if (n0 === 0) {
+                    s += 'nobody';
+                  } else if (n0 === 1) {
+                    s += `${values.guest}`;
+                  } else {
+                    s += `${values.guest} and ${numberFormat.format(n0 - 1)} others`;
+                  }
+                  return s;
+                }
+
+                export function list(values) {
+                  const parts = [];
+                  const children0 = [];
+                  const n1 = values.n;
+                  switch (cardinal.select(n1)) {
+                    case 'one':
+                      children0.push(values.li(['one']));
+                      break;
+                    default:
+                      children0.push(values.li([`${numberFormat.format(values.n * 100)}`]));
+                  }
+                  parts.push(values.ul(children0));
+                  return parts;
+                }
+
+                export function status(values) {
+                  let s = '';
+                  switch (String(values.status)) {
+                    case 'on':
+                      s += 'On';
+                      break;
+                    case 'off':
+                      s += 'Off';
+                      break;
+                    default:
+                      throw new Error('No matching option or \"other\" option for \"status\"');
+                  }
+                  return s;
+                }
+
+                export default {
+                  'guests': guests,
+                  'list': list,
+                  'status': status,
+                };
+            "}
+        );
+    }
+
+    #[test]
+    fn escape_names_and_text() {
+        let messages =
+            [("delete", "Cost: `${price}` \\ {0}\n"), ("numberFormat", "{n, number}"), ("2fa", "")];
+        assert_eq!(
+            compile(&messages, "en"),
+            indoc! {r#"
+                // Generated from the message catalog. Do not edit.
+                const locale = 'en';
+                const numberFormat2 = new Intl.NumberFormat(locale);
+
+                export function _2fa(values) {
+                  return '';
+                }
+
+                export function delete2(values) {
+                  return `Cost: \`\$${values.price}\` \\ ${values['0']}\n`;
+                }
+
+                export function numberFormat(values) {
+                  return `${numberFormat2.format(values.n)}`;
+                }
+
+                export default {
+                  '2fa': _2fa,
+                  'delete': delete2,
+                  'numberFormat': numberFormat,
+                };
+            "#}
+        );
+    }
+
+    #[test]
+    fn compile_select_keys_as_strings() {
+        let messages = [("stars", "{count, select, 1 {One star} 2 {Two stars} other {Stars}}")];
+        assert_eq!(
+            compile(&messages, "en"),
+            indoc! {"
+                // Generated from the message catalog. Do not edit.
+                const locale = 'en';
+
+                export function stars(values) {
+                  let s = '';
+                  switch (String(values.count)) {
+                    case '1':
+                      s += 'One star';
+                      break;
+                    case '2':
+                      s += 'Two stars';
+                      break;
+                    default:
+                      s += 'Stars';
+                  }
+                  return s;
+                }
+
+                export default {
+                  'stars': stars,
+                };
+            "}
+        );
+    }
+
+    #[test]
+    fn compile_exact_matches_as_numbers() {
+        let mut ast = Parser::new("{n, plural, =01 {one} =-2 {minus two} =3 {#} other {#}}", None)
+            .parse()
+            .unwrap();
+        // Selectors that do not fit in `i64` are only in ASTs built without the parser.
+        if let AstElement::Plural { options, .. } = &mut ast[0] {
+            options.0[2].0 = "=99999999999999999999".into();
+        }
+        assert_eq!(
+            compile_to_javascript([("n", ast.as_slice())], "en"),
+            indoc! {"
+                // Generated from the message catalog. Do not edit.
+                const locale = 'en';
+                const numberFormat = new Intl.NumberFormat(locale);
+
+                export function n(values) {
+                  let s = '';
+                  const n0 = values.n;
+                  if (n0 === 1) {
+                    s += 'one';
+                  } else if (n0 === -2) {
+                    s += 'minus two';
+                  } else if (n0 === Number('99999999999999999999')) {
+                    s += `${numberFormat.format(n0)}`;
+                  } else {
+                    s += `${numberFormat.format(n0)}`;
+                  }
+                  return s;
+                }
+
+                export default {
+                  'n': n,
+                };
+            "}
+        );
+    }
+}
@@ -1,15 +1,361 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Subset of options that will be parsed from the ICU message number skeleton.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+///
+/// Mirrors `Intl.NumberFormatOptions`, plus the non-standard `scale` option used by formatjs.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct JsIntlNumberFormatOptions {
-    // TODO
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<NumberFormatStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_display: Option<CurrencyDisplay>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_sign: Option<CurrencySign>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_display: Option<UnitDisplay>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_grouping: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notation: Option<Notation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compact_display: Option<CompactDisplay>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sign_display: Option<SignDisplay>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_integer_digits: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_fraction_digits: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_fraction_digits: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_significant_digits: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_significant_digits: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_zero_display: Option<TrailingZeroDisplay>,
+    /// Multiplier applied to the number before formatting (e.g. `scale/100`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale: Option<f64>,
+}
+
+// `scale` is never NaN because it is parsed from a skeleton option that must be a valid number.
+impl Eq for JsIntlNumberFormatOptions {}
+
+impl JsIntlNumberFormatOptions {
+    /// Overwrite the options with those that are set in `other`, like `{...self, ...other}` in JS.
+    pub fn merge(&mut self, other: JsIntlNumberFormatOptions) {
+        macro_rules! merge_fields {
+            ($($field:ident),*) => {
+                $(
+                    if other.$field.is_some() {
+                        self.$field = other.$field;
+                    }
+                )*
+            };
+        }
+        merge_fields!(
+            style,
+            currency,
+            currency_display,
+            currency_sign,
+            unit,
+            unit_display,
+            use_grouping,
+            notation,
+            compact_display,
+            sign_display,
+            minimum_integer_digits,
+            minimum_fraction_digits,
+            maximum_fraction_digits,
+            minimum_significant_digits,
+            maximum_significant_digits,
+            trailing_zero_display,
+            scale
+        );
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NumberFormatStyle {
+    Decimal,
+    Percent,
+    Currency,
+    Unit,
+}
+
+impl NumberFormatStyle {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NumberFormatStyle::Decimal => "decimal",
+            NumberFormatStyle::Percent => "percent",
+            NumberFormatStyle::Currency => "currency",
+            NumberFormatStyle::Unit => "unit",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CurrencyDisplay {
+    Symbol,
+    NarrowSymbol,
+    Code,
+    Name,
+}
+
+impl CurrencyDisplay {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CurrencyDisplay::Symbol => "symbol",
+            CurrencyDisplay::NarrowSymbol => "narrowSymbol",
+            CurrencyDisplay::Code => "code",
+            CurrencyDisplay::Name => "name",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CurrencySign {
+    Standard,
+    Accounting,
+}
+
+impl CurrencySign {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CurrencySign::Standard => "standard",
+            CurrencySign::Accounting => "accounting",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnitDisplay {
+    Short,
+    Narrow,
+    Long,
+}
+
+impl UnitDisplay {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UnitDisplay::Short => "short",
+            UnitDisplay::Narrow => "narrow",
+            UnitDisplay::Long => "long",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Notation {
+    Standard,
+    Scientific,
+    Engineering,
+    Compact,
+}
+
+impl Notation {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Notation::Standard => "standard",
+            Notation::Scientific => "scientific",
+            Notation::Engineering => "engineering",
+            Notation::Compact => "compact",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CompactDisplay {
+    Short,
+    Long,
+}
+
+impl CompactDisplay {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CompactDisplay::Short => "short",
+            CompactDisplay::Long => "long",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SignDisplay {
+    Auto,
+    Never,
+    Always,
+    ExceptZero,
+}
+
+impl SignDisplay {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SignDisplay::Auto => "auto",
+            SignDisplay::Never => "never",
+            SignDisplay::Always => "always",
+            SignDisplay::ExceptZero => "exceptZero",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TrailingZeroDisplay {
+    Auto,
+    StripIfInteger,
+}
+
+impl TrailingZeroDisplay {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TrailingZeroDisplay::Auto => "auto",
+            TrailingZeroDisplay::StripIfInteger => "stripIfInteger",
+        }
+    }
 }
 
 /// Subset of options that will be parsed from the ICU message daet or time skeleton.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+///
+/// Mirrors `Intl.DateTimeFormatOptions`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct JsIntlDateTimeFormatOptions {
-    // TODO
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub era: Option<DateTimeTextStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<DateTimeNumericStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub month: Option<MonthStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day: Option<DateTimeNumericStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekday: Option<DateTimeTextStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hour: Option<DateTimeNumericStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hour12: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hour_cycle: Option<HourCycle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minute: Option<DateTimeNumericStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub second: Option<DateTimeNumericStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone_name: Option<TimeZoneNameStyle>,
+}
+
+/// Representation of the `era` and `weekday` fields.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DateTimeTextStyle {
+    Narrow,
+    Short,
+    Long,
+}
+
+impl DateTimeTextStyle {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DateTimeTextStyle::Narrow => "narrow",
+            DateTimeTextStyle::Short => "short",
+            DateTimeTextStyle::Long => "long",
+        }
+    }
+}
+
+/// Representation of the `year`, `day`, `hour`, `minute` and `second` fields.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DateTimeNumericStyle {
+    Numeric,
+    #[serde(rename = "2-digit")]
+    TwoDigit,
+}
+
+impl DateTimeNumericStyle {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DateTimeNumericStyle::Numeric => "numeric",
+            DateTimeNumericStyle::TwoDigit => "2-digit",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MonthStyle {
+    Numeric,
+    #[serde(rename = "2-digit")]
+    TwoDigit,
+    Narrow,
+    Short,
+    Long,
+}
+
+impl MonthStyle {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MonthStyle::Numeric => "numeric",
+            MonthStyle::TwoDigit => "2-digit",
+            MonthStyle::Narrow => "narrow",
+            MonthStyle::Short => "short",
+            MonthStyle::Long => "long",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HourCycle {
+    H11,
+    H12,
+    H23,
+    H24,
+}
+
+impl HourCycle {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HourCycle::H11 => "h11",
+            HourCycle::H12 => "h12",
+            HourCycle::H23 => "h23",
+            HourCycle::H24 => "h24",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TimeZoneNameStyle {
+    Short,
+    Long,
+    ShortOffset,
+    LongOffset,
+    ShortGeneric,
+    LongGeneric,
+}
+
+impl TimeZoneNameStyle {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TimeZoneNameStyle::Short => "short",
+            TimeZoneNameStyle::Long => "long",
+            TimeZoneNameStyle::ShortOffset => "shortOffset",
+            TimeZoneNameStyle::LongOffset => "longOffset",
+            TimeZoneNameStyle::ShortGeneric => "shortGeneric",
+            TimeZoneNameStyle::LongGeneric => "longGeneric",
+        }
+    }
 }
@@ -1,13 +1,46 @@
+mod arguments;
 mod ast;
-mod js_intl;
+mod catalog;
+mod date_time_skeleton;
+mod diagnostic;
+mod formatter;
+mod javascript;
+pub mod js_intl;
+mod number_format;
+mod number_skeleton;
 mod parser;
 mod pattern_syntax;
-
-pub use ast::{Ast, AstElement, Position, Span};
-pub use parser::Parser;
+mod plural_rules;
+mod pretty_printer;
+mod printer;
+mod rust;
+mod translation;
+mod typescript;
+pub mod visit;
+
+pub use arguments::{extract_arguments, ArgumentKind, ArgumentType, ArgumentUse, MessageArgument};
+pub use ast::{
+    into_owned_ast, Ast, AstElement, DateTimeArgStyle, DateTimeSkeleton, Error, ErrorKind,
+    NumberArgStyle, NumberSkeleton, NumberSkeletonToken, PluralOrSelectOption,
+    PluralOrSelectOptions, PluralType, Position, Span,
+};
+pub use catalog::{check_catalogs, Catalog, CatalogIssue, CatalogIssueKind, CatalogReport};
+pub use diagnostic::{render_diagnostic, suggest_fix, DiagnosticOptions};
+pub use formatter::{format, format_to_rich_text, FormatError, FormatValue, RichText};
+pub use javascript::compile_to_javascript;
+pub use parser::{OffsetEncoding, Parser, ParserOptions, DEFAULT_PARSER_OPTIONS};
+pub use plural_rules::{InvalidPluralOperands, PluralCategory, PluralOperands, PluralRules};
+pub use pretty_printer::{is_pretty_printed, pretty_print_ast, PrettyPrintOptions};
+pub use printer::print_ast;
+pub use rust::{generate_rust_module, RustError, RustOptions};
+pub use translation::{
+    check_translation, check_translation_ast, TranslationCheckError, TranslationCheckOptions,
+    TranslationIssue, TranslationIssueKind,
+};
+pub use typescript::{generate_type_declarations, TypeScriptError, TypeScriptOptions};
+pub use visit::{Fold, Visitor, VisitorMut};
 
 #[cfg(test)]
-#[macro_use]
 extern crate pretty_assertions;
 
 #[cfg(test)]
@@ -19,6 +52,7 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use crate::ast::*;
+    use crate::js_intl::*;
     use crate::parser::*;
 
     #[test]
@@ -48,7 +82,7 @@ mod tests {
         assert_eq!(
             Parser::new("{a}", None).parse(),
             Ok(vec![AstElement::Argument {
-                value: "a",
+                value: "a".into(),
                 span: Span::new(Position::new(0, 1, 1), Position::new(3, 1, 4))
             }])
         );
@@ -64,7 +98,7 @@ mod tests {
                     span: Span::new(Position::new(0, 1, 1), Position::new(2, 1, 3))
                 },
                 AstElement::Argument {
-                    value: "b",
+                    value: "b".into(),
                     span: Span::new(Position::new(2, 1, 3), Position::new(5, 1, 6))
                 },
                 AstElement::Literal {
@@ -246,12 +280,12 @@ mod tests {
                     span: Span::new(Position::new(0, 1, 1), Position::new(4, 1, 5))
                 },
                 AstElement::Plural {
-                    value: "count",
+                    value: "count".into(),
                     plural_type: PluralType::Cardinal,
                     span: Span::new(Position::new(4, 1, 5), Position::new(75, 1, 76)),
                     offset: 0,
                     options: PluralOrSelectOptions(vec![
-                        ("one", PluralOrSelectOption {
+                        ("one".into(), PluralOrSelectOption {
                             value: vec![
                                 AstElement::Literal {
                                     value: "worked for # hour".to_string(),
@@ -259,15 +293,17 @@ mod tests {
                                 },
                             ],
                             span: Span::new(Position::new(24, 1, 25), Position::new(45, 1, 46)),
+                                    selector_span: Span::new(Position::new(20, 1, 21), Position::new(23, 1, 24)),
                         }),
-                        ("other", PluralOrSelectOption {
+                        ("other".into(), PluralOrSelectOption {
                             value: vec![
                                 AstElement::Literal {
                                     value: "worked for # hours".to_string(),
                                     span: Span::new(Position::new(53, 1, 54), Position::new(73, 1, 74))
                                 },
                             ],
-                            span: Span::new(Position::new(52, 1, 53), Position::new(74, 1, 75))
+                            span: Span::new(Position::new(52, 1, 53), Position::new(74, 1, 75)),
+                                    selector_span: Span::new(Position::new(46, 1, 47), Position::new(51, 1, 52)),
                         })
                     ])
                 },
@@ -294,12 +330,12 @@ mod tests {
                     span: Span::new(Position::new(0, 1, 1), Position::new(4, 1, 5)),
                 },
                 AstElement::Plural {
-                    value: "count",
+                    value: "count".into(),
                     plural_type: PluralType::Cardinal,
                     span: Span::new(Position::new(4, 1, 5), Position::new(73, 1, 74)),
                     offset: 0,
                     options: PluralOrSelectOptions(vec![(
-                        "one",
+                        "one".into(),
                         PluralOrSelectOption {
                             value: vec![
                                 AstElement::Literal {
@@ -322,6 +358,10 @@ mod tests {
                                 },
                             ],
                             span: Span::new(Position::new(24, 1, 25), Position::new(72, 1, 73)),
+                            selector_span: Span::new(
+                                Position::new(20, 1, 21),
+                                Position::new(23, 1, 24)
+                            ),
                         },
                     )]),
                 },
@@ -343,7 +383,7 @@ mod tests {
                     span: Span::new(Position::new(0, 1, 1), Position::new(11, 1, 12))
                 },
                 AstElement::Argument {
-                    value: "0",
+                    value: "0".into(),
                     span: Span::new(Position::new(11, 1, 12), Position::new(14, 1, 15))
                 }
             ])
@@ -360,7 +400,7 @@ mod tests {
                     span: Span::new(Position::new(0, 1, 1), Position::new(11, 1, 12))
                 },
                 AstElement::Argument {
-                    value: "name",
+                    value: "name".into(),
                     span: Span::new(Position::new(11, 1, 12), Position::new(19, 1, 20))
                 }
             ])
@@ -437,7 +477,7 @@ mod tests {
                     span: Span::new(Position::new(0, 1, 1), Position::new(7, 1, 8))
                 },
                 AstElement::Number {
-                    value: "numCats",
+                    value: "numCats".into(),
                     span: Span::new(Position::new(7, 1, 8), Position::new(24, 1, 25)),
                     style: None
                 },
@@ -463,7 +503,7 @@ mod tests {
                     span: Span::new(Position::new(0, 1, 1), Position::new(34, 1, 35))
                 },
                 AstElement::Date {
-                    value: "dateVal",
+                    value: "dateVal".into(),
                     span: Span::new(Position::new(34, 1, 35), Position::new(49, 1, 50)),
                     style: None
                 },
@@ -472,7 +512,7 @@ mod tests {
                     span: Span::new(Position::new(49, 1, 50), Position::new(53, 1, 54))
                 },
                 AstElement::Time {
-                    value: "timeVal",
+                    value: "timeVal".into(),
                     span: Span::new(Position::new(53, 1, 54), Position::new(68, 1, 69)),
                     style: None
                 },
@@ -545,9 +585,9 @@ mod tests {
         assert_eq!(
             Parser::new("{0, number, percent}", None).parse(),
             Ok(vec![AstElement::Number {
-                value: "0",
+                value: "0".into(),
                 span: Span::new(Position::new(0, 1, 1), Position::new(20, 1, 21)),
-                style: Some(NumberArgStyle::Style("percent"))
+                style: Some(NumberArgStyle::Style("percent".into()))
             }])
         )
     }
@@ -569,12 +609,15 @@ mod tests {
         assert_eq!(
             Parser::new("{0, number, ::percent}", None).parse(),
             Ok(vec![AstElement::Number {
-                value: "0",
+                value: "0".into(),
                 span: Span::new(Position::new(0, 1, 1), Position::new(22, 1, 23)),
                 style: Some(NumberArgStyle::Skeleton(NumberSkeleton {
-                    tokens: vec![NumberSkeletonToken { stem: "percent", options: vec![] }],
+                    tokens: vec![NumberSkeletonToken { stem: "percent".into(), options: vec![] }],
                     span: Span::new(Position::new(12, 1, 13), Position::new(21, 1, 22)),
-                    parsed_options: None,
+                    parsed_options: Some(JsIntlNumberFormatOptions {
+                        style: Some(NumberFormatStyle::Percent),
+                        ..Default::default()
+                    }),
                 }))
             }])
         )
@@ -585,12 +628,19 @@ mod tests {
         assert_eq!(
             Parser::new("{0, number, :: currency/GBP}", None).parse(),
             Ok(vec![AstElement::Number {
-                value: "0",
+                value: "0".into(),
                 span: Span::new(Position::new(0, 1, 1), Position::new(28, 1, 29)),
                 style: Some(NumberArgStyle::Skeleton(NumberSkeleton {
-                    tokens: vec![NumberSkeletonToken { stem: "currency", options: vec!["GBP"] }],
+                    tokens: vec![NumberSkeletonToken {
+                        stem: "currency".into(),
+                        options: vec!["GBP".into()]
+                    }],
                     span: Span::new(Position::new(12, 1, 13), Position::new(27, 1, 28)),
-                    parsed_options: None,
+                    parsed_options: Some(JsIntlNumberFormatOptions {
+                        style: Some(NumberFormatStyle::Currency),
+                        currency: Some("GBP".to_string()),
+                        ..Default::default()
+                    }),
                 }))
             }])
         )
@@ -601,15 +651,24 @@ mod tests {
         assert_eq!(
             Parser::new("{0, number, ::currency/GBP compact-short}", None).parse(),
             Ok(vec![AstElement::Number {
-                value: "0",
+                value: "0".into(),
                 span: Span::new(Position::new(0, 1, 1), Position::new(41, 1, 42)),
                 style: Some(NumberArgStyle::Skeleton(NumberSkeleton {
                     tokens: vec![
-                        NumberSkeletonToken { stem: "currency", options: vec!["GBP"] },
-                        NumberSkeletonToken { stem: "compact-short", options: vec![] }
+                        NumberSkeletonToken {
+                            stem: "currency".into(),
+                            options: vec!["GBP".into()]
+                        },
+                        NumberSkeletonToken { stem: "compact-short".into(), options: vec![] }
                     ],
                     span: Span::new(Position::new(12, 1, 13), Position::new(40, 1, 41)),
-                    parsed_options: None,
+                    parsed_options: Some(JsIntlNumberFormatOptions {
+                        style: Some(NumberFormatStyle::Currency),
+                        currency: Some("GBP".to_string()),
+                        notation: Some(Notation::Compact),
+                        compact_display: Some(CompactDisplay::Short),
+                        ..Default::default()
+                    }),
                 }))
             }])
         )
@@ -641,6 +700,7 @@ mod tests {
 
     mod number_skeleton_tests {
         use super::*;
+        use pretty_assertions::assert_eq;
 
         /// Convenient macro to help parametrize tests with number skeleton strings.
         macro_rules! number_skeleton_tests {
@@ -675,37 +735,37 @@ mod tests {
         number_skeleton_tests! {
             case_0: ("compact-short currency/GBP", &vec![
                 NumberSkeletonToken {
-                    stem: "compact-short",
+                    stem: "compact-short".into(),
                     options: vec![],
                 },
                 NumberSkeletonToken {
-                    stem: "currency",
-                    options: vec!["GBP"],
+                    stem: "currency".into(),
+                    options: vec!["GBP".into()],
                 }
             ]),
             case_1: ("@@#", &vec![
                 NumberSkeletonToken {
-                    stem: "@@#",
+                    stem: "@@#".into(),
                     options: vec![],
                 },
             ]),
             case_2: ("currency/CAD unit-width-narrow", &vec![
                 NumberSkeletonToken {
-                    stem: "currency",
-                    options: vec!["CAD"],
+                    stem: "currency".into(),
+                    options: vec!["CAD".into()],
                 },
                 NumberSkeletonToken {
-                    stem: "unit-width-narrow",
+                    stem: "unit-width-narrow".into(),
                     options: vec![],
                 }
             ]),
             case_3: ("percent .##", &vec![
                 NumberSkeletonToken {
-                    stem: "percent",
+                    stem: "percent".into(),
                     options: vec![],
                 },
                 NumberSkeletonToken {
-                    stem: ".##",
+                    stem: ".##".into(),
                     options: vec![],
                 },
             ]),
@@ -713,67 +773,67 @@ mod tests {
             // Some percent skeletons
             case_4: ("percent .000*", &vec![
                 NumberSkeletonToken {
-                    stem: "percent",
+                    stem: "percent".into(),
                     options: vec![],
                 },
                 NumberSkeletonToken {
-                    stem: ".000*",
+                    stem: ".000*".into(),
                     options: vec![],
                 },
             ]),
             case_5: ("percent .0###", &vec![
                 NumberSkeletonToken {
-                    stem: "percent",
+                    stem: "percent".into(),
                     options: vec![],
                 },
                 NumberSkeletonToken {
-                    stem: ".0###",
+                    stem: ".0###".into(),
                     options: vec![],
                 },
             ]),
             case_6: ("percent .00/@##", &vec![
                 NumberSkeletonToken {
-                    stem: "percent",
+                    stem: "percent".into(),
                     options: vec![],
                 },
                 NumberSkeletonToken {
-                    stem: ".00",
-                    options: vec!["@##"],
+                    stem: ".00".into(),
+                    options: vec!["@##".into()],
                 },
             ]),
             case_7: ("percent .00/@@@", &vec![
                 NumberSkeletonToken {
-                    stem: "percent",
+                    stem: "percent".into(),
                     options: vec![],
                 },
                 NumberSkeletonToken {
-                    stem: ".00",
-                    options: vec!["@@@"],
+                    stem: ".00".into(),
+                    options: vec!["@@@".into()],
                 },
             ]),
             case_8: ("percent .00/@@@@*", &vec![
                 NumberSkeletonToken {
-                    stem: "percent",
+                    stem: "percent".into(),
                     options: vec![],
                 },
                 NumberSkeletonToken {
-                    stem: ".00",
-                    options: vec!["@@@@*"],
+                    stem: ".00".into(),
+                    options: vec!["@@@@*".into()],
                 },
             ]),
 
             // Complex currency skeleton
             case_9: ("currency/GBP .00##/@@@ unit-width-full-name", &vec![
                 NumberSkeletonToken {
-                    stem: "currency",
-                    options: vec!["GBP"],
+                    stem: "currency".into(),
+                    options: vec!["GBP".into()],
                 },
                 NumberSkeletonToken {
-                    stem: ".00##",
-                    options: vec!["@@@"],
+                    stem: ".00##".into(),
+                    options: vec!["@@@".into()],
                 },
                 NumberSkeletonToken {
-                    stem: "unit-width-full-name",
+                    stem: "unit-width-full-name".into(),
                     options: vec![],
                 },
             ]),
@@ -781,15 +841,15 @@ mod tests {
             // Complex unit
             case_10: ("measure-unit/length-meter .00##/@@@ unit-width-full-name", &vec![
                 NumberSkeletonToken {
-                    stem: "measure-unit",
-                    options: vec!["length-meter"],
+                    stem: "measure-unit".into(),
+                    options: vec!["length-meter".into()],
                 },
                 NumberSkeletonToken {
-                    stem: ".00##",
-                    options: vec!["@@@"],
+                    stem: ".00##".into(),
+                    options: vec!["@@@".into()],
                 },
                 NumberSkeletonToken {
-                    stem: "unit-width-full-name",
+                    stem: "unit-width-full-name".into(),
                     options: vec![],
                 },
             ]),
@@ -797,8 +857,8 @@ mod tests {
             // Multiple options
             case_11: ("scientific/+ee/sign-always", &vec![
                 NumberSkeletonToken {
-                    stem: "scientific",
-                    options: vec!["+ee", "sign-always"],
+                    stem: "scientific".into(),
+                    options: vec!["+ee".into(), "sign-always".into()],
                 },
             ]),
         }
@@ -809,12 +869,23 @@ mod tests {
         assert_eq!(
             Parser::new("{0, date, ::yyyy.MM.dd G 'at' HH:mm:ss vvvv}", None).parse(),
             Ok(vec![AstElement::Date {
-                value: "0",
+                value: "0".into(),
                 span: Span::new(Position::new(0, 1, 1), Position::new(44, 1, 45)),
                 style: Some(DateTimeArgStyle::Skeleton(DateTimeSkeleton {
-                    pattern: "yyyy.MM.dd G 'at' HH:mm:ss vvvv",
+                    pattern: "yyyy.MM.dd G 'at' HH:mm:ss vvvv".into(),
                     span: Span::new(Position::new(10, 1, 11), Position::new(43, 1, 44)),
-                    parsed_options: None,
+                    parsed_options: Some(JsIntlDateTimeFormatOptions {
+                        era: Some(DateTimeTextStyle::Short),
+                        year: Some(DateTimeNumericStyle::Numeric),
+                        month: Some(MonthStyle::TwoDigit),
+                        day: Some(DateTimeNumericStyle::TwoDigit),
+                        hour: Some(DateTimeNumericStyle::TwoDigit),
+                        hour_cycle: Some(HourCycle::H23),
+                        minute: Some(DateTimeNumericStyle::TwoDigit),
+                        second: Some(DateTimeNumericStyle::TwoDigit),
+                        time_zone_name: Some(TimeZoneNameStyle::LongGeneric),
+                        ..Default::default()
+                    }),
                 }))
             }])
         )
@@ -825,12 +896,18 @@ mod tests {
         assert_eq!(
             Parser::new("{0, date, ::EEE, MMM d, ''yy}", None).parse(),
             Ok(vec![AstElement::Date {
-                value: "0",
+                value: "0".into(),
                 span: Span::new(Position::new(0, 1, 1), Position::new(29, 1, 30)),
                 style: Some(DateTimeArgStyle::Skeleton(DateTimeSkeleton {
-                    pattern: "EEE, MMM d, ''yy",
+                    pattern: "EEE, MMM d, ''yy".into(),
                     span: Span::new(Position::new(10, 1, 11), Position::new(28, 1, 29)),
-                    parsed_options: None,
+                    parsed_options: Some(JsIntlDateTimeFormatOptions {
+                        weekday: Some(DateTimeTextStyle::Short),
+                        month: Some(MonthStyle::Short),
+                        day: Some(DateTimeNumericStyle::Numeric),
+                        year: Some(DateTimeNumericStyle::TwoDigit),
+                        ..Default::default()
+                    }),
                 }))
             }])
         )
@@ -841,17 +918,47 @@ mod tests {
         assert_eq!(
             Parser::new("{0, date, ::h:mm a}", None).parse(),
             Ok(vec![AstElement::Date {
-                value: "0",
+                value: "0".into(),
                 span: Span::new(Position::new(0, 1, 1), Position::new(19, 1, 20)),
                 style: Some(DateTimeArgStyle::Skeleton(DateTimeSkeleton {
-                    pattern: "h:mm a",
+                    pattern: "h:mm a".into(),
                     span: Span::new(Position::new(10, 1, 11), Position::new(18, 1, 19)),
-                    parsed_options: None,
+                    parsed_options: Some(JsIntlDateTimeFormatOptions {
+                        hour: Some(DateTimeNumericStyle::Numeric),
+                        hour_cycle: Some(HourCycle::H12),
+                        minute: Some(DateTimeNumericStyle::TwoDigit),
+                        hour12: Some(true),
+                        ..Default::default()
+                    }),
                 }))
             }])
         )
     }
 
+    #[test]
+    fn invalid_date_arg_skeleton_1() {
+        assert_eq!(
+            Parser::new("{0, date, ::  y QQQ}", None).parse(),
+            Err(Error {
+                kind: ErrorKind::InvalidDateTimeSkeleton,
+                message: "{0, date, ::  y QQQ}".to_string(),
+                span: Span::new(Position::new(16, 1, 17), Position::new(19, 1, 20)),
+            })
+        )
+    }
+
+    #[test]
+    fn invalid_time_arg_skeleton_multiline() {
+        assert_eq!(
+            Parser::new("中\n{0, time, ::Hmm'時'ww}", None).parse(),
+            Err(Error {
+                kind: ErrorKind::InvalidDateTimeSkeleton,
+                message: "中\n{0, time, ::Hmm'時'ww}".to_string(),
+                span: Span::new(Position::new(24, 2, 19), Position::new(26, 2, 21)),
+            })
+        )
+    }
+
     #[test]
     fn duplicate_plural_selectors() {
         assert_eq!(
@@ -876,6 +983,284 @@ mod tests {
         )
     }
 
+    #[test]
+    fn invalid_plural_category() {
+        let options = ParserOptions { locale: Some("en-US".to_string()), ..Default::default() };
+        assert_eq!(
+            Parser::new("{n, plural, one {#} severals {#}}", Some(&options)).parse(),
+            Err(Error {
+                kind: ErrorKind::InvalidPluralArgumentCategory,
+                message: "{n, plural, one {#} severals {#}}".to_string(),
+                span: Span::new(Position::new(20, 1, 21), Position::new(28, 1, 29))
+            })
+        );
+        assert_eq!(
+            Parser::new("{n, plural, few {#} other {#}}", Some(&options)).parse(),
+            Err(Error {
+                kind: ErrorKind::InvalidPluralArgumentCategory,
+                message: "{n, plural, few {#} other {#}}".to_string(),
+                span: Span::new(Position::new(12, 1, 13), Position::new(15, 1, 16))
+            })
+        );
+        // Exact selectors, `select` arguments and ordinal categories are fine.
+        assert!(Parser::new("{n, plural, =5 {#} one {#} other {#}}", Some(&options))
+            .parse()
+            .is_ok());
+        assert!(Parser::new("{n, select, few {#} other {#}}", Some(&options)).parse().is_ok());
+        assert!(Parser::new("{n, selectordinal, few {#rd} other {#th}}", Some(&options))
+            .parse()
+            .is_ok());
+    }
+
+    #[test]
+    fn invalid_ordinal_category() {
+        let options = ParserOptions { locale: Some("fr".to_string()), ..Default::default() };
+        assert_eq!(
+            Parser::new("{n, selectordinal, two {#} other {#}}", Some(&options)).parse(),
+            Err(Error {
+                kind: ErrorKind::InvalidPluralArgumentCategory,
+                message: "{n, selectordinal, two {#} other {#}}".to_string(),
+                span: Span::new(Position::new(19, 1, 20), Position::new(22, 1, 23))
+            })
+        );
+        assert!(Parser::new("{n, plural, many {#} other {#}}", Some(&options)).parse().is_ok());
+    }
+
+    #[test]
+    fn plural_category_of_unsupported_locale() {
+        let options = ParserOptions { locale: Some("xx".to_string()), ..Default::default() };
+        assert!(Parser::new("{n, plural, severals {#}}", Some(&options)).parse().is_ok());
+    }
+
+    #[test]
+    fn missing_other_clause() {
+        let options = ParserOptions { requires_other_clause: true, ..Default::default() };
+        assert_eq!(
+            Parser::new("{a, select, x {X}} and {n, plural, other {#}}", Some(&options)).parse(),
+            Err(Error {
+                kind: ErrorKind::MissingOtherClause,
+                message: "{a, select, x {X}} and {n, plural, other {#}}".to_string(),
+                span: Span::new(Position::new(0, 1, 1), Position::new(18, 1, 19))
+            })
+        );
+        assert_eq!(
+            Parser::new("{a, select, other {{n, selectordinal, one {#}}}}", Some(&options)).parse(),
+            Err(Error {
+                kind: ErrorKind::MissingOtherClause,
+                message: "{a, select, other {{n, selectordinal, one {#}}}}".to_string(),
+                span: Span::new(Position::new(19, 1, 20), Position::new(46, 1, 47))
+            })
+        );
+        assert!(Parser::new("{a, select, x {X}}", None).parse().is_ok());
+    }
+
+    #[test]
+    fn error_display() {
+        let error = Parser::new("Hello\n{name, plural, one {#}", None).parse().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected a closing brace `}` for the argument at line 2, column 1"
+        );
+
+        let error: Box<dyn std::error::Error> = Box::new(error);
+        assert_eq!(
+            error.to_string(),
+            "expected a closing brace `}` for the argument at line 2, column 1"
+        );
+    }
+
+    #[test]
+    fn utf16_offsets() {
+        let options =
+            ParserOptions { offset_encoding: OffsetEncoding::Utf16, ..Default::default() };
+        assert_eq!(
+            Parser::new("中文{a}😀<b>x</b>", Some(&options)).parse(),
+            Ok(vec![
+                AstElement::Literal {
+                    value: "中文".to_string(),
+                    span: Span::new(Position::new(0, 1, 1), Position::new(2, 1, 3)),
+                },
+                AstElement::Argument {
+                    value: "a".into(),
+                    span: Span::new(Position::new(2, 1, 3), Position::new(5, 1, 6)),
+                },
+                AstElement::Literal {
+                    value: "😀".to_string(),
+                    span: Span::new(Position::new(5, 1, 6), Position::new(7, 1, 7)),
+                },
+                AstElement::Tag {
+                    value: "b".into(),
+                    span: Span::new(Position::new(7, 1, 7), Position::new(15, 1, 15)),
+                    children: Box::new(vec![AstElement::Literal {
+                        value: "x".to_string(),
+                        span: Span::new(Position::new(10, 1, 10), Position::new(11, 1, 11)),
+                    }]),
+                },
+            ])
+        );
+        assert_eq!(
+            Parser::new("😀{}", Some(&options)).parse(),
+            Err(Error {
+                kind: ErrorKind::EmptyArgument,
+                message: "😀{}".to_string(),
+                span: Span::new(Position::new(2, 1, 2), Position::new(4, 1, 4)),
+            })
+        );
+    }
+
+    #[test]
+    fn code_point_offsets() {
+        let options =
+            ParserOptions { offset_encoding: OffsetEncoding::CodePoint, ..Default::default() };
+        assert_eq!(
+            Parser::new("😀{n, plural, one {#}}{d, date, ::yMd}", Some(&options)).parse(),
+            Ok(vec![
+                AstElement::Literal {
+                    value: "😀".to_string(),
+                    span: Span::new(Position::new(0, 1, 1), Position::new(1, 1, 2)),
+                },
+                AstElement::Plural {
+                    value: "n".into(),
+                    plural_type: PluralType::Cardinal,
+                    span: Span::new(Position::new(1, 1, 2), Position::new(21, 1, 22)),
+                    offset: 0,
+                    options: PluralOrSelectOptions(vec![(
+                        "one".into(),
+                        PluralOrSelectOption {
+                            value: vec![AstElement::Pound(Span::new(
+                                Position::new(18, 1, 19),
+                                Position::new(19, 1, 20)
+                            ))],
+                            span: Span::new(Position::new(17, 1, 18), Position::new(20, 1, 21)),
+                            selector_span: Span::new(
+                                Position::new(13, 1, 14),
+                                Position::new(16, 1, 17)
+                            ),
+                        }
+                    )]),
+                },
+                AstElement::Date {
+                    value: "d".into(),
+                    span: Span::new(Position::new(21, 1, 22), Position::new(37, 1, 38)),
+                    style: Some(DateTimeArgStyle::Skeleton(DateTimeSkeleton {
+                        pattern: "yMd".into(),
+                        span: Span::new(Position::new(31, 1, 32), Position::new(36, 1, 37)),
+                        parsed_options: Some(JsIntlDateTimeFormatOptions {
+                            year: Some(DateTimeNumericStyle::Numeric),
+                            month: Some(MonthStyle::Numeric),
+                            day: Some(DateTimeNumericStyle::Numeric),
+                            ..Default::default()
+                        }),
+                    })),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn utf16_offsets_when_recovering() {
+        let options =
+            ParserOptions { offset_encoding: OffsetEncoding::Utf16, ..Default::default() };
+        let (ast, errors) = Parser::new("😀{} {a", Some(&options)).parse_with_recovery();
+        assert_eq!(
+            ast,
+            vec![
+                AstElement::Literal {
+                    value: "😀".to_string(),
+                    span: Span::new(Position::new(0, 1, 1), Position::new(2, 1, 2)),
+                },
+                AstElement::Invalid(Span::new(Position::new(2, 1, 2), Position::new(4, 1, 4))),
+                AstElement::Literal {
+                    value: " ".to_string(),
+                    span: Span::new(Position::new(4, 1, 4), Position::new(5, 1, 5)),
+                },
+                AstElement::Invalid(Span::new(Position::new(5, 1, 5), Position::new(7, 1, 7))),
+            ]
+        );
+        assert_eq!(
+            errors.iter().map(|error| error.span).collect::<Vec<_>>(),
+            vec![
+                Span::new(Position::new(2, 1, 2), Position::new(4, 1, 4)),
+                Span::new(Position::new(5, 1, 5), Position::new(7, 1, 7)),
+            ]
+        );
+    }
+
+    #[test]
+    fn omit_location() {
+        let options = ParserOptions { should_omit_location: true, ..Default::default() };
+        assert_eq!(
+            Parser::new("中文 {n, plural, one {<b>#</b>}}\n{d, date, ::yMd}", Some(&options))
+                .parse(),
+            Ok(vec![
+                AstElement::Literal { value: "中文 ".to_string(), span: Span::default() },
+                AstElement::Plural {
+                    value: "n".into(),
+                    plural_type: PluralType::Cardinal,
+                    span: Span::default(),
+                    offset: 0,
+                    options: PluralOrSelectOptions(vec![(
+                        "one".into(),
+                        PluralOrSelectOption {
+                            value: vec![AstElement::Tag {
+                                value: "b".into(),
+                                span: Span::default(),
+                                children: Box::new(vec![AstElement::Pound(Span::default())]),
+                            }],
+                            span: Span::default(),
+                            selector_span: Span::default(),
+                        }
+                    )]),
+                },
+                AstElement::Literal { value: "\n".to_string(), span: Span::default() },
+                AstElement::Date {
+                    value: "d".into(),
+                    span: Span::default(),
+                    style: Some(DateTimeArgStyle::Skeleton(DateTimeSkeleton {
+                        pattern: "yMd".into(),
+                        span: Span::default(),
+                        parsed_options: Some(JsIntlDateTimeFormatOptions {
+                            year: Some(DateTimeNumericStyle::Numeric),
+                            month: Some(MonthStyle::Numeric),
+                            day: Some(DateTimeNumericStyle::Numeric),
+                            ..Default::default()
+                        }),
+                    })),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn omit_location_keeps_error_span() {
+        let options = ParserOptions {
+            should_omit_location: true,
+            offset_encoding: OffsetEncoding::Utf16,
+            ..Default::default()
+        };
+        assert_eq!(
+            Parser::new("😀\n{}", Some(&options)).parse(),
+            Err(Error {
+                kind: ErrorKind::EmptyArgument,
+                message: "😀\n{}".to_string(),
+                span: Span::new(Position::new(3, 2, 1), Position::new(5, 2, 3)),
+            })
+        );
+        let (ast, errors) = Parser::new("a {} b", Some(&options)).parse_with_recovery();
+        assert_eq!(
+            ast,
+            vec![
+                AstElement::Literal { value: "a ".to_string(), span: Span::default() },
+                AstElement::Invalid(Span::default()),
+                AstElement::Literal { value: " b".to_string(), span: Span::default() },
+            ]
+        );
+        assert_eq!(
+            errors.iter().map(|error| error.span).collect::<Vec<_>>(),
+            vec![Span::new(Position::new(2, 1, 3), Position::new(4, 1, 5))]
+        );
+    }
+
     #[test]
     fn treat_unicode_nbsp_as_whitespace() {
         assert_eq!(
@@ -893,11 +1278,11 @@ mod tests {
             .parse(),
             Ok(vec![
                 AstElement::Select {
-                    value: "gender",
+                    value: "gender".into(),
                     span: Span::new(Position::new(0, 1, 1), Position::new(79, 7, 13)),
                     options: PluralOrSelectOptions(vec![
                         (
-                            "male",
+                            "male".into(),
                             PluralOrSelectOption {
                                 value: vec![
                                     AstElement::Literal {
@@ -908,7 +1293,7 @@ mod tests {
                                         ),
                                     },
                                     AstElement::Argument {
-                                        value: "He",
+                                        value: "He".into(),
                                         span: Span::new(
                                             Position::new(30, 3, 5),
                                             Position::new(34, 3, 9)
@@ -916,10 +1301,14 @@ mod tests {
                                     },
                                 ],
                                 span: Span::new(Position::new(24, 2, 7), Position::new(35, 3, 10)),
+                                selector_span: Span::new(
+                                    Position::new(19, 2, 2),
+                                    Position::new(23, 2, 6)
+                                ),
                             },
                         ),
                         (
-                            "female",
+                            "female".into(),
                             PluralOrSelectOption {
                                 value: vec![
                                     AstElement::Literal {
@@ -930,7 +1319,7 @@ mod tests {
                                         ),
                                     },
                                     AstElement::Argument {
-                                        value: "She",
+                                        value: "She".into(),
                                         span: Span::new(
                                             Position::new(51, 5, 5),
                                             Position::new(56, 5, 10)
@@ -938,10 +1327,14 @@ mod tests {
                                     },
                                 ],
                                 span: Span::new(Position::new(45, 4, 9), Position::new(57, 5, 11)),
+                                selector_span: Span::new(
+                                    Position::new(38, 4, 2),
+                                    Position::new(44, 4, 8)
+                                ),
                             },
                         ),
                         (
-                            "other",
+                            "other".into(),
                             PluralOrSelectOption {
                                 value: vec![
                                     AstElement::Literal {
@@ -952,7 +1345,7 @@ mod tests {
                                         ),
                                     },
                                     AstElement::Argument {
-                                        value: "They",
+                                        value: "They".into(),
                                         span: Span::new(
                                             Position::new(71, 7, 5),
                                             Position::new(77, 7, 11)
@@ -960,6 +1353,10 @@ mod tests {
                                     },
                                 ],
                                 span: Span::new(Position::new(65, 6, 7), Position::new(78, 7, 12)),
+                                selector_span: Span::new(
+                                    Position::new(60, 6, 2),
+                                    Position::new(65, 6, 7)
+                                ),
                             },
                         ),
                     ]),
@@ -990,7 +1387,7 @@ mod tests {
                     span: Span::new(Position::new(0, 1, 1), Position::new(6, 1, 7)),
                 },
                 AstElement::Argument {
-                    value: "itemCount",
+                    value: "itemCount".into(),
                     span: Span::new(Position::new(6, 1, 7), Position::new(17, 1, 18)),
                 },
                 AstElement::Literal {
@@ -998,13 +1395,13 @@ mod tests {
                     span: Span::new(Position::new(17, 1, 18), Position::new(18, 1, 19)),
                 },
                 AstElement::Plural {
-                    value: "itemCount",
+                    value: "itemCount".into(),
                     plural_type: PluralType::Cardinal,
                     span: Span::new(Position::new(18, 1, 19), Position::new(68, 4, 2)),
                     offset: 0,
                     options: PluralOrSelectOptions(vec![
                         (
-                            "one",
+                            "one".into(),
                             PluralOrSelectOption {
                                 value: vec![AstElement::Literal {
                                     value: "item".to_string(),
@@ -1014,10 +1411,14 @@ mod tests {
                                     ),
                                 }],
                                 span: Span::new(Position::new(44, 2, 7), Position::new(50, 2, 13)),
+                                selector_span: Span::new(
+                                    Position::new(40, 2, 3),
+                                    Position::new(43, 2, 6)
+                                ),
                             },
                         ),
                         (
-                            "other",
+                            "other".into(),
                             PluralOrSelectOption {
                                 value: vec![AstElement::Literal {
                                     value: "items".to_string(),
@@ -1027,6 +1428,10 @@ mod tests {
                                     ),
                                 },],
                                 span: Span::new(Position::new(59, 3, 9), Position::new(66, 3, 16)),
+                                selector_span: Span::new(
+                                    Position::new(53, 3, 3),
+                                    Position::new(58, 3, 8)
+                                ),
                             },
                         ),
                     ]),
@@ -1054,13 +1459,13 @@ mod tests {
                     span: Span::new(Position::new(0, 1, 1), Position::new(9, 1, 10)),
                 },
                 AstElement::Plural {
-                    value: "itemCount",
+                    value: "itemCount".into(),
                     plural_type: PluralType::Cardinal,
                     span: Span::new(Position::new(9, 1, 10), Position::new(89, 5, 2)),
                     offset: 0,
                     options: PluralOrSelectOptions(vec![
                         (
-                            "=0",
+                            "=0".into(),
                             PluralOrSelectOption {
                                 value: vec![AstElement::Literal {
                                     value: "no items".to_string(),
@@ -1070,10 +1475,14 @@ mod tests {
                                     ),
                                 },],
                                 span: Span::new(Position::new(34, 2, 6), Position::new(44, 2, 16)),
+                                selector_span: Span::new(
+                                    Position::new(31, 2, 3),
+                                    Position::new(33, 2, 5)
+                                ),
                             },
                         ),
                         (
-                            "one",
+                            "one".into(),
                             PluralOrSelectOption {
                                 value: vec![AstElement::Literal {
                                     value: "1 item".to_string(),
@@ -1083,14 +1492,18 @@ mod tests {
                                     ),
                                 },],
                                 span: Span::new(Position::new(51, 3, 7), Position::new(59, 3, 15)),
+                                selector_span: Span::new(
+                                    Position::new(47, 3, 3),
+                                    Position::new(50, 3, 6)
+                                ),
                             },
                         ),
                         (
-                            "other",
+                            "other".into(),
                             PluralOrSelectOption {
                                 value: vec![
                                     AstElement::Argument {
-                                        value: "itemCount",
+                                        value: "itemCount".into(),
                                         span: Span::new(
                                             Position::new(69, 4, 10),
                                             Position::new(80, 4, 21)
@@ -1105,6 +1518,10 @@ mod tests {
                                     },
                                 ],
                                 span: Span::new(Position::new(68, 4, 9), Position::new(87, 4, 28)),
+                                selector_span: Span::new(
+                                    Position::new(62, 4, 3),
+                                    Position::new(67, 4, 8)
+                                ),
                             },
                         ),
                     ]),
@@ -1136,13 +1553,13 @@ mod tests {
                     span: Span::new(Position::new(0, 1, 1), Position::new(9, 1, 10)),
                 },
                 AstElement::Plural {
-                    value: "itemCount",
+                    value: "itemCount".into(),
                     plural_type: PluralType::Cardinal,
                     span: Span::new(Position::new(9, 1, 10), Position::new(99, 5, 2)),
                     offset: 2,
                     options: PluralOrSelectOptions(vec![
                         (
-                            "=0",
+                            "=0".into(),
                             PluralOrSelectOption {
                                 value: vec![AstElement::Literal {
                                     value: "no items".to_string(),
@@ -1152,10 +1569,14 @@ mod tests {
                                     ),
                                 },],
                                 span: Span::new(Position::new(44, 2, 6), Position::new(54, 2, 16)),
+                                selector_span: Span::new(
+                                    Position::new(41, 2, 3),
+                                    Position::new(43, 2, 5)
+                                ),
                             },
                         ),
                         (
-                            "one",
+                            "one".into(),
                             PluralOrSelectOption {
                                 value: vec![AstElement::Literal {
                                     value: "1 item".to_string(),
@@ -1165,14 +1586,18 @@ mod tests {
                                     ),
                                 },],
                                 span: Span::new(Position::new(61, 3, 7), Position::new(69, 3, 15)),
+                                selector_span: Span::new(
+                                    Position::new(57, 3, 3),
+                                    Position::new(60, 3, 6)
+                                ),
                             },
                         ),
                         (
-                            "other",
+                            "other".into(),
                             PluralOrSelectOption {
                                 value: vec![
                                     AstElement::Argument {
-                                        value: "itemCount",
+                                        value: "itemCount".into(),
                                         span: Span::new(
                                             Position::new(79, 4, 10),
                                             Position::new(90, 4, 21)
@@ -1187,6 +1612,10 @@ mod tests {
                                     },
                                 ],
                                 span: Span::new(Position::new(78, 4, 9), Position::new(97, 4, 28)),
+                                selector_span: Span::new(
+                                    Position::new(72, 4, 3),
+                                    Position::new(77, 4, 8)
+                                ),
                             },
                         ),
                     ]),
@@ -1212,29 +1641,37 @@ mod tests {
             )
             .parse(),
             Ok(vec![AstElement::Plural {
-                value: "itemCount",
+                value: "itemCount".into(),
                 plural_type: PluralType::Cardinal,
                 span: Span::new(Position::new(0, 1, 1), Position::new(56, 4, 2)),
                 offset: 0,
                 options: PluralOrSelectOptions(vec![
                     (
-                        "one",
+                        "one".into(),
                         PluralOrSelectOption {
                             value: vec![AstElement::Literal {
                                 value: "item}".to_string(),
                                 span: Span::new(Position::new(27, 2, 8), Position::new(34, 2, 15)),
                             },],
                             span: Span::new(Position::new(26, 2, 7), Position::new(35, 2, 16)),
+                            selector_span: Span::new(
+                                Position::new(22, 2, 3),
+                                Position::new(25, 2, 6)
+                            ),
                         },
                     ),
                     (
-                        "other",
+                        "other".into(),
                         PluralOrSelectOption {
                             value: vec![AstElement::Literal {
                                 value: "items}".to_string(),
                                 span: Span::new(Position::new(45, 3, 10), Position::new(53, 3, 18)),
                             },],
                             span: Span::new(Position::new(44, 3, 9), Position::new(54, 3, 19)),
+                            selector_span: Span::new(
+                                Position::new(38, 3, 3),
+                                Position::new(43, 3, 8)
+                            ),
                         },
                     ),
                 ]),
@@ -1258,11 +1695,11 @@ mod tests {
             .parse(),
             Ok(vec![
                 AstElement::Select {
-                    value: "gender",
+                    value: "gender".into(),
                     span: Span::new(Position::new(0, 1, 1), Position::new(66, 5, 2)),
                     options: PluralOrSelectOptions(vec![
                         (
-                            "male",
+                            "male".into(),
                             PluralOrSelectOption {
                                 value: vec![AstElement::Literal {
                                     value: "He".to_string(),
@@ -1272,10 +1709,14 @@ mod tests {
                                     ),
                                 },],
                                 span: Span::new(Position::new(26, 2, 10), Position::new(30, 2, 14)),
+                                selector_span: Span::new(
+                                    Position::new(21, 2, 5),
+                                    Position::new(25, 2, 9)
+                                ),
                             },
                         ),
                         (
-                            "female",
+                            "female".into(),
                             PluralOrSelectOption {
                                 value: vec![AstElement::Literal {
                                     value: "She".to_string(),
@@ -1285,10 +1726,14 @@ mod tests {
                                     ),
                                 },],
                                 span: Span::new(Position::new(42, 3, 12), Position::new(47, 3, 17)),
+                                selector_span: Span::new(
+                                    Position::new(35, 3, 5),
+                                    Position::new(41, 3, 11)
+                                ),
                             },
                         ),
                         (
-                            "other",
+                            "other".into(),
                             PluralOrSelectOption {
                                 value: vec![AstElement::Literal {
                                     value: "They".to_string(),
@@ -1298,6 +1743,10 @@ mod tests {
                                     ),
                                 },],
                                 span: Span::new(Position::new(58, 4, 11), Position::new(64, 4, 17)),
+                                selector_span: Span::new(
+                                    Position::new(52, 4, 5),
+                                    Position::new(57, 4, 10)
+                                ),
                             },
                         ),
                     ]),
@@ -1325,11 +1774,11 @@ mod tests {
             .parse(),
             Ok(vec![
                 AstElement::Select {
-                    value: "taxableArea",
+                    value: "taxableArea".into(),
                     span: Span::new(Position::new(0, 1, 1), Position::new(125, 4, 2)),
                     options: PluralOrSelectOptions(vec![
                         (
-                            "yes",
+                            "yes".into(),
                             PluralOrSelectOption {
                                 value: vec![
                                     AstElement::Literal {
@@ -1340,12 +1789,12 @@ mod tests {
                                         ),
                                     },
                                     AstElement::Number {
-                                        value: "taxRate",
+                                        value: "taxRate".into(),
                                         span: Span::new(
                                             Position::new(45, 2, 24),
                                             Position::new(71, 2, 50)
                                         ),
-                                        style: Some(NumberArgStyle::Style("percent",)),
+                                        style: Some(NumberArgStyle::Style("percent".into(),)),
                                     },
                                     AstElement::Literal {
                                         value: " tax will be collected.".to_string(),
@@ -1356,10 +1805,14 @@ mod tests {
                                     },
                                 ],
                                 span: Span::new(Position::new(30, 2, 9), Position::new(95, 2, 74)),
+                                selector_span: Span::new(
+                                    Position::new(26, 2, 5),
+                                    Position::new(29, 2, 8)
+                                ),
                             },
                         ),
                         (
-                            "other",
+                            "other".into(),
                             PluralOrSelectOption {
                                 value: vec![AstElement::Literal {
                                     value: "No taxes apply.".to_string(),
@@ -1372,6 +1825,10 @@ mod tests {
                                     Position::new(106, 3, 11),
                                     Position::new(123, 3, 28)
                                 ),
+                                selector_span: Span::new(
+                                    Position::new(100, 3, 5),
+                                    Position::new(105, 3, 10)
+                                ),
                             },
                         ),
                     ]),
@@ -1389,7 +1846,7 @@ mod tests {
         assert_eq!(
             Parser::new("<test-tag />", None).parse(),
             Ok(vec![AstElement::Tag {
-                value: "test-tag",
+                value: "test-tag".into(),
                 children: Box::new(vec![]),
                 span: Span::new(Position::new(0, 1, 1), Position::new(12, 1, 13)),
             }])
@@ -1401,7 +1858,7 @@ mod tests {
         assert_eq!(
             Parser::new("<test-tag/>", None).parse(),
             Ok(vec![AstElement::Tag {
-                value: "test-tag",
+                value: "test-tag".into(),
                 children: Box::new(vec![]),
                 span: Span::new(Position::new(0, 1, 1), Position::new(11, 1, 12)),
             }])
@@ -1460,7 +1917,7 @@ mod tests {
         assert_eq!(
             Parser::new("<test-tag></test-tag>", None).parse(),
             Ok(vec![AstElement::Tag {
-                value: "test-tag",
+                value: "test-tag".into(),
                 children: Box::new(vec![]),
                 span: Span::new(Position::new(0, 1, 1), Position::new(21, 1, 22)),
             }])
@@ -1472,7 +1929,7 @@ mod tests {
         assert_eq!(
             Parser::new("<test-tag>foo</test-tag>", None).parse(),
             Ok(vec![AstElement::Tag {
-                value: "test-tag",
+                value: "test-tag".into(),
                 children: Box::new(vec![AstElement::Literal {
                     value: "foo".to_string(),
                     span: Span::new(Position::new(10, 1, 11), Position::new(13, 1, 14))
@@ -1487,7 +1944,7 @@ mod tests {
         assert_eq!(
             Parser::new("<test-tag>foo {0} bar</test-tag>", None).parse(),
             Ok(vec![AstElement::Tag {
-                value: "test-tag",
+                value: "test-tag".into(),
                 span: Span::new(Position::new(0, 1, 1), Position::new(32, 1, 33)),
                 children: Box::new(vec![
                     AstElement::Literal {
@@ -1495,7 +1952,7 @@ mod tests {
                         span: Span::new(Position::new(10, 1, 11), Position::new(14, 1, 15)),
                     },
                     AstElement::Argument {
-                        value: "0",
+                        value: "0".into(),
                         span: Span::new(Position::new(14, 1, 15), Position::new(17, 1, 18)),
                     },
                     AstElement::Literal {
@@ -1520,7 +1977,7 @@ mod tests {
             )
             .parse(),
             Ok(vec![AstElement::Tag {
-                value: "bold",
+                value: "bold".into(),
                 span: Span::new(Position::new(0, 1, 1), Position::new(118, 5, 10)),
                 children: Box::new(vec![
                     AstElement::Literal {
@@ -1528,17 +1985,17 @@ mod tests {
                         span: Span::new(Position::new(6, 1, 7), Position::new(15, 1, 16)),
                     },
                     AstElement::Plural {
-                        value: "count",
+                        value: "count".into(),
                         plural_type: PluralType::Cardinal,
                         span: Span::new(Position::new(15, 1, 16), Position::new(110, 5, 2)),
                         offset: 0,
                         options: PluralOrSelectOptions(vec![
                             (
-                                "one",
+                                "one".into(),
                                 PluralOrSelectOption {
                                     value: vec![
                                         AstElement::Tag {
-                                            value: "italic",
+                                            value: "italic".into(),
                                             span: Span::new(
                                                 Position::new(45, 3, 10),
                                                 Position::new(63, 3, 28)
@@ -1560,14 +2017,18 @@ mod tests {
                                         Position::new(44, 3, 9),
                                         Position::new(70, 3, 35)
                                     ),
+                                    selector_span: Span::new(
+                                        Position::new(40, 3, 5),
+                                        Position::new(43, 3, 8)
+                                    ),
                                 },
                             ),
                             (
-                                "other",
+                                "other".into(),
                                 PluralOrSelectOption {
                                     value: vec![
                                         AstElement::Tag {
-                                            value: "italic",
+                                            value: "italic".into(),
                                             span: Span::new(
                                                 Position::new(82, 4, 12),
                                                 Position::new(100, 4, 30)
@@ -1589,6 +2050,10 @@ mod tests {
                                         Position::new(81, 4, 11),
                                         Position::new(108, 4, 38)
                                     ),
+                                    selector_span: Span::new(
+                                        Position::new(75, 4, 5),
+                                        Position::new(80, 4, 10)
+                                    ),
                                 },
                             ),
                         ]),
@@ -1616,7 +2081,7 @@ mod tests {
                     span: Span::new(Position::new(0, 1, 1), Position::new(2, 1, 3)),
                 },
                 AstElement::Tag {
-                    value: "b",
+                    value: "b".into(),
                     span: Span::new(Position::new(2, 1, 3), Position::new(13, 1, 14)),
                     children: Box::new(vec![AstElement::Literal {
                         value: "have".to_string(),
@@ -1628,11 +2093,11 @@ mod tests {
                     span: Span::new(Position::new(13, 1, 14), Position::new(14, 1, 15)),
                 },
                 AstElement::Tag {
-                    value: "foo",
+                    value: "foo".into(),
                     span: Span::new(Position::new(14, 1, 15), Position::new(68, 1, 69)),
                     children: Box::new(vec![
                         AstElement::Number {
-                            value: "numCats",
+                            value: "numCats".into(),
                             span: Span::new(Position::new(19, 1, 20), Position::new(36, 1, 37)),
                             style: None,
                         },
@@ -1641,7 +2106,7 @@ mod tests {
                             span: Span::new(Position::new(36, 1, 37), Position::new(49, 1, 50)),
                         },
                         AstElement::Argument {
-                            value: "placeholder",
+                            value: "placeholder".into(),
                             span: Span::new(Position::new(49, 1, 50), Position::new(62, 1, 63)),
                         },
                     ]),
@@ -1669,8 +2134,11 @@ mod tests {
     #[test]
     fn ignore_tags_1() {
         assert_eq!(
-            Parser::new("<test-tag></test-tag>", Some(&ParserOptions { should_ignore_tag: true }))
-                .parse(),
+            Parser::new(
+                "<test-tag></test-tag>",
+                Some(&ParserOptions { should_ignore_tag: true, ..Default::default() })
+            )
+            .parse(),
             Ok(vec![AstElement::Literal {
                 value: "<test-tag></test-tag>".to_string(),
                 span: Span::new(Position::new(0, 1, 1), Position::new(21, 1, 22)),
@@ -1730,7 +2198,7 @@ mod tests {
         assert_eq!(
             Parser::new(
                 "I have <foo>{numCats, number}</foo> cats.",
-                Some(&ParserOptions { should_ignore_tag: true })
+                Some(&ParserOptions { should_ignore_tag: true, ..Default::default() })
             )
             .parse(),
             Ok(vec![
@@ -1739,7 +2207,7 @@ mod tests {
                     span: Span::new(Position::new(0, 1, 1), Position::new(12, 1, 13)),
                 },
                 AstElement::Number {
-                    value: "numCats",
+                    value: "numCats".into(),
                     span: Span::new(Position::new(12, 1, 13), Position::new(29, 1, 30)),
                     style: None,
                 },
@@ -1783,7 +2251,7 @@ mod tests {
                     span: Span::new(Position::new(0, 1, 1), Position::new(8, 1, 9)),
                 },
                 AstElement::Tag {
-                    value: "a",
+                    value: "a".into(),
                     span: Span::new(Position::new(8, 1, 9), Position::new(42, 1, 43)),
                     children: Box::new(vec![
                         AstElement::Literal {
@@ -1791,10 +2259,10 @@ mod tests {
                             span: Span::new(Position::new(11, 1, 12), Position::new(18, 1, 19)),
                         },
                         AstElement::Tag {
-                            value: "b",
+                            value: "b".into(),
                             span: Span::new(Position::new(18, 1, 19), Position::new(38, 1, 39)),
                             children: Box::new(vec![AstElement::Argument {
-                                value: "placeholder",
+                                value: "placeholder".into(),
                                 span: Span::new(Position::new(21, 1, 22), Position::new(34, 1, 35)),
                             }]),
                         },
@@ -1812,6 +2280,171 @@ mod tests {
             .is_ok())
     }
 
+    #[test]
+    fn recover_from_multiple_errors() {
+        let message = "a {} b {c, foo} <x></y> {d}";
+        assert_eq!(
+            Parser::new(message, None).parse_with_recovery(),
+            (
+                vec![
+                    AstElement::Literal {
+                        value: "a ".to_string(),
+                        span: Span::new(Position::new(0, 1, 1), Position::new(2, 1, 3)),
+                    },
+                    AstElement::Invalid(Span::new(Position::new(2, 1, 3), Position::new(4, 1, 5))),
+                    AstElement::Literal {
+                        value: " b ".to_string(),
+                        span: Span::new(Position::new(4, 1, 5), Position::new(7, 1, 8)),
+                    },
+                    AstElement::Invalid(Span::new(
+                        Position::new(7, 1, 8),
+                        Position::new(15, 1, 16)
+                    )),
+                    AstElement::Literal {
+                        value: " ".to_string(),
+                        span: Span::new(Position::new(15, 1, 16), Position::new(16, 1, 17)),
+                    },
+                    AstElement::Invalid(Span::new(
+                        Position::new(16, 1, 17),
+                        Position::new(23, 1, 24)
+                    )),
+                    AstElement::Literal {
+                        value: " ".to_string(),
+                        span: Span::new(Position::new(23, 1, 24), Position::new(24, 1, 25)),
+                    },
+                    AstElement::Argument {
+                        value: "d".into(),
+                        span: Span::new(Position::new(24, 1, 25), Position::new(27, 1, 28)),
+                    },
+                ],
+                vec![
+                    Error {
+                        kind: ErrorKind::EmptyArgument,
+                        message: message.to_string(),
+                        span: Span::new(Position::new(2, 1, 3), Position::new(4, 1, 5)),
+                    },
+                    Error {
+                        kind: ErrorKind::InvalidArgumentType,
+                        message: message.to_string(),
+                        span: Span::new(Position::new(11, 1, 12), Position::new(14, 1, 15)),
+                    },
+                    Error {
+                        kind: ErrorKind::UnmatchedClosingTag,
+                        message: message.to_string(),
+                        span: Span::new(Position::new(21, 1, 22), Position::new(22, 1, 23)),
+                    },
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn recover_inside_plural_option() {
+        let message = "{n, plural, one {{}} other {#}}";
+        let (ast, errors) = Parser::new(message, None).parse_with_recovery();
+        assert_eq!(
+            errors,
+            vec![Error {
+                kind: ErrorKind::EmptyArgument,
+                message: message.to_string(),
+                span: Span::new(Position::new(17, 1, 18), Position::new(19, 1, 20)),
+            }]
+        );
+        match &ast[..] {
+            [AstElement::Plural { options: PluralOrSelectOptions(options), .. }] => {
+                assert_eq!(
+                    options[0].1.value,
+                    vec![AstElement::Invalid(Span::new(
+                        Position::new(17, 1, 18),
+                        Position::new(19, 1, 20)
+                    ))]
+                );
+                assert_eq!(
+                    options[1].1.value,
+                    vec![AstElement::Pound(Span::new(
+                        Position::new(28, 1, 29),
+                        Position::new(29, 1, 30)
+                    ))]
+                );
+            }
+            _ => panic!("expected a plural argument, got {:?}", ast),
+        }
+    }
+
+    #[test]
+    fn recover_unclosed_argument() {
+        let message = "a {b, select, x {c}";
+        assert_eq!(
+            Parser::new(message, None).parse_with_recovery(),
+            (
+                vec![
+                    AstElement::Literal {
+                        value: "a ".to_string(),
+                        span: Span::new(Position::new(0, 1, 1), Position::new(2, 1, 3)),
+                    },
+                    AstElement::Invalid(Span::new(
+                        Position::new(2, 1, 3),
+                        Position::new(19, 1, 20)
+                    )),
+                ],
+                vec![Error {
+                    kind: ErrorKind::ExpectArgumentClosingBrace,
+                    message: message.to_string(),
+                    span: Span::new(Position::new(2, 1, 3), Position::new(19, 1, 20)),
+                }]
+            )
+        );
+    }
+
+    #[test]
+    fn recover_closing_tag_of_invalid_tag() {
+        let message = "<b!>x</b!> <a></a>";
+        assert_eq!(
+            Parser::new(message, None).parse_with_recovery(),
+            (
+                vec![
+                    AstElement::Invalid(Span::new(Position::new(0, 1, 1), Position::new(4, 1, 5))),
+                    AstElement::Literal {
+                        value: "x".to_string(),
+                        span: Span::new(Position::new(4, 1, 5), Position::new(5, 1, 6)),
+                    },
+                    AstElement::Invalid(Span::new(
+                        Position::new(5, 1, 6),
+                        Position::new(10, 1, 11)
+                    )),
+                    AstElement::Literal {
+                        value: " ".to_string(),
+                        span: Span::new(Position::new(10, 1, 11), Position::new(11, 1, 12)),
+                    },
+                    AstElement::Tag {
+                        value: "a".into(),
+                        span: Span::new(Position::new(11, 1, 12), Position::new(18, 1, 19)),
+                        children: Box::new(vec![]),
+                    },
+                ],
+                vec![Error {
+                    kind: ErrorKind::InvalidTag,
+                    message: message.to_string(),
+                    span: Span::new(Position::new(0, 1, 1), Position::new(2, 1, 3)),
+                }]
+            )
+        );
+    }
+
+    #[test]
+    fn recover_without_errors() {
+        assert_eq!(
+            Parser::new("{a}", None).parse_with_recovery(),
+            (
+                vec![AstElement::Argument {
+                    value: "a".into(),
+                    span: Span::new(Position::new(0, 1, 1), Position::new(3, 1, 4))
+                }],
+                vec![]
+            )
+        );
+    }
+
     // TODO: port https://github.com/formatjs/formatjs/blob/main/packages/intl-messageformat-parser/tests/nested.test.ts
     // TODO: port https://github.com/formatjs/formatjs/blob/main/packages/intl-messageformat-parser/tests/index.test.ts
 }
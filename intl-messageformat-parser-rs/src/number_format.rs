@@ -0,0 +1,382 @@
+use crate::js_intl::*;
+use data::LOCALE_NUMBERS;
+
+mod data;
+
+/// The symbols and patterns of the default numbering system of locales, compiled in from the
+/// CLDR `numbers.json` data.
+/// See: https://github.com/unicode-org/cldr-json/tree/main/cldr-json/cldr-numbers-full
+#[derive(Debug)]
+struct LocaleNumbers {
+    /// The locales that use these numbers, separated by spaces.
+    locales: &'static str,
+    /// The digits from 0 to 9.
+    digits: [char; 10],
+    decimal: &'static str,
+    group: &'static str,
+    plus_sign: &'static str,
+    minus_sign: &'static str,
+    infinity: &'static str,
+    nan: &'static str,
+    /// The minimum number of digits before the first group separator, e.g. `2` if `1234` is not
+    /// grouped but `12,345` is.
+    minimum_grouping_digits: usize,
+    decimal_pattern: NumberPattern,
+    percent_pattern: NumberPattern,
+}
+
+/// A number pattern such as `#,##,##0 %`, with its symbols replaced.
+/// See: https://unicode.org/reports/tr35/tr35-numbers.html#Number_Format_Patterns
+#[derive(Debug)]
+struct NumberPattern {
+    prefix: &'static str,
+    suffix: &'static str,
+    /// The primary and secondary grouping sizes, e.g. `(3, 2)` for `#,##,##0`, or `(0, 0)` if
+    /// the integer digits are not grouped.
+    grouping: (usize, usize),
+}
+
+impl LocaleNumbers {
+    /// The numbers of the locale. The locale is matched as a whole first (e.g. `en-IN`), then
+    /// without its last subtag until the root locale is used.
+    fn new(locale: &str) -> &'static LocaleNumbers {
+        let find = |locale: &str| {
+            LOCALE_NUMBERS.iter().find(|numbers| numbers.locales.split(' ').any(|l| l == locale))
+        };
+        let mut locale = locale.to_ascii_lowercase().replace('-', "_");
+        loop {
+            if let Some(numbers) = find(&locale) {
+                return numbers;
+            }
+            match locale.rfind('_') {
+                Some(index) => locale.truncate(index),
+                None => return find("root").expect("the CLDR data has a root locale"),
+            }
+        }
+    }
+}
+
+/// The decimal digits of a finite, non-negative number, without leading or trailing zeros.
+#[derive(Debug)]
+struct Decimal {
+    /// Empty if the number is zero.
+    digits: Vec<u8>,
+    /// The number of integer digits, which is negative if the number has leading fraction
+    /// zeros, e.g. `-1` for `0.012`.
+    integer_digits: i32,
+}
+
+impl Decimal {
+    /// The shortest decimal representation of the number, which is what ICU rounds.
+    fn new(number: f64) -> Decimal {
+        let scientific = format!("{:e}", number);
+        let (mantissa, exponent) = scientific.split_at(scientific.find('e').unwrap());
+        let mut decimal = Decimal {
+            digits: mantissa.bytes().filter(u8::is_ascii_digit).map(|b| b - b'0').collect(),
+            integer_digits: exponent[1..].parse::<i32>().unwrap() + 1,
+        };
+        decimal.trim();
+        decimal
+    }
+
+    fn trim(&mut self) {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+        if self.digits.is_empty() {
+            self.integer_digits = 0;
+        }
+    }
+
+    /// Round half away from zero to `length` digits (which may be negative), like the default
+    /// `halfExpand` rounding mode of `Intl.NumberFormat`.
+    fn round(&mut self, length: i32) {
+        if length < 0 {
+            self.digits.clear();
+        } else if (length as usize) < self.digits.len() {
+            let round_up = self.digits[length as usize] >= 5;
+            self.digits.truncate(length as usize);
+            if round_up {
+                // The trailing nines become zeros, which are trimmed.
+                while self.digits.last() == Some(&9) {
+                    self.digits.pop();
+                }
+                match self.digits.last_mut() {
+                    Some(digit) => *digit += 1,
+                    None => {
+                        self.digits.push(1);
+                        self.integer_digits += 1;
+                    }
+                }
+            }
+        }
+        self.trim();
+    }
+
+    /// The integer and fraction digits, with zeros where the number has no digits.
+    fn split(&self) -> (Vec<u8>, Vec<u8>) {
+        let integer_digits = self.integer_digits.max(0) as usize;
+        let mut integer: Vec<u8> = self.digits.iter().copied().take(integer_digits).collect();
+        integer.resize(integer_digits, 0);
+        let mut fraction = vec![0; (-self.integer_digits).max(0) as usize];
+        fraction.extend(self.digits.iter().skip(integer_digits));
+        (integer, fraction)
+    }
+}
+
+/// Format the number like `Intl.NumberFormat` with the `decimal` or `percent` style and the
+/// `standard` notation, using the symbols and patterns of the locale.
+///
+/// `scale` is applied first, like formatjs does. The currency and unit options are ignored.
+pub fn format_number(number: f64, locale: &str, options: &JsIntlNumberFormatOptions) -> String {
+    let numbers = LocaleNumbers::new(locale);
+    let is_percent = options.style == Some(NumberFormatStyle::Percent);
+    let pattern = if is_percent { &numbers.percent_pattern } else { &numbers.decimal_pattern };
+    let number = number * options.scale.unwrap_or(1.0);
+
+    let (body, is_zero) = if number.is_nan() {
+        (numbers.nan.to_string(), true)
+    } else if number.is_infinite() {
+        (numbers.infinity.to_string(), false)
+    } else {
+        let mut decimal = Decimal::new(number.abs());
+        if is_percent && !decimal.digits.is_empty() {
+            decimal.integer_digits += 2;
+        }
+        let (integer, fraction) = round_decimal(&mut decimal, is_percent, options);
+        let mut body = String::new();
+        let (primary, secondary) = pattern.grouping;
+        let is_grouped = options.use_grouping != Some(false)
+            && primary > 0
+            && integer.len() >= primary + numbers.minimum_grouping_digits;
+        for (index, digit) in integer.iter().enumerate() {
+            let remaining = integer.len() - index;
+            if is_grouped
+                && index > 0
+                && remaining >= primary
+                && (remaining - primary) % secondary == 0
+            {
+                body.push_str(numbers.group);
+            }
+            body.push(numbers.digits[*digit as usize]);
+        }
+        if !fraction.is_empty() {
+            body.push_str(numbers.decimal);
+            body.extend(fraction.iter().map(|digit| numbers.digits[*digit as usize]));
+        }
+        (body, decimal.digits.is_empty())
+    };
+
+    // NaN is not negative, and `-0` is, like in `Intl.NumberFormat`.
+    let is_negative = !number.is_nan() && number.is_sign_negative();
+    let sign = match options.sign_display.unwrap_or(SignDisplay::Auto) {
+        SignDisplay::Auto | SignDisplay::Always if is_negative => numbers.minus_sign,
+        SignDisplay::ExceptZero if is_zero => "",
+        SignDisplay::ExceptZero if is_negative => numbers.minus_sign,
+        SignDisplay::Always | SignDisplay::ExceptZero => numbers.plus_sign,
+        _ => "",
+    };
+    format!("{}{}{}{}", sign, pattern.prefix, body, pattern.suffix)
+}
+
+/// Round the decimal to the significant or fraction digits of the options, and return its
+/// integer and fraction digits padded to the minimum number of digits.
+fn round_decimal(
+    decimal: &mut Decimal,
+    is_percent: bool,
+    options: &JsIntlNumberFormatOptions,
+) -> (Vec<u8>, Vec<u8>) {
+    let is_significant = options.minimum_significant_digits.is_some()
+        || options.maximum_significant_digits.is_some();
+    let minimum_fraction_digits = if is_significant {
+        // The significant digits take precedence over the fraction digits.
+        let minimum = options.minimum_significant_digits.unwrap_or(1);
+        let maximum = options.maximum_significant_digits.unwrap_or(21).max(minimum);
+        decimal.round(maximum as i32);
+        // The integer digits of zero count as one significant digit.
+        let significant_digits = if decimal.digits.is_empty() {
+            1
+        } else {
+            decimal.digits.len().max(decimal.integer_digits.max(0) as usize)
+        };
+        let (_, fraction) = decimal.split();
+        fraction.len() + minimum.saturating_sub(significant_digits)
+    } else {
+        let minimum = options.minimum_fraction_digits.unwrap_or(0);
+        let default_maximum = if is_percent { 0 } else { 3 };
+        let maximum = options.maximum_fraction_digits.unwrap_or(default_maximum).max(minimum);
+        decimal.round(decimal.integer_digits + maximum as i32);
+        minimum
+    };
+
+    let (mut integer, mut fraction) = decimal.split();
+    let minimum_integer_digits = options.minimum_integer_digits.unwrap_or(1);
+    if integer.len() < minimum_integer_digits {
+        integer.splice(0..0, vec![0; minimum_integer_digits - integer.len()]);
+    }
+    if fraction.len() < minimum_fraction_digits {
+        fraction.resize(minimum_fraction_digits, 0);
+    }
+    if options.trailing_zero_display == Some(TrailingZeroDisplay::StripIfInteger)
+        && fraction.iter().all(|digit| *digit == 0)
+    {
+        fraction.clear();
+    }
+    (integer, fraction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn format(number: f64, locale: &str, options: JsIntlNumberFormatOptions) -> String {
+        format_number(number, locale, &options)
+    }
+
+    #[test]
+    fn format_decimal() {
+        let options = JsIntlNumberFormatOptions::default;
+        assert_eq!(format(1234567.891, "en", options()), "1,234,567.891");
+        assert_eq!(format(1234567.8915, "en-US", options()), "1,234,567.892");
+        assert_eq!(format(-0.0001, "en", options()), "-0");
+        assert_eq!(format(0.5e-3, "en", options()), "0.001");
+        assert_eq!(format(999.9999, "en", options()), "1,000");
+        assert_eq!(format(1.005, "en", options()), "1.005");
+        assert_eq!(format(1e21, "en", options()), "1,000,000,000,000,000,000,000");
+        assert_eq!(format(1234.5, "de", options()), "1.234,5");
+        assert_eq!(format(1234567.5, "fr", options()), "1\u{202f}234\u{202f}567,5");
+        assert_eq!(format(-1234567.5, "en-IN", options()), "-12,34,567.5");
+        assert_eq!(format(-1234567.5, "ar-SA", options()), "\u{61c}-١٬٢٣٤٬٥٦٧٫٥");
+        assert_eq!(format(f64::NEG_INFINITY, "en", options()), "-∞");
+        assert_eq!(format(f64::NAN, "ar", options()), "ليس\u{a0}رقم\u{64b}ا");
+    }
+
+    #[test]
+    fn format_minimum_grouping_digits() {
+        let options = JsIntlNumberFormatOptions::default;
+        assert_eq!(format(1234.0, "es", options()), "1234");
+        assert_eq!(format(12345.0, "es", options()), "12.345");
+        assert_eq!(format(1234.0, "es-MX", options()), "1,234");
+        assert_eq!(format(1234.0, "pl", options()), "1234");
+        assert_eq!(format(12345.0, "pl", options()), "12\u{a0}345");
+        assert_eq!(
+            format(
+                12345.0,
+                "en",
+                JsIntlNumberFormatOptions { use_grouping: Some(false), ..options() }
+            ),
+            "12345"
+        );
+    }
+
+    #[test]
+    fn format_unknown_locale() {
+        let options = JsIntlNumberFormatOptions::default;
+        assert_eq!(format(1234.5, "xx", options()), "1,234.5");
+        assert_eq!(format(1234.5, "de-XX-u-nu-latn", options()), "1.234,5");
+    }
+
+    #[test]
+    fn format_percent() {
+        let options = || JsIntlNumberFormatOptions {
+            style: Some(NumberFormatStyle::Percent),
+            ..Default::default()
+        };
+        assert_eq!(format(0.1234, "en", options()), "12%");
+        assert_eq!(format(0.125, "en", options()), "13%");
+        assert_eq!(format(-0.12, "tr", options()), "-%12");
+        assert_eq!(format(12.5, "de", options()), "1.250\u{a0}%");
+        assert_eq!(format(f64::NAN, "en", options()), "NaN%");
+        assert_eq!(
+            format(0.5, "en", JsIntlNumberFormatOptions { scale: Some(100.0), ..options() }),
+            "5,000%"
+        );
+    }
+
+    #[test]
+    fn format_fraction_digits() {
+        let options = |minimum, maximum| JsIntlNumberFormatOptions {
+            minimum_fraction_digits: minimum,
+            maximum_fraction_digits: maximum,
+            ..Default::default()
+        };
+        assert_eq!(format(1.0, "en", options(Some(2), None)), "1.00");
+        assert_eq!(format(1.23456, "en", options(Some(2), None)), "1.235");
+        assert_eq!(format(1.23456, "en", options(Some(2), Some(4))), "1.2346");
+        assert_eq!(format(1.2, "en", options(Some(2), Some(4))), "1.20");
+        assert_eq!(format(0.5, "en", options(None, Some(0))), "1");
+        assert_eq!(format(-0.5, "en", options(None, Some(0))), "-1");
+        assert_eq!(
+            format(
+                1.001,
+                "en",
+                JsIntlNumberFormatOptions {
+                    trailing_zero_display: Some(TrailingZeroDisplay::StripIfInteger),
+                    ..options(Some(2), None)
+                }
+            ),
+            "1.001"
+        );
+        assert_eq!(
+            format(
+                1.0001,
+                "en",
+                JsIntlNumberFormatOptions {
+                    trailing_zero_display: Some(TrailingZeroDisplay::StripIfInteger),
+                    ..options(Some(2), None)
+                }
+            ),
+            "1"
+        );
+        assert_eq!(
+            format(
+                1.5,
+                "en",
+                JsIntlNumberFormatOptions {
+                    minimum_integer_digits: Some(3),
+                    ..options(None, None)
+                }
+            ),
+            "001.5"
+        );
+    }
+
+    #[test]
+    fn format_significant_digits() {
+        let options = |minimum, maximum| JsIntlNumberFormatOptions {
+            minimum_significant_digits: minimum,
+            maximum_significant_digits: maximum,
+            // Ignored when significant digits are set.
+            maximum_fraction_digits: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(format(1234.5, "en", options(None, Some(2))), "1,200");
+        assert_eq!(format(0.012345, "en", options(None, Some(2))), "0.012");
+        assert_eq!(format(9.99, "en", options(None, Some(2))), "10");
+        assert_eq!(format(1.0, "en", options(Some(2), None)), "1.0");
+        assert_eq!(format(1200.0, "en", options(Some(5), None)), "1,200.0");
+        assert_eq!(format(0.0, "en", options(Some(3), None)), "0.00");
+        assert_eq!(format(0.1, "en", options(Some(3), None)), "0.100");
+    }
+
+    #[test]
+    fn format_sign_display() {
+        let options = |sign_display| JsIntlNumberFormatOptions {
+            sign_display: Some(sign_display),
+            ..Default::default()
+        };
+        let cases = [
+            (SignDisplay::Auto, ["-1", "-0", "0", "1", "NaN"]),
+            (SignDisplay::Always, ["-1", "-0", "+0", "+1", "+NaN"]),
+            (SignDisplay::ExceptZero, ["-1", "0", "0", "+1", "NaN"]),
+            (SignDisplay::Never, ["1", "0", "0", "1", "NaN"]),
+        ];
+        for (sign_display, expected) in cases {
+            let formatted = [-1.0, -0.0, 0.0, 1.0, f64::NAN]
+                .map(|number| format(number, "en", options(sign_display)));
+            assert_eq!(formatted, expected, "{:?}", sign_display);
+        }
+        assert_eq!(format(1.0, "he", options(SignDisplay::Always)), "\u{200e}+1");
+    }
+}
@@ -0,0 +1,968 @@
+// Generated by scripts/generate-number-symbols.mjs from CLDR 48, do not edit.
+
+use super::{LocaleNumbers, NumberPattern};
+
+pub(super) static LOCALE_NUMBERS: &[LocaleNumbers] = &[
+    LocaleNumbers {
+        locales: "af agq dyo en_cz en_ee en_hu en_lt en_lv en_pt en_sk en_ua en_za es_cr ewo ff \
+                  kab kea ksf nmg prg pt_ao shi szl tg tzm uk yav",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "am",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "በቁጥር\u{a0}ሊገለጽ\u{a0}የማይችል",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ar",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "\u{200e}+",
+        minus_sign: "\u{200e}-",
+        infinity: "∞",
+        nan: "ليس\u{a0}رقمًا",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern {
+            prefix: "",
+            suffix: "\u{200e}%\u{200e}",
+            grouping: (3, 3),
+        },
+    },
+    LocaleNumbers {
+        locales: "ar_bh ar_dj ar_eg ar_er ar_il ar_iq ar_jo ar_km ar_kw ar_lb ar_mr ar_om ar_ps \
+                  ar_qa ar_sa ar_sd ar_so ar_ss ar_sy ar_td ar_ye ars",
+        digits: ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'],
+        decimal: "٫",
+        group: "٬",
+        plus_sign: "\u{61c}+",
+        minus_sign: "\u{61c}-",
+        infinity: "∞",
+        nan: "ليس\u{a0}رقمًا",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "٪\u{61c}", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ar_dz ar_ly ar_ma ar_tn",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: ".",
+        plus_sign: "\u{200e}+",
+        minus_sign: "\u{200e}-",
+        infinity: "∞",
+        nan: "ليس\u{a0}رقمًا",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern {
+            prefix: "",
+            suffix: "\u{200e}%\u{200e}",
+            grouping: (3, 3),
+        },
+    },
+    LocaleNumbers {
+        locales: "as bn",
+        digits: ['০', '১', '২', '৩', '৪', '৫', '৬', '৭', '৮', '৯'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 2) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 2) },
+    },
+    LocaleNumbers {
+        locales: "ast",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: ".",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "ND",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "az bs el en_be en_es en_id en_it en_nl en_pl en_ro en_si es_ar es_bo es_cl \
+                  es_co es_ec es_py es_uy es_ve fur fy id is jgo jv kgp kkj lij ln lu mgh ms_bn \
+                  ms_id mua nl nnh pms pt rw sc scn seh sg sr su sw_cd vi vmw wo yrl",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: ".",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ba dje khq mfe ses twq tyv xh",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "bas br bua cs de_at dua en_fi en_no en_se fr_ca sk tt zgh",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "be",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 2,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "bg hu ie pl pt_ch pt_cv pt_gq pt_gw pt_lu pt_mo pt_mz pt_pt pt_st pt_tl sq",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 2,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "bgc bho raj",
+        digits: ['०', '१', '२', '३', '४', '५', '६', '७', '८', '९'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "blo",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "%\u{a0}", suffix: "", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "brx en_in gu hi kok kxv ml or pa ta te xnr",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 2) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 2) },
+    },
+    LocaleNumbers {
+        locales: "ca da de dsb en_at en_de en_dk fr_lu fr_ma gl hsb kl lb mk nds qu_bo rn ro",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: ".",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ccp",
+        digits: ['𑄶', '𑄷', '𑄸', '𑄹', '𑄺', '𑄻', '𑄼', '𑄽', '𑄾', '𑄿'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 2) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 2) },
+    },
+    LocaleNumbers {
+        locales: "ce",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "Терхьаш\u{a0}дац",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ckb",
+        digits: ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'],
+        decimal: "٫",
+        group: "٬",
+        plus_sign: "\u{200f}+",
+        minus_sign: "\u{200f}-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}٪", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "cv",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "хисеп\u{a0}мар",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "de_ch de_li en_ch tn",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: "'",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "dz",
+        digits: ['༠', '༡', '༢', '༣', '༤', '༥', '༦', '༧', '༨', '༩'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "གྲངས་མེད",
+        nan: "ཨང་མད",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 2) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 2) },
+    },
+    LocaleNumbers {
+        locales: "ee",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "mnn",
+        minimum_grouping_digits: 3,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "en_fr en_ge eo fr_ch rm vec",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{202f}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "es",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: ".",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 2,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "es_419 es_br es_bz es_cu es_do es_gt es_hn es_mx es_ni es_pa es_pe es_pr es_sv \
+                  es_us ks_deva root sd_deva sd_in ta_my ta_sg",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "et",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "−",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 2,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "eu",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: ".",
+        plus_sign: "+",
+        minus_sign: "−",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "%\u{a0}", suffix: "", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "fa",
+        digits: ['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹'],
+        decimal: "٫",
+        group: "٬",
+        plus_sign: "\u{200e}+",
+        minus_sign: "\u{200e}−",
+        infinity: "∞",
+        nan: "ناعدد",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "٪", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ff_adlm",
+        digits: ['𞥐', '𞥑', '𞥒', '𞥓', '𞥔', '𞥕', '𞥖', '𞥗', '𞥘', '𞥙'],
+        decimal: ".",
+        group: "⹁",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "fi",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "−",
+        infinity: "∞",
+        nan: "epäluku",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "fo hr",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: ".",
+        plus_sign: "+",
+        minus_sign: "−",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "fr",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{202f}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ga",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "Nuimh",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "gsw",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: "'",
+        plus_sign: "+",
+        minus_sign: "−",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "he ur",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "\u{200e}+",
+        minus_sign: "\u{200e}-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "hy",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "ՈչԹ",
+        minimum_grouping_digits: 2,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ia it",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: ".",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 2,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "it_ch",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: "'",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 2,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ka",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "არ\u{a0}არის\u{a0}რიცხვი",
+        minimum_grouping_digits: 2,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "kk",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "сан\u{a0}емес",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "kk_arab",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "سان\u{a0}ەمەس",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ks lrc mzn pa_arab pa_pk ps uz_af uz_arab",
+        digits: ['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹'],
+        decimal: "٫",
+        group: "٬",
+        plus_sign: "\u{200e}+\u{200e}",
+        minus_sign: "\u{200e}-\u{200e}",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "٪", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ksh lt nb nn no se sv",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "−",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ku tr",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: ".",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "%", suffix: "", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ky",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "сан\u{a0}эмес",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "lmo wae",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "'",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "lo",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: ".",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "ບໍ່\u{200b}ແມ່ນ\u{200b}ໂຕ\u{200b}ເລກ",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "lv",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NS",
+        minimum_grouping_digits: 2,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "mni",
+        digits: ['০', '১', '২', '৩', '৪', '৫', '৬', '৭', '৮', '৯'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "mr ne sa",
+        digits: ['०', '१', '२', '३', '४', '५', '६', '७', '८', '९'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 2) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 2) },
+    },
+    LocaleNumbers {
+        locales: "my",
+        digits: ['၀', '၁', '၂', '၃', '၄', '၅', '၆', '၇', '၈', '၉'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "ဂဏန်းမဟုတ်သော",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "nqo",
+        digits: ['߀', '߁', '߂', '߃', '߄', '߅', '߆', '߇', '߈', '߉'],
+        decimal: ".",
+        group: "،",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "oc",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{202f}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "os",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "НН",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "qu",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ru",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "не\u{a0}число",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "ru_ua",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "не\u{a0}число",
+        minimum_grouping_digits: 2,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "sah",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "чыыһыла\u{a0}буотах",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "sat",
+        digits: ['᱐', '᱑', '᱒', '᱓', '᱔', '᱕', '᱖', '᱗', '᱘', '᱙'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "sd",
+        digits: ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'],
+        decimal: ".",
+        group: "٬",
+        plus_sign: "\u{61c}+",
+        minus_sign: "\u{61c}-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "٪\u{61c}", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "sl",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: ".",
+        plus_sign: "+",
+        minus_sign: "−",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 2,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "smn",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "epiloho",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "so",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "MaL",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "syr",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "ܠܝܬ\u{a0}ܡܢܝܢܐ",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "tk",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "san\u{a0}däl",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "\u{a0}%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "to",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "TF",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "tok",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (2, 2) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (2, 2) },
+    },
+    LocaleNumbers {
+        locales: "ur_in",
+        digits: ['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹'],
+        decimal: "٫",
+        group: "٬",
+        plus_sign: "\u{200e}+\u{200e}",
+        minus_sign: "\u{200e}-\u{200e}",
+        infinity: "∞",
+        nan: "NaN",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "uz",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "son\u{a0}emas",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "uz_cyrl",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ",",
+        group: "\u{a0}",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "ҳақиқий\u{a0}сон\u{a0}эмас",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "yue zh_hant zh_hk zh_mo zh_tw",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "非數值",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+    LocaleNumbers {
+        locales: "yue_cn yue_hans",
+        digits: ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+        decimal: ".",
+        group: ",",
+        plus_sign: "+",
+        minus_sign: "-",
+        infinity: "∞",
+        nan: "非数值",
+        minimum_grouping_digits: 1,
+        decimal_pattern: NumberPattern { prefix: "", suffix: "", grouping: (3, 3) },
+        percent_pattern: NumberPattern { prefix: "", suffix: "%", grouping: (3, 3) },
+    },
+];
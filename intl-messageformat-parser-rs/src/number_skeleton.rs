@@ -0,0 +1,385 @@
+use crate::ast::{ErrorKind, NumberSkeletonToken};
+use crate::js_intl::*;
+use std::result;
+
+type Result<T> = result::Result<T, ErrorKind>;
+
+/// Convert the tokens of an ICU number skeleton into `Intl.NumberFormatOptions`. Adapted from:
+/// https://github.com/formatjs/formatjs/blob/main/packages/icu-skeleton-parser/number.ts
+///
+/// Unknown stems are ignored, like the reference implementation does.
+/// See: https://unicode-org.github.io/icu/userguide/format_parse/numbers/skeletons.html
+pub fn parse_number_skeleton(tokens: &[NumberSkeletonToken]) -> Result<JsIntlNumberFormatOptions> {
+    let mut result = JsIntlNumberFormatOptions::default();
+
+    for token in tokens {
+        match token.stem.as_ref() {
+            "percent" | "%" => {
+                result.style = Some(NumberFormatStyle::Percent);
+            }
+            "%x100" => {
+                result.style = Some(NumberFormatStyle::Percent);
+                result.scale = Some(100.0);
+            }
+            "currency" => {
+                result.style = Some(NumberFormatStyle::Currency);
+                result.currency = Some(first_option(token)?.to_string());
+            }
+            "group-off" | ",_" => {
+                result.use_grouping = Some(false);
+            }
+            "precision-integer" | "." => {
+                result.maximum_fraction_digits = Some(0);
+            }
+            "measure-unit" | "unit" => {
+                result.style = Some(NumberFormatStyle::Unit);
+                result.unit = Some(icu_unit_to_ecma(first_option(token)?).to_string());
+            }
+            "compact-short" | "K" => {
+                result.notation = Some(Notation::Compact);
+                result.compact_display = Some(CompactDisplay::Short);
+            }
+            "compact-long" | "KK" => {
+                result.notation = Some(Notation::Compact);
+                result.compact_display = Some(CompactDisplay::Long);
+            }
+            "scientific" | "engineering" => {
+                result.notation = Some(if token.stem == "scientific" {
+                    Notation::Scientific
+                } else {
+                    Notation::Engineering
+                });
+                for option in &token.options {
+                    if let Some(sign_options) = parse_sign(option) {
+                        result.merge(sign_options);
+                    }
+                }
+            }
+            "notation-simple" => {
+                result.notation = Some(Notation::Standard);
+            }
+            "unit-width-narrow" => {
+                result.currency_display = Some(CurrencyDisplay::NarrowSymbol);
+                result.unit_display = Some(UnitDisplay::Narrow);
+            }
+            "unit-width-short" => {
+                result.currency_display = Some(CurrencyDisplay::Code);
+                result.unit_display = Some(UnitDisplay::Short);
+            }
+            "unit-width-full-name" => {
+                result.currency_display = Some(CurrencyDisplay::Name);
+                result.unit_display = Some(UnitDisplay::Long);
+            }
+            "unit-width-iso-code" => {
+                result.currency_display = Some(CurrencyDisplay::Symbol);
+            }
+            "scale" => {
+                let scale = first_option(token)?
+                    .parse::<f64>()
+                    .map_err(|_| ErrorKind::InvalidNumberSkeleton)?;
+                if !scale.is_finite() {
+                    return Err(ErrorKind::InvalidNumberSkeleton);
+                }
+                result.scale = Some(scale);
+            }
+            "integer-width" => {
+                if token.options.len() > 1 {
+                    return Err(ErrorKind::InvalidNumberSkeleton);
+                }
+                // Only `*0+` (minimum integer digits) is supported, the maximum and exact integer
+                // digit variants cannot be expressed in `Intl.NumberFormatOptions`.
+                let option = first_option(token)?;
+                match option.strip_prefix('*') {
+                    Some(zeros) if is_non_empty_repeat(zeros, '0') => {
+                        result.minimum_integer_digits = Some(zeros.len());
+                    }
+                    _ => return Err(ErrorKind::InvalidNumberSkeleton),
+                }
+            }
+            stem if is_non_empty_repeat(stem, '0') => {
+                // Concise integer width, e.g. `000`.
+                result.minimum_integer_digits = Some(stem.len());
+            }
+            stem if parse_fraction_precision(stem).is_some() => {
+                if token.options.len() > 1 {
+                    return Err(ErrorKind::InvalidNumberSkeleton);
+                }
+                if let Some((minimum, maximum)) = parse_fraction_precision(stem) {
+                    result.minimum_fraction_digits = minimum.or(result.minimum_fraction_digits);
+                    result.maximum_fraction_digits = maximum.or(result.maximum_fraction_digits);
+                }
+
+                match token.options.first() {
+                    Some(option) if option == "w" => {
+                        result.trailing_zero_display = Some(TrailingZeroDisplay::StripIfInteger);
+                    }
+                    Some(option) => {
+                        if let Some(significant_options) = parse_significant_precision(option) {
+                            result.merge(significant_options);
+                        }
+                    }
+                    None => (),
+                }
+            }
+            stem if parse_significant_precision(stem).is_some() => {
+                if let Some(significant_options) = parse_significant_precision(stem) {
+                    result.merge(significant_options);
+                }
+            }
+            stem => {
+                if let Some(sign_options) = parse_sign(stem) {
+                    result.merge(sign_options);
+                }
+                if let Some(notation_options) = parse_concise_scientific_and_engineering(stem)? {
+                    result.merge(notation_options);
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn first_option<'t>(token: &'t NumberSkeletonToken) -> Result<&'t str> {
+    token.options.first().map(|option| option.as_ref()).ok_or(ErrorKind::InvalidNumberSkeleton)
+}
+
+/// Strip the ICU unit type prefix, e.g. `length-meter` becomes `meter`.
+fn icu_unit_to_ecma(unit: &str) -> &str {
+    match unit.find('-') {
+        Some(index) => &unit[index + 1..],
+        None => unit,
+    }
+}
+
+/// Return true if `s` is non-empty and only consists of `c`.
+fn is_non_empty_repeat(s: &str, c: char) -> bool {
+    !s.is_empty() && s.chars().all(|x| x == c)
+}
+
+/// Parse the fraction precision stem (`.00`, `.00*`, `.##`, `.00##`) into the minimum and
+/// maximum fraction digits.
+fn parse_fraction_precision(stem: &str) -> Option<(Option<usize>, Option<usize>)> {
+    let digits = stem.strip_prefix('.')?;
+    let zeros = digits.len() - digits.trim_start_matches('0').len();
+    let rest = &digits[zeros..];
+
+    match rest {
+        "" if zeros > 0 => Some((Some(zeros), Some(zeros))),
+        "*" if zeros > 0 => Some((Some(zeros), None)),
+        _ if is_non_empty_repeat(rest, '#') => {
+            if zeros == 0 {
+                Some((None, Some(rest.len())))
+            } else {
+                Some((Some(zeros), Some(zeros + rest.len())))
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Parse the significant digits precision (`@@@`, `@@*`, `@@##`). `@@+` is the pre-ICU 67 syntax
+/// of `@@*`.
+fn parse_significant_precision(s: &str) -> Option<JsIntlNumberFormatOptions> {
+    let rest = s.trim_start_matches('@');
+    let at_signs = s.len() - rest.len();
+    if at_signs == 0 {
+        return None;
+    }
+
+    let mut result = JsIntlNumberFormatOptions::default();
+    match rest {
+        "" => {
+            result.minimum_significant_digits = Some(at_signs);
+            result.maximum_significant_digits = Some(at_signs);
+        }
+        "*" | "+" => {
+            result.minimum_significant_digits = Some(at_signs);
+        }
+        _ if is_non_empty_repeat(rest, '#') => {
+            result.minimum_significant_digits = Some(at_signs);
+            result.maximum_significant_digits = Some(at_signs + rest.len());
+        }
+        _ => return None,
+    }
+    Some(result)
+}
+
+fn parse_sign(s: &str) -> Option<JsIntlNumberFormatOptions> {
+    let (sign_display, currency_sign) = match s {
+        "sign-auto" => (Some(SignDisplay::Auto), None),
+        "sign-accounting" | "()" => (None, Some(CurrencySign::Accounting)),
+        "sign-always" | "+!" => (Some(SignDisplay::Always), None),
+        "sign-accounting-always" | "()!" => {
+            (Some(SignDisplay::Always), Some(CurrencySign::Accounting))
+        }
+        "sign-except-zero" | "+?" => (Some(SignDisplay::ExceptZero), None),
+        "sign-accounting-except-zero" | "()?" => {
+            (Some(SignDisplay::ExceptZero), Some(CurrencySign::Accounting))
+        }
+        "sign-never" | "+_" => (Some(SignDisplay::Never), None),
+        _ => return None,
+    };
+    Some(JsIntlNumberFormatOptions { sign_display, currency_sign, ..Default::default() })
+}
+
+/// Parse the concise scientific and engineering notation stems (e.g. `E0`, `EE+!00`).
+fn parse_concise_scientific_and_engineering(
+    stem: &str,
+) -> Result<Option<JsIntlNumberFormatOptions>> {
+    let (notation, rest) = if let Some(rest) = stem.strip_prefix("EE") {
+        (Notation::Engineering, rest)
+    } else if let Some(rest) = stem.strip_prefix('E') {
+        (Notation::Scientific, rest)
+    } else {
+        return Ok(None);
+    };
+
+    let mut result = JsIntlNumberFormatOptions { notation: Some(notation), ..Default::default() };
+    let rest = if let Some(rest) = rest.strip_prefix("+!") {
+        result.sign_display = Some(SignDisplay::Always);
+        rest
+    } else if let Some(rest) = rest.strip_prefix("+?") {
+        result.sign_display = Some(SignDisplay::ExceptZero);
+        rest
+    } else {
+        rest
+    };
+
+    if !is_non_empty_repeat(rest, '0') {
+        return Err(ErrorKind::InvalidNumberSkeleton);
+    }
+    result.minimum_integer_digits = Some(rest.len());
+
+    Ok(Some(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn parse(skeleton: &str) -> Result<serde_json::Value> {
+        let tokens: Vec<_> = skeleton
+            .split_whitespace()
+            .map(|token| {
+                let mut parts = token.split('/');
+                NumberSkeletonToken {
+                    stem: parts.next().unwrap().into(),
+                    options: parts.map(|option| option.into()).collect(),
+                }
+            })
+            .collect();
+        parse_number_skeleton(&tokens).map(|options| serde_json::to_value(options).unwrap())
+    }
+
+    /// Convenient macro to help parametrize tests with number skeleton strings.
+    macro_rules! number_skeleton_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (skeleton, expected) = $value;
+                    assert_eq!(parse(skeleton), expected);
+                }
+            )*
+        }
+    }
+
+    number_skeleton_tests! {
+        percent: ("percent", Ok(json!({"style": "percent"}))),
+        percent_concise: ("%x100", Ok(json!({"style": "percent", "scale": 100.0}))),
+        currency: (
+            "currency/USD unit-width-narrow",
+            Ok(json!({
+                "style": "currency",
+                "currency": "USD",
+                "currencyDisplay": "narrowSymbol",
+                "unitDisplay": "narrow",
+            }))
+        ),
+        currency_without_code: ("currency", Err(ErrorKind::InvalidNumberSkeleton)),
+        unit: ("unit/meter", Ok(json!({"style": "unit", "unit": "meter"}))),
+        measure_unit: (
+            "measure-unit/length-meter unit-width-full-name",
+            Ok(json!({
+                "style": "unit",
+                "unit": "meter",
+                "currencyDisplay": "name",
+                "unitDisplay": "long",
+            }))
+        ),
+        compact_short: (
+            "compact-short",
+            Ok(json!({"notation": "compact", "compactDisplay": "short"}))
+        ),
+        compact_long_concise: (
+            "KK",
+            Ok(json!({"notation": "compact", "compactDisplay": "long"}))
+        ),
+        notation_simple: ("notation-simple", Ok(json!({"notation": "standard"}))),
+        scientific_with_sign: (
+            "scientific/sign-always",
+            Ok(json!({"notation": "scientific", "signDisplay": "always"}))
+        ),
+        concise_engineering: (
+            "EE+?00",
+            Ok(json!({
+                "notation": "engineering",
+                "signDisplay": "exceptZero",
+                "minimumIntegerDigits": 2,
+            }))
+        ),
+        malformed_concise_scientific: ("E", Err(ErrorKind::InvalidNumberSkeleton)),
+        scale: ("scale/100", Ok(json!({"scale": 100.0}))),
+        invalid_scale: ("scale/abc", Err(ErrorKind::InvalidNumberSkeleton)),
+        sign_accounting_always: (
+            "()!",
+            Ok(json!({"signDisplay": "always", "currencySign": "accounting"}))
+        ),
+        group_off: (",_", Ok(json!({"useGrouping": false}))),
+        precision_integer: ("precision-integer", Ok(json!({"maximumFractionDigits": 0}))),
+        fraction_exact: (
+            ".00",
+            Ok(json!({"minimumFractionDigits": 2, "maximumFractionDigits": 2}))
+        ),
+        fraction_minimum: ("percent .000*", Ok(json!({
+            "style": "percent",
+            "minimumFractionDigits": 3,
+        }))),
+        fraction_maximum: (".##", Ok(json!({"maximumFractionDigits": 2}))),
+        fraction_range: (
+            ".00##",
+            Ok(json!({"minimumFractionDigits": 2, "maximumFractionDigits": 4}))
+        ),
+        fraction_with_significant: (
+            ".##/@@@+",
+            Ok(json!({"maximumFractionDigits": 2, "minimumSignificantDigits": 3}))
+        ),
+        fraction_with_trailing_zero_display: (
+            ".00/w",
+            Ok(json!({
+                "minimumFractionDigits": 2,
+                "maximumFractionDigits": 2,
+                "trailingZeroDisplay": "stripIfInteger",
+            }))
+        ),
+        malformed_fraction: (".0#0", Ok(json!({}))),
+        fraction_with_multiple_options: (".00/@@/w", Err(ErrorKind::InvalidNumberSkeleton)),
+        significant_exact: (
+            "@@@",
+            Ok(json!({"minimumSignificantDigits": 3, "maximumSignificantDigits": 3}))
+        ),
+        significant_minimum: ("@@*", Ok(json!({"minimumSignificantDigits": 2}))),
+        malformed_significant: ("@@#@", Ok(json!({}))),
+        significant_range: (
+            "@@#",
+            Ok(json!({"minimumSignificantDigits": 2, "maximumSignificantDigits": 3}))
+        ),
+        integer_width: ("integer-width/*000", Ok(json!({"minimumIntegerDigits": 3}))),
+        integer_width_exact: ("integer-width/000", Err(ErrorKind::InvalidNumberSkeleton)),
+        concise_integer_width: ("0000", Ok(json!({"minimumIntegerDigits": 4}))),
+        unknown_stem: ("rounding-mode-floor", Ok(json!({}))),
+    }
+}
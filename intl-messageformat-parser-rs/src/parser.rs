@@ -1,6 +1,10 @@
 use crate::ast::{self, *};
+use crate::date_time_skeleton::parse_date_time_skeleton;
+use crate::number_skeleton::parse_number_skeleton;
 use crate::pattern_syntax::is_pattern_syntax;
-use std::cell::Cell;
+use crate::plural_rules::{PluralCategory, PluralRules};
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
 use std::cmp;
 use std::collections::HashSet;
 use std::result;
@@ -12,14 +16,55 @@ pub struct Parser<'s> {
     position: Cell<Position>,
     message: &'s str,
     should_ignore_tag: bool,
+    requires_other_clause: bool,
+    should_omit_location: bool,
+    offset_encoding: OffsetEncoding,
+    /// The cardinal and ordinal plural rules of `ParserOptions::locale`, if it is supported.
+    cardinal_rules: Option<PluralRules>,
+    ordinal_rules: Option<PluralRules>,
+    /// If true, errors are collected into `errors` instead of aborting the parse.
+    recovering: bool,
+    errors: RefCell<Vec<ast::Error>>,
+    /// The names of the opening tags that failed to parse while recovering, whose closing tags
+    /// are skipped instead of being reported as unmatched.
+    invalid_opening_tags: RefCell<Vec<&'s str>>,
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct ParserOptions {
     pub should_ignore_tag: bool,
+    /// If true, `select`, `plural` and `selectordinal` arguments must have an `other` clause.
+    pub requires_other_clause: bool,
+    /// If set, the selectors of `plural` and `selectordinal` arguments must be cardinal and
+    /// ordinal plural categories of this locale respectively (e.g. `en-US`). Locales without
+    /// plural rules data are not checked.
+    pub locale: Option<String>,
+    /// The unit of `Position::offset` in every span that the parser emits.
+    pub offset_encoding: OffsetEncoding,
+    /// If true, the AST has no location info: every span is `Span::default()`, which is not
+    /// serialized. Like `captureLocation: false` in formatjs. Errors still have a span.
+    pub should_omit_location: bool,
 }
 
-pub const DEFAULT_PARSER_OPTIONS: &ParserOptions = &ParserOptions { should_ignore_tag: false };
+pub const DEFAULT_PARSER_OPTIONS: &ParserOptions = &ParserOptions {
+    should_ignore_tag: false,
+    requires_other_clause: false,
+    locale: None,
+    offset_encoding: OffsetEncoding::Utf8,
+    should_omit_location: false,
+};
+
+/// The unit of `Position::offset`. Columns always count Unicode scalar values, like formatjs.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OffsetEncoding {
+    /// UTF-8 bytes, which can be used to slice the message in Rust.
+    #[default]
+    Utf8,
+    /// UTF-16 code units, which is what JavaScript string indices and formatjs use.
+    Utf16,
+    /// Unicode scalar values.
+    CodePoint,
+}
 
 impl<'s> Parser<'s> {
     pub fn new(message: &'s str, options: Option<&ParserOptions>) -> Parser<'s> {
@@ -28,12 +73,93 @@ impl<'s> Parser<'s> {
             message,
             position: Cell::new(Position { offset: 0, line: 1, column: 1 }),
             should_ignore_tag: options.should_ignore_tag,
+            requires_other_clause: options.requires_other_clause,
+            should_omit_location: options.should_omit_location,
+            offset_encoding: options.offset_encoding,
+            cardinal_rules: options
+                .locale
+                .as_ref()
+                .and_then(|locale| PluralRules::try_new(locale, PluralType::Cardinal)),
+            ordinal_rules: options
+                .locale
+                .as_ref()
+                .and_then(|locale| PluralRules::try_new(locale, PluralType::Ordinal)),
+            recovering: false,
+            errors: RefCell::new(vec![]),
+            invalid_opening_tags: RefCell::new(vec![]),
+        }
+    }
+
+    pub fn parse(&mut self) -> Result<Ast<'s>> {
+        assert_eq!(self.offset(), 0, "parser can only be used once");
+        match self.parse_message(0, "", false) {
+            Ok(mut ast) => {
+                self.finish_spans(&mut ast, &mut []);
+                Ok(ast)
+            }
+            Err(mut error) => {
+                self.finish_spans(&mut [], std::slice::from_mut(&mut error));
+                Err(error)
+            }
         }
     }
 
-    pub fn parse(&mut self) -> Result<Ast> {
+    /// Parse the message without stopping at the first error.
+    ///
+    /// Whenever an argument or a tag fails to parse, the error is recorded and the parser
+    /// resynchronizes at the closing `}` of the argument or the `>` of the tag. The offending
+    /// source range is replaced by an `AstElement::Invalid` placeholder in the returned AST.
+    /// The returned errors are in the order they were encountered.
+    pub fn parse_with_recovery(&mut self) -> (Ast<'s>, Vec<ast::Error>) {
         assert_eq!(self.offset(), 0, "parser can only be used once");
-        self.parse_message(0, "", false)
+        self.recovering = true;
+        let mut ast = self
+            .parse_message(0, "", false)
+            .expect("the parser should not bail out when recovering from errors");
+        let mut errors = self.errors.replace(vec![]);
+        self.finish_spans(&mut ast, &mut errors);
+        (ast, errors)
+    }
+
+    /// Turn the spans that the parser tracked internally into the ones requested by the options.
+    fn finish_spans(&self, ast: &mut [AstElement], errors: &mut [ast::Error]) {
+        if self.should_omit_location {
+            // Only byte offsets were tracked, so the lines and columns of the errors are
+            // computed now. Errors are rare, unlike chars to bump past.
+            let locate = |offset: usize| {
+                self.message[..offset].chars().fold(Position::new(0, 1, 1), next_position)
+            };
+            for error in errors.iter_mut() {
+                error.span =
+                    Span::new(locate(error.span.start.offset), locate(error.span.end.offset));
+            }
+            for_each_span(ast, &|span| *span = Span::default());
+        }
+        self.encode_offsets(ast, errors);
+    }
+
+    /// Convert the UTF-8 byte offsets of the spans, which the parser uses internally, into
+    /// `offset_encoding`.
+    fn encode_offsets(&self, ast: &mut [AstElement], errors: &mut [ast::Error]) {
+        let unit_len: fn(char) -> usize = match self.offset_encoding {
+            OffsetEncoding::Utf8 => return,
+            OffsetEncoding::Utf16 => char::len_utf16,
+            OffsetEncoding::CodePoint => |_| 1,
+        };
+        // Encoded offset at each char boundary of the message
+        let mut offsets = vec![0; self.message.len() + 1];
+        for (index, ch) in self.message.char_indices() {
+            offsets[index + ch.len_utf8()] = offsets[index] + unit_len(ch);
+        }
+        let encode = |span: &mut Span| {
+            span.start.offset = offsets[span.start.offset];
+            span.end.offset = offsets[span.end.offset];
+        };
+
+        for_each_span(ast, &encode);
+        for error in errors {
+            encode(&mut error.span);
+        }
     }
 
     /// # Arguments
@@ -50,38 +176,129 @@ impl<'s> Parser<'s> {
         nesting_level: usize,
         parent_arg_type: &str,
         expecting_close_tag: bool,
-    ) -> Result<Ast> {
+    ) -> Result<Ast<'s>> {
         let mut elements: Vec<AstElement> = vec![];
+        let invalid_opening_tag_count = self.invalid_opening_tags.borrow().len();
 
         while !self.is_eof() {
-            elements.push(match self.char() {
-                '{' => self.parse_argument(nesting_level, expecting_close_tag)?,
+            let start_position = self.position();
+            let element = match self.char() {
+                '{' => self.parse_argument(nesting_level, expecting_close_tag),
                 '}' if nesting_level > 0 => break,
                 '#' if matches!(parent_arg_type, "plural" | "selectordinal") => {
                     let position = self.position();
                     self.bump();
-                    AstElement::Pound(Span::new(position, self.position()))
+                    Ok(AstElement::Pound(Span::new(position, self.position())))
                 }
                 '<' if !self.should_ignore_tag && self.peek() == Some('/') => {
-                    if expecting_close_tag {
+                    if let Some(element) = self.bump_closing_tag_of_invalid_tag() {
+                        Ok(element)
+                    } else if expecting_close_tag {
                         break;
                     } else {
-                        return Err(self.error(
+                        Err(self.error(
                             ErrorKind::UnmatchedClosingTag,
                             Span::new(self.position(), self.position()),
-                        ));
+                        ))
                     }
                 }
                 '<' if !self.should_ignore_tag && matches!(self.peek(), Some('a'..='z')) => {
-                    self.parse_tag(nesting_level, parent_arg_type)?
+                    self.parse_tag(nesting_level, parent_arg_type)
                 }
-                _ => self.parse_literal(nesting_level, parent_arg_type)?,
+                _ => self.parse_literal(nesting_level, parent_arg_type),
+            };
+
+            elements.push(match element {
+                Ok(element) => element,
+                Err(error) if self.recovering => self.recover(error, start_position),
+                Err(error) => return Err(error),
             })
         }
 
+        // Opening tags only match closing tags of the same message.
+        self.invalid_opening_tags.borrow_mut().truncate(invalid_opening_tag_count);
         Ok(elements)
     }
 
+    /// Record the error and skip the malformed element starting at `start_position`, returning
+    /// a placeholder for the skipped source range.
+    ///
+    /// A malformed argument is skipped up to its matching closing brace. A malformed tag is
+    /// skipped up to the `>` following the position where the error occurred, unless that
+    /// would cross an argument boundary. If the opening tag itself is malformed, its closing
+    /// tag is skipped later on too, e.g. `</b!>` in `<b!>x</b!>`.
+    fn recover(&self, error: ast::Error, start_position: Position) -> AstElement<'s> {
+        if error.kind == ErrorKind::InvalidTag && error.span.start == start_position {
+            let name = &self.message[start_position.offset + 1..];
+            let name_len = name.find(|c| !is_potential_element_name_char(c)).unwrap_or(name.len());
+            self.invalid_opening_tags.borrow_mut().push(&name[..name_len]);
+        }
+        self.errors.borrow_mut().push(error);
+
+        if self.char_at(start_position.offset) == '{' {
+            self.position.set(start_position);
+            self.bump_past_argument();
+        } else {
+            self.bump_past_tag();
+        }
+
+        // Always make progress, otherwise the same error would be reported forever.
+        if self.position() == start_position {
+            self.bump();
+        }
+
+        AstElement::Invalid(Span::new(start_position, self.position()))
+    }
+
+    /// Bump the parser past the next `>`, without crossing an argument boundary.
+    fn bump_past_tag(&self) {
+        while !self.is_eof() && !matches!(self.char(), '>' | '{' | '}') {
+            self.bump();
+        }
+        self.bump_if(">");
+    }
+
+    /// If the parser is at the closing tag of the last opening tag that failed to parse, skip
+    /// it and return a placeholder for it.
+    fn bump_closing_tag_of_invalid_tag(&self) -> Option<AstElement<'s>> {
+        let name = *self.invalid_opening_tags.borrow().last()?;
+        let after_name = self.message[self.offset() + 2..].strip_prefix(name)?;
+        if after_name.starts_with(is_potential_element_name_char) {
+            return None;
+        }
+
+        self.invalid_opening_tags.borrow_mut().pop();
+        let start_position = self.position();
+        self.bump_past_tag();
+        Some(AstElement::Invalid(Span::new(start_position, self.position())))
+    }
+
+    /// Bump the parser past the closing brace that matches the opening brace at the current
+    /// position, or to the end of the input if there is none. Braces inside quoted literal
+    /// text are not counted.
+    fn bump_past_argument(&self) {
+        let mut nested_braces = 0;
+        while !self.is_eof() {
+            match self.char() {
+                '\'' if matches!(self.peek(), Some('{') | Some('}')) => {
+                    self.bump(); // apostrophe
+                    self.bump(); // escaped char
+                    self.bump_until('\'');
+                }
+                '{' => nested_braces += 1,
+                '}' => {
+                    nested_braces -= 1;
+                    if nested_braces == 0 {
+                        self.bump();
+                        return;
+                    }
+                }
+                _ => (),
+            }
+            self.bump();
+        }
+    }
+
     fn position(&self) -> Position {
         self.position.get()
     }
@@ -100,7 +317,7 @@ impl<'s> Parser<'s> {
     /// ```
     ///
     /// [custom element name]: https://html.spec.whatwg.org/multipage/custom-elements.html#valid-custom-element-name
-    fn parse_tag(&self, nesting_level: usize, parent_arg_type: &str) -> Result<AstElement> {
+    fn parse_tag(&self, nesting_level: usize, parent_arg_type: &str) -> Result<AstElement<'s>> {
         let start_position = self.position();
         self.bump(); // '<'
 
@@ -110,7 +327,7 @@ impl<'s> Parser<'s> {
         if self.bump_if("/>") {
             // Self closing tag
             Ok(AstElement::Tag {
-                value: tag_name,
+                value: tag_name.into(),
                 span: Span::new(start_position, self.position()),
                 children: Box::new(vec![]),
             })
@@ -121,7 +338,7 @@ impl<'s> Parser<'s> {
             let end_tag_start_position = self.position();
 
             if self.bump_if("</") {
-                if self.is_eof() || !(matches!(self.char(), 'a'..='z')) {
+                if self.is_eof() || !self.char().is_ascii_lowercase() {
                     return Err(self.error(
                         ErrorKind::InvalidTag,
                         Span::new(end_tag_start_position, self.position()),
@@ -144,7 +361,7 @@ impl<'s> Parser<'s> {
                 }
 
                 Ok(AstElement::Tag {
-                    value: tag_name,
+                    value: tag_name.into(),
                     span: Span::new(start_position, self.position()),
                     children: Box::new(children),
                 })
@@ -156,7 +373,7 @@ impl<'s> Parser<'s> {
         }
     }
 
-    fn parse_tag_name(&self) -> &str {
+    fn parse_tag_name(&self) -> &'s str {
         let start_offset = self.offset();
 
         self.bump(); // the first tag name character
@@ -167,7 +384,7 @@ impl<'s> Parser<'s> {
         &self.message[start_offset..self.offset()]
     }
 
-    fn parse_literal(&self, nesting_level: usize, parent_arg_type: &str) -> Result<AstElement> {
+    fn parse_literal(&self, nesting_level: usize, parent_arg_type: &str) -> Result<AstElement<'s>> {
         let start = self.position();
 
         let mut value = String::new();
@@ -268,7 +485,7 @@ impl<'s> Parser<'s> {
         &self,
         nesting_level: usize,
         expecting_close_tag: bool,
-    ) -> Result<AstElement> {
+    ) -> Result<AstElement<'s>> {
         let opening_brace_position = self.position();
         self.bump(); // `{`
 
@@ -314,7 +531,7 @@ impl<'s> Parser<'s> {
 
                 Ok(AstElement::Argument {
                     // value does not include the opening and closing braces.
-                    value,
+                    value: value.into(),
                     span: Span::new(opening_brace_position, self.position()),
                 })
             }
@@ -334,7 +551,7 @@ impl<'s> Parser<'s> {
                 self.parse_argument_options(
                     nesting_level,
                     expecting_close_tag,
-                    value,
+                    value.into(),
                     opening_brace_position,
                 )
             }
@@ -347,12 +564,12 @@ impl<'s> Parser<'s> {
     }
 
     fn parse_argument_options(
-        &'s self,
+        &self,
         nesting_level: usize,
         expecting_close_tag: bool,
-        value: &'s str,
+        value: Cow<'s, str>,
         opening_brace_position: Position,
-    ) -> Result<AstElement> {
+    ) -> Result<AstElement<'s>> {
         // Parse this range:
         // {name, type, style}
         //        ^---^
@@ -398,9 +615,9 @@ impl<'s> Parser<'s> {
 
                 // Extract style or skeleton
                 if let Some((style, style_span)) = style_and_span {
-                    if style.starts_with("::") {
+                    if let Some(skeleton) = style.strip_prefix("::") {
                         // Skeleton starts with `::`.
-                        let skeleton = style[2..].trim_start();
+                        let skeleton = skeleton.trim_start();
 
                         Ok(match arg_type {
                             "number" => {
@@ -418,10 +635,23 @@ impl<'s> Parser<'s> {
                                 if skeleton.is_empty() {
                                     return Err(self.error(ErrorKind::ExpectDateTimeSkeleton, span));
                                 }
+                                // The skeleton is a suffix of the style.
+                                let skeleton_position = self
+                                    .position_after(style_span.start, style.len() - skeleton.len());
+                                let parsed_options =
+                                    parse_date_time_skeleton(skeleton).map_err(|field| {
+                                        let start =
+                                            self.position_after(skeleton_position, field.start);
+                                        let end = self.position_after(start, field.len());
+                                        self.error(
+                                            ErrorKind::InvalidDateTimeSkeleton,
+                                            Span::new(start, end),
+                                        )
+                                    })?;
                                 let style = Some(DateTimeArgStyle::Skeleton(DateTimeSkeleton {
-                                    pattern: skeleton,
+                                    pattern: skeleton.into(),
                                     span: style_span,
-                                    parsed_options: None,
+                                    parsed_options: Some(parsed_options),
                                 }));
                                 if arg_type == "date" {
                                     AstElement::Date { value, span, style }
@@ -436,17 +666,17 @@ impl<'s> Parser<'s> {
                             "number" => AstElement::Number {
                                 value,
                                 span,
-                                style: Some(NumberArgStyle::Style(style)),
+                                style: Some(NumberArgStyle::Style(style.into())),
                             },
                             "date" => AstElement::Date {
                                 value,
                                 span,
-                                style: Some(DateTimeArgStyle::Style(style)),
+                                style: Some(DateTimeArgStyle::Style(style.into())),
                             },
                             _ => AstElement::Time {
                                 value,
                                 span,
-                                style: Some(DateTimeArgStyle::Style(style)),
+                                style: Some(DateTimeArgStyle::Style(style.into())),
                             },
                         })
                     }
@@ -516,6 +746,12 @@ impl<'s> Parser<'s> {
                 self.try_parse_argument_close(opening_brace_position)?;
 
                 let span = Span::new(opening_brace_position, self.position());
+                if self.requires_other_clause
+                    && !options.0.iter().any(|(selector, _)| selector == "other")
+                {
+                    return Err(self.error(ErrorKind::MissingOtherClause, span));
+                }
+
                 match arg_type {
                     "select" => Ok(AstElement::Select { value, span, options }),
                     _ => Ok(AstElement::Plural {
@@ -548,14 +784,12 @@ impl<'s> Parser<'s> {
     ///   between a pair of opening and closing tags. The nested message will not parse beyond
     ///   the closing tag boundary.    ///
     fn try_parse_plural_or_select_options(
-        &'s self,
+        &self,
         nesting_level: usize,
-        parent_arg_type: &'s str,
+        parent_arg_type: &str,
         expecting_close_tag: bool,
         parsed_first_identifier: (&'s str, Span),
-    ) -> Result<PluralOrSelectOptions> {
-        let mut has_other_clause = false;
-
+    ) -> Result<PluralOrSelectOptions<'s>> {
         let mut options = vec![];
         let mut selectors_parsed = HashSet::new();
         let (mut selector, mut selector_span) = parsed_first_identifier;
@@ -574,11 +808,28 @@ impl<'s> Parser<'s> {
                     selector_span = Span::new(start_position, self.position());
                     selector = &self.message[start_position.offset..self.offset()];
                 } else {
-                    // TODO: check to make sure that the plural category is valid.
                     break;
                 }
             }
 
+            // Plural category that the locale does not have
+            if !selector.starts_with('=') {
+                let rules = match parent_arg_type {
+                    "plural" => self.cardinal_rules.as_ref(),
+                    "selectordinal" => self.ordinal_rules.as_ref(),
+                    _ => None,
+                };
+                if let Some(rules) = rules {
+                    let is_valid_category = PluralCategory::from_name(selector)
+                        .is_some_and(|category| rules.categories().contains(&category));
+                    if !is_valid_category {
+                        return Err(
+                            self.error(ErrorKind::InvalidPluralArgumentCategory, selector_span)
+                        );
+                    }
+                }
+            }
+
             // Duplicate selector clauses
             if selectors_parsed.contains(selector) {
                 return Err(self.error(
@@ -591,10 +842,6 @@ impl<'s> Parser<'s> {
                 ));
             }
 
-            if selector == "other" {
-                has_other_clause = true;
-            }
-
             // Parse:
             // one {one apple}
             //     ^----------^
@@ -616,10 +863,11 @@ impl<'s> Parser<'s> {
             self.try_parse_argument_close(opening_brace_position)?;
 
             options.push((
-                selector,
+                selector.into(),
                 PluralOrSelectOption {
                     value: fragment,
                     span: Span::new(opening_brace_position, self.position()),
+                    selector_span,
                 },
             ));
             // Keep track of the existing selectors
@@ -643,15 +891,6 @@ impl<'s> Parser<'s> {
             ));
         }
 
-        // TODO: make this configurable
-        let requires_other_clause = false;
-        if requires_other_clause && !has_other_clause {
-            return Err(self.error(
-                ErrorKind::MissingOtherClause,
-                Span::new(self.position(), self.position()),
-            ));
-        }
-
         Ok(PluralOrSelectOptions(options))
     }
 
@@ -669,7 +908,7 @@ impl<'s> Parser<'s> {
         }
 
         let mut digits = String::new();
-        while !self.is_eof() && self.char().is_digit(10) {
+        while !self.is_eof() && self.char().is_ascii_digit() {
             digits.push(self.char());
             self.bump();
         }
@@ -684,7 +923,7 @@ impl<'s> Parser<'s> {
     }
 
     /// See: https://github.com/unicode-org/icu/blob/af7ed1f6d2298013dc303628438ec4abe1f16479/icu4c/source/common/messagepattern.cpp#L659
-    fn parse_simple_arg_style_if_possible(&self) -> Result<&str> {
+    fn parse_simple_arg_style_if_possible(&self) -> Result<&'s str> {
         let mut nested_braces = 0;
 
         let start_position = self.position();
@@ -746,7 +985,7 @@ impl<'s> Parser<'s> {
 
     /// Advance the parser until the end of the identifier, if it is currently on
     /// an identifier character. Return an empty string otherwise.
-    fn parse_identifier_if_possible(&self) -> (&str, Span) {
+    fn parse_identifier_if_possible(&self) -> (&'s str, Span) {
         let starting_position = self.position();
 
         while !self.is_eof() && !self.char().is_whitespace() && !is_pattern_syntax(self.char()) {
@@ -786,16 +1025,26 @@ impl<'s> Parser<'s> {
         if self.is_eof() {
             return;
         }
-        let Position { mut offset, mut line, mut column } = self.position();
-        let ch = self.char();
-        if ch == '\n' {
-            line = line.checked_add(1).unwrap();
-            column = 1;
+        self.position.set(self.next_position(self.position(), self.char()));
+    }
+
+    /// Return the position following the given character at the given position.
+    ///
+    /// If the location is omitted, only the offset is tracked.
+    fn next_position(&self, position: Position, ch: char) -> Position {
+        if self.should_omit_location {
+            Position { offset: position.offset + ch.len_utf8(), ..position }
         } else {
-            column = column.checked_add(1).unwrap();
+            next_position(position, ch)
         }
-        offset += ch.len_utf8();
-        self.position.set(Position { offset, line, column });
+    }
+
+    /// Return the position that is `len` bytes after the given position, without moving the
+    /// parser.
+    fn position_after(&self, position: Position, len: usize) -> Position {
+        self.message[position.offset..position.offset + len]
+            .chars()
+            .fold(position, |position, ch| self.next_position(position, ch))
     }
 
     /// Bump the parser to the target offset.
@@ -883,7 +1132,7 @@ impl<'s> Parser<'s> {
 fn parse_number_skeleton_from_string(
     skeleton: &str,
     span: Span,
-) -> std::result::Result<NumberSkeleton, ErrorKind> {
+) -> std::result::Result<NumberSkeleton<'_>, ErrorKind> {
     if skeleton.is_empty() {
         return Err(ErrorKind::ExpectNumberSkeleton);
     }
@@ -900,26 +1149,77 @@ fn parse_number_skeleton_from_string(
                         if option.is_empty() {
                             Err(ErrorKind::InvalidNumberSkeleton)
                         } else {
-                            Ok(option)
+                            Ok(option.into())
                         }
                     })
                     .collect();
-                Ok(NumberSkeletonToken { stem, options: options? })
+                Ok(NumberSkeletonToken { stem: stem.into(), options: options? })
             } else {
                 Err(ErrorKind::InvalidNumberSkeleton)
             }
         })
         .collect();
 
+    let tokens = tokens?;
+    let parsed_options = parse_number_skeleton(&tokens)?;
+
     Ok(NumberSkeleton {
-        tokens: tokens?,
+        tokens,
         // TODO: use trimmed end position
         span,
-        // TODO
-        parsed_options: None,
+        parsed_options: Some(parsed_options),
     })
 }
 
+/// Apply `f` to every span in the AST.
+fn for_each_span(ast: &mut [AstElement], f: &dyn Fn(&mut Span)) {
+    for element in ast {
+        match element {
+            AstElement::Literal { span, .. }
+            | AstElement::Argument { span, .. }
+            | AstElement::Pound(span)
+            | AstElement::Invalid(span) => f(span),
+            AstElement::Number { span, style, .. } => {
+                f(span);
+                if let Some(NumberArgStyle::Skeleton(skeleton)) = style {
+                    f(&mut skeleton.span);
+                }
+            }
+            AstElement::Date { span, style, .. } | AstElement::Time { span, style, .. } => {
+                f(span);
+                if let Some(DateTimeArgStyle::Skeleton(skeleton)) = style {
+                    f(&mut skeleton.span);
+                }
+            }
+            AstElement::Select { span, options, .. } | AstElement::Plural { span, options, .. } => {
+                f(span);
+                for (_, option) in &mut options.0 {
+                    f(&mut option.span);
+                    f(&mut option.selector_span);
+                    for_each_span(&mut option.value, f);
+                }
+            }
+            AstElement::Tag { span, children, .. } => {
+                f(span);
+                for_each_span(children, f);
+            }
+        }
+    }
+}
+
+/// Return the position following the given character at the given position.
+fn next_position(position: Position, ch: char) -> Position {
+    let Position { mut offset, mut line, mut column } = position;
+    if ch == '\n' {
+        line = line.checked_add(1).unwrap();
+        column = 1;
+    } else {
+        column = column.checked_add(1).unwrap();
+    }
+    offset += ch.len_utf8();
+    Position { offset, line, column }
+}
+
 fn is_potential_element_name_char(ch: char) -> bool {
     matches!(ch, '-'
         | '.'
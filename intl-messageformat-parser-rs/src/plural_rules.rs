@@ -0,0 +1,385 @@
+use crate::ast::PluralType;
+use data::{CARDINAL_RULES, ORDINAL_RULES};
+use std::fmt;
+use std::iter;
+use std::str::FromStr;
+
+mod data;
+
+/// A CLDR plural category.
+/// See: https://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+
+    pub fn from_name(s: &str) -> Option<PluralCategory> {
+        match s {
+            "zero" => Some(PluralCategory::Zero),
+            "one" => Some(PluralCategory::One),
+            "two" => Some(PluralCategory::Two),
+            "few" => Some(PluralCategory::Few),
+            "many" => Some(PluralCategory::Many),
+            "other" => Some(PluralCategory::Other),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The plural operands of a decimal number.
+/// See: https://unicode.org/reports/tr35/tr35-numbers.html#Operands
+#[derive(Clone, Debug, PartialEq)]
+pub struct PluralOperands {
+    /// Absolute value of the source number.
+    pub n: f64,
+    /// Integer digits of `n`.
+    pub i: u128,
+    /// Number of visible fraction digits in `n`, with trailing zeros.
+    pub v: usize,
+    /// Number of visible fraction digits in `n`, without trailing zeros.
+    pub w: usize,
+    /// Visible fraction digits in `n`, with trailing zeros.
+    pub f: u128,
+    /// Visible fraction digits in `n`, without trailing zeros.
+    pub t: u128,
+    /// Compact decimal exponent value, e.g. `6` for `1.2c6`.
+    pub e: u32,
+}
+
+impl PluralOperands {
+    /// The operands of the shortest decimal representation of the number, so `1.0` has no
+    /// visible fraction digits. Use `from_str` to keep trailing zeros, e.g. `"1.0"`.
+    pub fn from_f64(number: f64) -> PluralOperands {
+        if number.is_finite() {
+            format!("{}", number.abs()).parse().unwrap()
+        } else {
+            PluralOperands { n: number.abs(), i: 0, v: 0, w: 0, f: 0, t: 0, e: 0 }
+        }
+    }
+}
+
+/// The largest compact decimal exponent: the compact decimal formats of CLDR are for numbers
+/// below 10^15, and its plural rule samples use at most `c6`.
+const MAX_EXPONENT: u32 = 15;
+
+/// The string is not a decimal number of the form `-1234.5678c3`, or its compact exponent is
+/// larger than 15.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidPluralOperands;
+
+impl FromStr for PluralOperands {
+    type Err = InvalidPluralOperands;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix('-').unwrap_or(s);
+        let (decimal, e) = match s.find(['c', 'e']) {
+            Some(index) => match s[index + 1..].parse::<u32>() {
+                Ok(e) if e <= MAX_EXPONENT => (&s[..index], e),
+                _ => return Err(InvalidPluralOperands),
+            },
+            None => (s, 0),
+        };
+        let (integer_digits, fraction_digits) = match decimal.find('.') {
+            Some(index) => (&decimal[..index], &decimal[index + 1..]),
+            None => (decimal, ""),
+        };
+        let is_digits = |digits: &str| digits.bytes().all(|b| b.is_ascii_digit());
+        if integer_digits.is_empty() || !is_digits(integer_digits) || !is_digits(fraction_digits) {
+            return Err(InvalidPluralOperands);
+        }
+
+        // Shift the decimal point by the compact exponent.
+        let shift = (e as usize).min(fraction_digits.len());
+        let shifted_digits = fraction_digits[..shift].bytes().map(|b| b - b'0');
+        let i = shifted_digits
+            .chain(iter::repeat_n(0, e as usize - shift))
+            .fold(parse_digits(integer_digits), push_digit);
+        let fraction_digits = &fraction_digits[shift..];
+
+        let trimmed_fraction_digits = fraction_digits.trim_end_matches('0');
+        Ok(PluralOperands {
+            n: format!("{}e{}", decimal, e).parse().map_err(|_| InvalidPluralOperands)?,
+            i,
+            v: fraction_digits.len(),
+            w: trimmed_fraction_digits.len(),
+            f: parse_digits(fraction_digits),
+            t: parse_digits(trimmed_fraction_digits),
+            e,
+        })
+    }
+}
+
+/// Parse the digits, keeping only the last 38 digits of numbers that do not fit in `u128`. The
+/// rules only compare operands to small numbers or use them in a modulo.
+fn parse_digits(digits: &str) -> u128 {
+    digits.bytes().map(|b| b - b'0').fold(0, push_digit)
+}
+
+/// Append the digit to the number, keeping only its last 38 digits like `parse_digits`.
+fn push_digit(number: u128, digit: u8) -> u128 {
+    number % 10u128.pow(37) * 10 + u128::from(digit)
+}
+
+/// Plural rules of a locale, compiled in from the CLDR `plurals.json` and `ordinals.json` data.
+/// See: https://github.com/unicode-org/cldr-json/tree/main/cldr-json/cldr-core/supplemental
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PluralRules {
+    rules: &'static [(PluralCategory, &'static [&'static [Relation]])],
+}
+
+impl PluralRules {
+    /// The plural rules of the locale, or the root locale's rules (only `other`) if the locale
+    /// is not supported.
+    pub fn new(locale: &str, plural_type: PluralType) -> PluralRules {
+        PluralRules::try_new(locale, plural_type).unwrap_or(PluralRules { rules: &[] })
+    }
+
+    /// The plural rules of the locale, or `None` if the locale is not supported.
+    ///
+    /// The locale is matched as a whole first (e.g. `pt-PT`), then by its language subtag,
+    /// e.g. `en-US` uses the rules of `en`.
+    pub fn try_new(locale: &str, plural_type: PluralType) -> Option<PluralRules> {
+        let locale = locale.to_ascii_lowercase().replace('-', "_");
+        let language = locale.split('_').next().unwrap();
+        let data = match plural_type {
+            PluralType::Cardinal => CARDINAL_RULES,
+            PluralType::Ordinal => ORDINAL_RULES,
+        };
+        let find = |locale: &str| {
+            data.iter()
+                .find(|(locales, _)| locales.contains(&locale))
+                .map(|(_, rules)| PluralRules { rules })
+        };
+        find(&locale).or_else(|| find(language))
+    }
+
+    /// The plural category of the number. See `PluralOperands::from_f64`.
+    pub fn select(&self, number: f64) -> PluralCategory {
+        self.select_operands(&PluralOperands::from_f64(number))
+    }
+
+    pub fn select_operands(&self, operands: &PluralOperands) -> PluralCategory {
+        self.rules
+            .iter()
+            .find(|(_, condition)| {
+                condition
+                    .iter()
+                    .any(|relations| relations.iter().all(|relation| relation.matches(operands)))
+            })
+            .map_or(PluralCategory::Other, |(category, _)| *category)
+    }
+
+    /// All plural categories of the locale, including `other`.
+    pub fn categories(&self) -> Vec<PluralCategory> {
+        let mut categories: Vec<_> = self.rules.iter().map(|(category, _)| *category).collect();
+        categories.push(PluralCategory::Other);
+        categories
+    }
+}
+
+/// A plural operand of the rules of CLDR, which use `c` as a synonym of `e` and never use `w`.
+/// See: https://unicode.org/reports/tr35/tr35-numbers.html#Operands
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Operand {
+    N,
+    I,
+    V,
+    F,
+    T,
+    E,
+}
+
+/// A relation of a plural rule condition, e.g. `n % 100 != 11..19`.
+/// See: https://unicode.org/reports/tr35/tr35-numbers.html#Plural_rules_syntax
+#[derive(Debug, Eq, PartialEq)]
+struct Relation {
+    operand: Operand,
+    modulus: Option<u32>,
+    /// `=` if true, `!=` otherwise.
+    is_equal: bool,
+    /// Inclusive ranges, with values as ranges of a single number.
+    ranges: &'static [(u32, u32)],
+}
+
+impl Relation {
+    fn matches(&self, operands: &PluralOperands) -> bool {
+        let integer = match self.operand {
+            Operand::N => None,
+            Operand::I => Some(operands.i),
+            Operand::V => Some(operands.v as u128),
+            Operand::F => Some(operands.f),
+            Operand::T => Some(operands.t),
+            Operand::E => Some(operands.e.into()),
+        };
+        let value = match (integer, self.modulus) {
+            (Some(value), Some(modulus)) => (value % u128::from(modulus)) as f64,
+            (Some(value), None) => value as f64,
+            (None, Some(modulus)) => operands.n % f64::from(modulus),
+            (None, None) => operands.n,
+        };
+
+        // Ranges only contain integers, so `n = 1..2` does not match `1.5`.
+        let is_in_range_list = value.fract() == 0.0
+            && self
+                .ranges
+                .iter()
+                .any(|&(start, end)| f64::from(start) <= value && value <= f64::from(end));
+        is_in_range_list == self.is_equal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn select(locale: &str, plural_type: PluralType, number: &str) -> PluralCategory {
+        PluralRules::new(locale, plural_type).select_operands(&number.parse().unwrap())
+    }
+
+    /// Convenient macro to help parametrize tests with `(locale, type, number, category)`.
+    macro_rules! plural_rules_tests {
+        ($($name:ident: $value:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (locale, plural_type, number, expected) = $value;
+                    assert_eq!(select(locale, plural_type, number), expected);
+                }
+            )*
+        }
+    }
+
+    use PluralCategory::*;
+    use PluralType::{Cardinal, Ordinal};
+
+    plural_rules_tests! {
+        en_one: ("en", Cardinal, "1", One),
+        en_one_with_fraction_digits: ("en-US", Cardinal, "1.0", Other),
+        en_other: ("en", Cardinal, "2", Other),
+        en_ordinal_one: ("en", Ordinal, "21", One),
+        en_ordinal_eleventh: ("en", Ordinal, "11", Other),
+        en_ordinal_two: ("en", Ordinal, "102", Two),
+        en_ordinal_few: ("en", Ordinal, "33", Few),
+        fr_zero: ("fr", Cardinal, "0", One),
+        fr_one_fraction: ("fr", Cardinal, "1.5", One),
+        fr_many_compact: ("fr", Cardinal, "1c6", Many),
+        fr_many: ("fr-CA", Cardinal, "2000000", Many),
+        fr_ordinal: ("fr", Ordinal, "1", One),
+        pt_zero: ("pt", Cardinal, "0", One),
+        pt_pt_zero: ("pt-PT", Cardinal, "0", Other),
+        ru_one: ("ru", Cardinal, "21", One),
+        ru_few: ("ru", Cardinal, "23", Few),
+        ru_many: ("ru", Cardinal, "11", Many),
+        ru_fraction: ("ru", Cardinal, "1.5", Other),
+        pl_many: ("pl", Cardinal, "12", Many),
+        cs_many: ("cs", Cardinal, "1.5", Many),
+        ar_zero: ("ar", Cardinal, "0", Zero),
+        ar_few: ("ar", Cardinal, "103", Few),
+        ar_many: ("ar", Cardinal, "111", Many),
+        cy_many: ("cy", Cardinal, "6", Many),
+        lv_zero: ("lv", Cardinal, "10", Zero),
+        lv_fraction_one: ("lv", Cardinal, "0.1", One),
+        lt_many: ("lt", Cardinal, "0.5", Many),
+        hr_fraction_one: ("hr", Cardinal, "0.1", One),
+        is_fraction: ("is", Cardinal, "0.1", One),
+        da_fraction: ("da", Cardinal, "0.5", One),
+        ja_other: ("ja", Cardinal, "1", Other),
+        unknown_locale: ("xx", Cardinal, "1", Other),
+        it_ordinal_many: ("it", Ordinal, "800", Many),
+        cy_ordinal_zero: ("cy", Ordinal, "7", Zero),
+        range_only_contains_integers: ("tzm", Cardinal, "1.5", Other),
+        shi_few: ("shi", Cardinal, "5", Few),
+        shi_other: ("shi", Cardinal, "11", Other),
+        dsb_fraction_few: ("dsb", Cardinal, "0.3", Few),
+        dsb_two: ("dsb", Cardinal, "102", Two),
+        hsb_one: ("hsb", Cardinal, "101", One),
+        br_many: ("br", Cardinal, "1000000", Many),
+        br_excluded_two: ("br", Cardinal, "72", Other),
+        br_few: ("br", Cardinal, "9", Few),
+        gv_fraction_many: ("gv", Cardinal, "1.5", Many),
+        gv_few: ("gv", Cardinal, "40", Few),
+        gv_one: ("gv", Cardinal, "11", One),
+        kw_zero: ("kw", Cardinal, "0", Zero),
+        kw_two: ("kw", Cardinal, "1000", Two),
+        kw_many: ("kw", Cardinal, "21", Many),
+        kw_ordinal_many: ("kw", Ordinal, "105", Many),
+        az_ordinal_many: ("az", Ordinal, "40", Many),
+        blo_ordinal_zero: ("blo", Ordinal, "0", Zero),
+    }
+
+    #[test]
+    fn select_f64() {
+        let rules = PluralRules::new("en", Cardinal);
+        assert_eq!(rules.select(1.0), One);
+        assert_eq!(rules.select(-1.0), One);
+        assert_eq!(rules.select(1.5), Other);
+        assert_eq!(rules.select(f64::INFINITY), Other);
+    }
+
+    #[test]
+    fn operands() {
+        assert_eq!(
+            "-1.2300".parse(),
+            Ok(PluralOperands { n: 1.23, i: 1, v: 4, w: 2, f: 2300, t: 23, e: 0 })
+        );
+        assert_eq!(
+            "1.23c3".parse(),
+            Ok(PluralOperands { n: 1230.0, i: 1230, v: 0, w: 0, f: 0, t: 0, e: 3 })
+        );
+        assert_eq!(
+            "1.2c15".parse(),
+            Ok(PluralOperands {
+                n: 1.2e15,
+                i: 1_200_000_000_000_000,
+                v: 0,
+                w: 0,
+                f: 0,
+                t: 0,
+                e: 15
+            })
+        );
+        assert_eq!("1.2.3".parse::<PluralOperands>(), Err(InvalidPluralOperands));
+        assert_eq!("1c16".parse::<PluralOperands>(), Err(InvalidPluralOperands));
+        assert_eq!("1c400000000".parse::<PluralOperands>(), Err(InvalidPluralOperands));
+        assert_eq!(
+            PluralOperands::from_f64(0.5),
+            PluralOperands { n: 0.5, i: 0, v: 1, w: 1, f: 5, t: 5, e: 0 }
+        );
+    }
+
+    #[test]
+    fn categories() {
+        assert_eq!(PluralRules::new("en", Cardinal).categories(), vec![One, Other]);
+        assert_eq!(PluralRules::new("en", Ordinal).categories(), vec![One, Two, Few, Other]);
+        assert_eq!(PluralRules::new("ja", Cardinal).categories(), vec![Other]);
+        assert_eq!(
+            PluralRules::new("kw", Cardinal).categories(),
+            vec![Zero, One, Two, Few, Many, Other]
+        );
+        assert_eq!(PluralRules::try_new("xx", Cardinal), None);
+    }
+}
@@ -0,0 +1,1866 @@
+// Generated by scripts/generate-plural-rules.mjs from CLDR 48, do not edit.
+
+use super::Operand::*;
+use super::PluralCategory::{self, *};
+use super::Relation;
+
+/// The rule of a category matches if all the relations of any of its `and` conditions match.
+type Rules = &'static [(PluralCategory, &'static [&'static [Relation]])];
+
+/// `(languages, rules)`, where the rules are checked in order and `other` is the fallback.
+pub(super) static CARDINAL_RULES: &[(&[&str], Rules)] = &[
+    (
+        &[
+            "bm", "bo", "dz", "hnj", "id", "ig", "ii", "in", "ja", "jbo", "jv", "jw", "kde", "kea",
+            "km", "ko", "lkt", "lo", "ms", "my", "nqo", "osa", "root", "sah", "ses", "sg", "su",
+            "th", "to", "tpi", "vi", "wo", "yo", "yue", "zh",
+        ],
+        &[],
+    ),
+    // one: n = 1
+    (
+        &[
+            "af", "an", "asa", "az", "bal", "bem", "bez", "bg", "brx", "ce", "cgg", "chr", "ckb",
+            "dv", "ee", "el", "eo", "eu", "fo", "fur", "gsw", "ha", "haw", "hu", "jgo", "jmc",
+            "ka", "kaj", "kcg", "kk", "kkj", "kl", "ks", "ksb", "ku", "ky", "lb", "lg", "mas",
+            "mgo", "ml", "mn", "mr", "nah", "nb", "nd", "ne", "nn", "nnh", "no", "nr", "ny", "nyn",
+            "om", "or", "os", "pap", "ps", "rm", "rof", "rwk", "saq", "sd", "sdh", "seh", "sn",
+            "so", "sq", "ss", "ssy", "st", "syr", "ta", "te", "teo", "tig", "tk", "tn", "tr", "ts",
+            "ug", "uz", "ve", "vo", "vun", "wae", "xh", "xog",
+        ],
+        &[(One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]])],
+    ),
+    // one: n = 0..1
+    (
+        &["ak", "bho", "csw", "guw", "ln", "mg", "nso", "pa", "ti", "wa"],
+        &[(One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(0, 1)] }]])],
+    ),
+    // one: i = 0 or n = 1
+    (
+        &["am", "as", "bn", "doi", "fa", "gu", "hi", "kn", "kok", "kok_latn", "pcm", "zu"],
+        &[(
+            One,
+            &[
+                &[Relation { operand: I, modulus: None, is_equal: true, ranges: &[(0, 0)] }],
+                &[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }],
+            ],
+        )],
+    ),
+    // one: i = 1 and v = 0
+    (
+        &[
+            "ast", "de", "en", "et", "fi", "fy", "gl", "ia", "ie", "io", "ji", "lij", "nl", "sc",
+            "sv", "sw", "ur", "yi",
+        ],
+        &[(
+            One,
+            &[&[
+                Relation { operand: I, modulus: None, is_equal: true, ranges: &[(1, 1)] },
+                Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+            ]],
+        )],
+    ),
+    // one: v = 0 and i = 1,2,3 or v = 0 and i % 10 != 4,6,9 or v != 0 and f % 10 != 4,6,9
+    (
+        &["ceb", "fil", "tl"],
+        &[(
+            One,
+            &[
+                &[
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    Relation {
+                        operand: I,
+                        modulus: None,
+                        is_equal: true,
+                        ranges: &[(1, 1), (2, 2), (3, 3)],
+                    },
+                ],
+                &[
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    Relation {
+                        operand: I,
+                        modulus: Some(10),
+                        is_equal: false,
+                        ranges: &[(4, 4), (6, 6), (9, 9)],
+                    },
+                ],
+                &[
+                    Relation { operand: V, modulus: None, is_equal: false, ranges: &[(0, 0)] },
+                    Relation {
+                        operand: F,
+                        modulus: Some(10),
+                        is_equal: false,
+                        ranges: &[(4, 4), (6, 6), (9, 9)],
+                    },
+                ],
+            ],
+        )],
+    ),
+    // one: n = 1 or t != 0 and i = 0,1
+    (
+        &["da"],
+        &[(
+            One,
+            &[
+                &[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }],
+                &[
+                    Relation { operand: T, modulus: None, is_equal: false, ranges: &[(0, 0)] },
+                    Relation {
+                        operand: I,
+                        modulus: None,
+                        is_equal: true,
+                        ranges: &[(0, 0), (1, 1)],
+                    },
+                ],
+            ],
+        )],
+    ),
+    // one: i = 0,1
+    (
+        &["ff", "hy", "kab"],
+        &[(
+            One,
+            &[&[Relation { operand: I, modulus: None, is_equal: true, ranges: &[(0, 0), (1, 1)] }]],
+        )],
+    ),
+    // one: t = 0 and i % 10 = 1 and i % 100 != 11 or t % 10 = 1 and t % 100 != 11
+    (
+        &["is"],
+        &[(
+            One,
+            &[
+                &[
+                    Relation { operand: T, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    Relation { operand: I, modulus: Some(10), is_equal: true, ranges: &[(1, 1)] },
+                    Relation {
+                        operand: I,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(11, 11)],
+                    },
+                ],
+                &[
+                    Relation { operand: T, modulus: Some(10), is_equal: true, ranges: &[(1, 1)] },
+                    Relation {
+                        operand: T,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(11, 11)],
+                    },
+                ],
+            ],
+        )],
+    ),
+    // one: v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11
+    (
+        &["mk"],
+        &[(
+            One,
+            &[
+                &[
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    Relation { operand: I, modulus: Some(10), is_equal: true, ranges: &[(1, 1)] },
+                    Relation {
+                        operand: I,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(11, 11)],
+                    },
+                ],
+                &[
+                    Relation { operand: F, modulus: Some(10), is_equal: true, ranges: &[(1, 1)] },
+                    Relation {
+                        operand: F,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(11, 11)],
+                    },
+                ],
+            ],
+        )],
+    ),
+    // one: n = 0,1 or i = 0 and f = 1
+    (
+        &["si"],
+        &[(
+            One,
+            &[
+                &[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(0, 0), (1, 1)],
+                }],
+                &[
+                    Relation { operand: I, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    Relation { operand: F, modulus: None, is_equal: true, ranges: &[(1, 1)] },
+                ],
+            ],
+        )],
+    ),
+    // one: n = 0..1 or n = 11..99
+    (
+        &["tzm"],
+        &[(
+            One,
+            &[
+                &[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(0, 1)] }],
+                &[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(11, 99)] }],
+            ],
+        )],
+    ),
+    // zero: n = 0
+    // one: n = 1
+    (
+        &["blo", "cv", "ksh"],
+        &[
+            (Zero, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(0, 0)] }]]),
+            (One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+        ],
+    ),
+    // one: v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11
+    // few: v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14
+    (
+        &["bs", "hr", "sh", "sr"],
+        &[
+            (
+                One,
+                &[
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(10),
+                            is_equal: true,
+                            ranges: &[(1, 1)],
+                        },
+                        Relation {
+                            operand: I,
+                            modulus: Some(100),
+                            is_equal: false,
+                            ranges: &[(11, 11)],
+                        },
+                    ],
+                    &[
+                        Relation {
+                            operand: F,
+                            modulus: Some(10),
+                            is_equal: true,
+                            ranges: &[(1, 1)],
+                        },
+                        Relation {
+                            operand: F,
+                            modulus: Some(100),
+                            is_equal: false,
+                            ranges: &[(11, 11)],
+                        },
+                    ],
+                ],
+            ),
+            (
+                Few,
+                &[
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(10),
+                            is_equal: true,
+                            ranges: &[(2, 4)],
+                        },
+                        Relation {
+                            operand: I,
+                            modulus: Some(100),
+                            is_equal: false,
+                            ranges: &[(12, 14)],
+                        },
+                    ],
+                    &[
+                        Relation {
+                            operand: F,
+                            modulus: Some(10),
+                            is_equal: true,
+                            ranges: &[(2, 4)],
+                        },
+                        Relation {
+                            operand: F,
+                            modulus: Some(100),
+                            is_equal: false,
+                            ranges: &[(12, 14)],
+                        },
+                    ],
+                ],
+            ),
+        ],
+    ),
+    // one: i = 1 and v = 0
+    // many: e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5
+    (
+        &["ca", "it", "lld", "pt_pt", "scn", "vec"],
+        &[
+            (
+                One,
+                &[&[
+                    Relation { operand: I, modulus: None, is_equal: true, ranges: &[(1, 1)] },
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                ]],
+            ),
+            (
+                Many,
+                &[
+                    &[
+                        Relation { operand: E, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation { operand: I, modulus: None, is_equal: false, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(1000000),
+                            is_equal: true,
+                            ranges: &[(0, 0)],
+                        },
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    ],
+                    &[Relation { operand: E, modulus: None, is_equal: false, ranges: &[(0, 5)] }],
+                ],
+            ),
+        ],
+    ),
+    // one: n = 1
+    // many: e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5
+    (
+        &["es"],
+        &[
+            (One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+            (
+                Many,
+                &[
+                    &[
+                        Relation { operand: E, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation { operand: I, modulus: None, is_equal: false, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(1000000),
+                            is_equal: true,
+                            ranges: &[(0, 0)],
+                        },
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    ],
+                    &[Relation { operand: E, modulus: None, is_equal: false, ranges: &[(0, 5)] }],
+                ],
+            ),
+        ],
+    ),
+    // one: i = 0,1
+    // many: e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5
+    (
+        &["fr"],
+        &[
+            (
+                One,
+                &[&[Relation {
+                    operand: I,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(0, 0), (1, 1)],
+                }]],
+            ),
+            (
+                Many,
+                &[
+                    &[
+                        Relation { operand: E, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation { operand: I, modulus: None, is_equal: false, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(1000000),
+                            is_equal: true,
+                            ranges: &[(0, 0)],
+                        },
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    ],
+                    &[Relation { operand: E, modulus: None, is_equal: false, ranges: &[(0, 5)] }],
+                ],
+            ),
+        ],
+    ),
+    // one: i = 1 and v = 0 or i = 0 and v != 0
+    // two: i = 2 and v = 0
+    (
+        &["he", "iw"],
+        &[
+            (
+                One,
+                &[
+                    &[
+                        Relation { operand: I, modulus: None, is_equal: true, ranges: &[(1, 1)] },
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    ],
+                    &[
+                        Relation { operand: I, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation { operand: V, modulus: None, is_equal: false, ranges: &[(0, 0)] },
+                    ],
+                ],
+            ),
+            (
+                Two,
+                &[&[
+                    Relation { operand: I, modulus: None, is_equal: true, ranges: &[(2, 2)] },
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                ]],
+            ),
+        ],
+    ),
+    // one: n = 1
+    // two: n = 2
+    (
+        &["iu", "naq", "sat", "se", "sma", "smi", "smj", "smn", "sms"],
+        &[
+            (One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+            (Two, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(2, 2)] }]]),
+        ],
+    ),
+    // zero: n = 0
+    // one: i = 0,1 and n != 0
+    (
+        &["lag"],
+        &[
+            (Zero, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(0, 0)] }]]),
+            (
+                One,
+                &[&[
+                    Relation {
+                        operand: I,
+                        modulus: None,
+                        is_equal: true,
+                        ranges: &[(0, 0), (1, 1)],
+                    },
+                    Relation { operand: N, modulus: None, is_equal: false, ranges: &[(0, 0)] },
+                ]],
+            ),
+        ],
+    ),
+    // zero: n % 10 = 0 or n % 100 = 11..19 or v = 2 and f % 100 = 11..19
+    // one: n % 10 = 1 and n % 100 != 11 or v = 2 and f % 10 = 1 and f % 100 != 11 or v != 2 and f %
+    //     10 = 1
+    (
+        &["lv", "prg"],
+        &[
+            (
+                Zero,
+                &[
+                    &[Relation {
+                        operand: N,
+                        modulus: Some(10),
+                        is_equal: true,
+                        ranges: &[(0, 0)],
+                    }],
+                    &[Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(11, 19)],
+                    }],
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(2, 2)] },
+                        Relation {
+                            operand: F,
+                            modulus: Some(100),
+                            is_equal: true,
+                            ranges: &[(11, 19)],
+                        },
+                    ],
+                ],
+            ),
+            (
+                One,
+                &[
+                    &[
+                        Relation {
+                            operand: N,
+                            modulus: Some(10),
+                            is_equal: true,
+                            ranges: &[(1, 1)],
+                        },
+                        Relation {
+                            operand: N,
+                            modulus: Some(100),
+                            is_equal: false,
+                            ranges: &[(11, 11)],
+                        },
+                    ],
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(2, 2)] },
+                        Relation {
+                            operand: F,
+                            modulus: Some(10),
+                            is_equal: true,
+                            ranges: &[(1, 1)],
+                        },
+                        Relation {
+                            operand: F,
+                            modulus: Some(100),
+                            is_equal: false,
+                            ranges: &[(11, 11)],
+                        },
+                    ],
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: false, ranges: &[(2, 2)] },
+                        Relation {
+                            operand: F,
+                            modulus: Some(10),
+                            is_equal: true,
+                            ranges: &[(1, 1)],
+                        },
+                    ],
+                ],
+            ),
+        ],
+    ),
+    // one: i = 1 and v = 0
+    // few: v != 0 or n = 0 or n != 1 and n % 100 = 1..19
+    (
+        &["mo", "ro"],
+        &[
+            (
+                One,
+                &[&[
+                    Relation { operand: I, modulus: None, is_equal: true, ranges: &[(1, 1)] },
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                ]],
+            ),
+            (
+                Few,
+                &[
+                    &[Relation { operand: V, modulus: None, is_equal: false, ranges: &[(0, 0)] }],
+                    &[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(0, 0)] }],
+                    &[
+                        Relation { operand: N, modulus: None, is_equal: false, ranges: &[(1, 1)] },
+                        Relation {
+                            operand: N,
+                            modulus: Some(100),
+                            is_equal: true,
+                            ranges: &[(1, 19)],
+                        },
+                    ],
+                ],
+            ),
+        ],
+    ),
+    // one: i = 0..1
+    // many: e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5
+    (
+        &["pt"],
+        &[
+            (One, &[&[Relation { operand: I, modulus: None, is_equal: true, ranges: &[(0, 1)] }]]),
+            (
+                Many,
+                &[
+                    &[
+                        Relation { operand: E, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation { operand: I, modulus: None, is_equal: false, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(1000000),
+                            is_equal: true,
+                            ranges: &[(0, 0)],
+                        },
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    ],
+                    &[Relation { operand: E, modulus: None, is_equal: false, ranges: &[(0, 5)] }],
+                ],
+            ),
+        ],
+    ),
+    // one: i = 0 or n = 1
+    // few: n = 2..10
+    (
+        &["shi"],
+        &[
+            (
+                One,
+                &[
+                    &[Relation { operand: I, modulus: None, is_equal: true, ranges: &[(0, 0)] }],
+                    &[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }],
+                ],
+            ),
+            (Few, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(2, 10)] }]]),
+        ],
+    ),
+    // one: n % 10 = 1 and n % 100 != 11
+    // few: n % 10 = 2..4 and n % 100 != 12..14
+    // many: n % 10 = 0 or n % 10 = 5..9 or n % 100 = 11..14
+    (
+        &["be"],
+        &[
+            (
+                One,
+                &[&[
+                    Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(1, 1)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(11, 11)],
+                    },
+                ]],
+            ),
+            (
+                Few,
+                &[&[
+                    Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(2, 4)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(12, 14)],
+                    },
+                ]],
+            ),
+            (
+                Many,
+                &[
+                    &[Relation {
+                        operand: N,
+                        modulus: Some(10),
+                        is_equal: true,
+                        ranges: &[(0, 0)],
+                    }],
+                    &[Relation {
+                        operand: N,
+                        modulus: Some(10),
+                        is_equal: true,
+                        ranges: &[(5, 9)],
+                    }],
+                    &[Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(11, 14)],
+                    }],
+                ],
+            ),
+        ],
+    ),
+    // one: i = 1 and v = 0
+    // few: i = 2..4 and v = 0
+    // many: v != 0
+    (
+        &["cs", "sk"],
+        &[
+            (
+                One,
+                &[&[
+                    Relation { operand: I, modulus: None, is_equal: true, ranges: &[(1, 1)] },
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                ]],
+            ),
+            (
+                Few,
+                &[&[
+                    Relation { operand: I, modulus: None, is_equal: true, ranges: &[(2, 4)] },
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                ]],
+            ),
+            (
+                Many,
+                &[&[Relation { operand: V, modulus: None, is_equal: false, ranges: &[(0, 0)] }]],
+            ),
+        ],
+    ),
+    // one: v = 0 and i % 100 = 1 or f % 100 = 1
+    // two: v = 0 and i % 100 = 2 or f % 100 = 2
+    // few: v = 0 and i % 100 = 3..4 or f % 100 = 3..4
+    (
+        &["dsb", "hsb"],
+        &[
+            (
+                One,
+                &[
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(100),
+                            is_equal: true,
+                            ranges: &[(1, 1)],
+                        },
+                    ],
+                    &[Relation {
+                        operand: F,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(1, 1)],
+                    }],
+                ],
+            ),
+            (
+                Two,
+                &[
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(100),
+                            is_equal: true,
+                            ranges: &[(2, 2)],
+                        },
+                    ],
+                    &[Relation {
+                        operand: F,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(2, 2)],
+                    }],
+                ],
+            ),
+            (
+                Few,
+                &[
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(100),
+                            is_equal: true,
+                            ranges: &[(3, 4)],
+                        },
+                    ],
+                    &[Relation {
+                        operand: F,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(3, 4)],
+                    }],
+                ],
+            ),
+        ],
+    ),
+    // one: n = 1,11
+    // two: n = 2,12
+    // few: n = 3..10,13..19
+    (
+        &["gd"],
+        &[
+            (
+                One,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(1, 1), (11, 11)],
+                }]],
+            ),
+            (
+                Two,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(2, 2), (12, 12)],
+                }]],
+            ),
+            (
+                Few,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(3, 10), (13, 19)],
+                }]],
+            ),
+        ],
+    ),
+    // one: n % 10 = 1 and n % 100 != 11..19
+    // few: n % 10 = 2..9 and n % 100 != 11..19
+    // many: f != 0
+    (
+        &["lt"],
+        &[
+            (
+                One,
+                &[&[
+                    Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(1, 1)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(11, 19)],
+                    },
+                ]],
+            ),
+            (
+                Few,
+                &[&[
+                    Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(2, 9)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(11, 19)],
+                    },
+                ]],
+            ),
+            (
+                Many,
+                &[&[Relation { operand: F, modulus: None, is_equal: false, ranges: &[(0, 0)] }]],
+            ),
+        ],
+    ),
+    // one: i = 1 and v = 0
+    // few: v = 0 and i % 10 = 2..4 and i % 100 != 12..14
+    // many: v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 =
+    //     12..14
+    (
+        &["pl"],
+        &[
+            (
+                One,
+                &[&[
+                    Relation { operand: I, modulus: None, is_equal: true, ranges: &[(1, 1)] },
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                ]],
+            ),
+            (
+                Few,
+                &[&[
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    Relation { operand: I, modulus: Some(10), is_equal: true, ranges: &[(2, 4)] },
+                    Relation {
+                        operand: I,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(12, 14)],
+                    },
+                ]],
+            ),
+            (
+                Many,
+                &[
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation { operand: I, modulus: None, is_equal: false, ranges: &[(1, 1)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(10),
+                            is_equal: true,
+                            ranges: &[(0, 1)],
+                        },
+                    ],
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(10),
+                            is_equal: true,
+                            ranges: &[(5, 9)],
+                        },
+                    ],
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(100),
+                            is_equal: true,
+                            ranges: &[(12, 14)],
+                        },
+                    ],
+                ],
+            ),
+        ],
+    ),
+    // one: v = 0 and i % 10 = 1 and i % 100 != 11
+    // few: v = 0 and i % 10 = 2..4 and i % 100 != 12..14
+    // many: v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14
+    (
+        &["ru", "uk"],
+        &[
+            (
+                One,
+                &[&[
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    Relation { operand: I, modulus: Some(10), is_equal: true, ranges: &[(1, 1)] },
+                    Relation {
+                        operand: I,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(11, 11)],
+                    },
+                ]],
+            ),
+            (
+                Few,
+                &[&[
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    Relation { operand: I, modulus: Some(10), is_equal: true, ranges: &[(2, 4)] },
+                    Relation {
+                        operand: I,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(12, 14)],
+                    },
+                ]],
+            ),
+            (
+                Many,
+                &[
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(10),
+                            is_equal: true,
+                            ranges: &[(0, 0)],
+                        },
+                    ],
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(10),
+                            is_equal: true,
+                            ranges: &[(5, 9)],
+                        },
+                    ],
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(100),
+                            is_equal: true,
+                            ranges: &[(11, 14)],
+                        },
+                    ],
+                ],
+            ),
+        ],
+    ),
+    // one: v = 0 and i % 100 = 1
+    // two: v = 0 and i % 100 = 2
+    // few: v = 0 and i % 100 = 3..4 or v != 0
+    (
+        &["sl"],
+        &[
+            (
+                One,
+                &[&[
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    Relation { operand: I, modulus: Some(100), is_equal: true, ranges: &[(1, 1)] },
+                ]],
+            ),
+            (
+                Two,
+                &[&[
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    Relation { operand: I, modulus: Some(100), is_equal: true, ranges: &[(2, 2)] },
+                ]],
+            ),
+            (
+                Few,
+                &[
+                    &[
+                        Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: I,
+                            modulus: Some(100),
+                            is_equal: true,
+                            ranges: &[(3, 4)],
+                        },
+                    ],
+                    &[Relation { operand: V, modulus: None, is_equal: false, ranges: &[(0, 0)] }],
+                ],
+            ),
+        ],
+    ),
+    // one: n % 10 = 1 and n % 100 != 11,71,91
+    // two: n % 10 = 2 and n % 100 != 12,72,92
+    // few: n % 10 = 3..4,9 and n % 100 != 10..19,70..79,90..99
+    // many: n != 0 and n % 1000000 = 0
+    (
+        &["br"],
+        &[
+            (
+                One,
+                &[&[
+                    Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(1, 1)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(11, 11), (71, 71), (91, 91)],
+                    },
+                ]],
+            ),
+            (
+                Two,
+                &[&[
+                    Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(2, 2)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(12, 12), (72, 72), (92, 92)],
+                    },
+                ]],
+            ),
+            (
+                Few,
+                &[&[
+                    Relation {
+                        operand: N,
+                        modulus: Some(10),
+                        is_equal: true,
+                        ranges: &[(3, 4), (9, 9)],
+                    },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(10, 19), (70, 79), (90, 99)],
+                    },
+                ]],
+            ),
+            (
+                Many,
+                &[&[
+                    Relation { operand: N, modulus: None, is_equal: false, ranges: &[(0, 0)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(1000000),
+                        is_equal: true,
+                        ranges: &[(0, 0)],
+                    },
+                ]],
+            ),
+        ],
+    ),
+    // one: n = 1
+    // two: n = 2
+    // few: n = 3..6
+    // many: n = 7..10
+    (
+        &["ga"],
+        &[
+            (One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+            (Two, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(2, 2)] }]]),
+            (Few, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(3, 6)] }]]),
+            (
+                Many,
+                &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(7, 10)] }]],
+            ),
+        ],
+    ),
+    // one: v = 0 and i % 10 = 1
+    // two: v = 0 and i % 10 = 2
+    // few: v = 0 and i % 100 = 0,20,40,60,80
+    // many: v != 0
+    (
+        &["gv"],
+        &[
+            (
+                One,
+                &[&[
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    Relation { operand: I, modulus: Some(10), is_equal: true, ranges: &[(1, 1)] },
+                ]],
+            ),
+            (
+                Two,
+                &[&[
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    Relation { operand: I, modulus: Some(10), is_equal: true, ranges: &[(2, 2)] },
+                ]],
+            ),
+            (
+                Few,
+                &[&[
+                    Relation { operand: V, modulus: None, is_equal: true, ranges: &[(0, 0)] },
+                    Relation {
+                        operand: I,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(0, 0), (20, 20), (40, 40), (60, 60), (80, 80)],
+                    },
+                ]],
+            ),
+            (
+                Many,
+                &[&[Relation { operand: V, modulus: None, is_equal: false, ranges: &[(0, 0)] }]],
+            ),
+        ],
+    ),
+    // one: n = 1
+    // two: n = 2
+    // few: n = 0 or n % 100 = 3..10
+    // many: n % 100 = 11..19
+    (
+        &["mt"],
+        &[
+            (One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+            (Two, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(2, 2)] }]]),
+            (
+                Few,
+                &[
+                    &[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(0, 0)] }],
+                    &[Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(3, 10)],
+                    }],
+                ],
+            ),
+            (
+                Many,
+                &[&[Relation {
+                    operand: N,
+                    modulus: Some(100),
+                    is_equal: true,
+                    ranges: &[(11, 19)],
+                }]],
+            ),
+        ],
+    ),
+    // one: n % 10 = 1 and n % 100 != 11
+    // two: n = 2
+    // few: n != 2 and n % 10 = 2..9 and n % 100 != 11..19
+    // many: f != 0
+    (
+        &["sgs"],
+        &[
+            (
+                One,
+                &[&[
+                    Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(1, 1)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(11, 11)],
+                    },
+                ]],
+            ),
+            (Two, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(2, 2)] }]]),
+            (
+                Few,
+                &[&[
+                    Relation { operand: N, modulus: None, is_equal: false, ranges: &[(2, 2)] },
+                    Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(2, 9)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(11, 19)],
+                    },
+                ]],
+            ),
+            (
+                Many,
+                &[&[Relation { operand: F, modulus: None, is_equal: false, ranges: &[(0, 0)] }]],
+            ),
+        ],
+    ),
+    // zero: n = 0
+    // one: n = 1
+    // two: n = 2
+    // few: n % 100 = 3..10
+    // many: n % 100 = 11..99
+    (
+        &["ar", "ars"],
+        &[
+            (Zero, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(0, 0)] }]]),
+            (One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+            (Two, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(2, 2)] }]]),
+            (
+                Few,
+                &[&[Relation {
+                    operand: N,
+                    modulus: Some(100),
+                    is_equal: true,
+                    ranges: &[(3, 10)],
+                }]],
+            ),
+            (
+                Many,
+                &[&[Relation {
+                    operand: N,
+                    modulus: Some(100),
+                    is_equal: true,
+                    ranges: &[(11, 99)],
+                }]],
+            ),
+        ],
+    ),
+    // zero: n = 0
+    // one: n = 1
+    // two: n = 2
+    // few: n = 3
+    // many: n = 6
+    (
+        &["cy"],
+        &[
+            (Zero, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(0, 0)] }]]),
+            (One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+            (Two, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(2, 2)] }]]),
+            (Few, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(3, 3)] }]]),
+            (Many, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(6, 6)] }]]),
+        ],
+    ),
+    // zero: n = 0
+    // one: n = 1
+    // two: n % 100 = 2,22,42,62,82 or n % 1000 = 0 and n % 100000 = 1000..20000,40000,60000,80000
+    //     or n != 0 and n % 1000000 = 100000
+    // few: n % 100 = 3,23,43,63,83
+    // many: n != 1 and n % 100 = 1,21,41,61,81
+    (
+        &["kw"],
+        &[
+            (Zero, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(0, 0)] }]]),
+            (One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+            (
+                Two,
+                &[
+                    &[Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(2, 2), (22, 22), (42, 42), (62, 62), (82, 82)],
+                    }],
+                    &[
+                        Relation {
+                            operand: N,
+                            modulus: Some(1000),
+                            is_equal: true,
+                            ranges: &[(0, 0)],
+                        },
+                        Relation {
+                            operand: N,
+                            modulus: Some(100000),
+                            is_equal: true,
+                            ranges: &[
+                                (1000, 20000),
+                                (40000, 40000),
+                                (60000, 60000),
+                                (80000, 80000),
+                            ],
+                        },
+                    ],
+                    &[
+                        Relation { operand: N, modulus: None, is_equal: false, ranges: &[(0, 0)] },
+                        Relation {
+                            operand: N,
+                            modulus: Some(1000000),
+                            is_equal: true,
+                            ranges: &[(100000, 100000)],
+                        },
+                    ],
+                ],
+            ),
+            (
+                Few,
+                &[&[Relation {
+                    operand: N,
+                    modulus: Some(100),
+                    is_equal: true,
+                    ranges: &[(3, 3), (23, 23), (43, 43), (63, 63), (83, 83)],
+                }]],
+            ),
+            (
+                Many,
+                &[&[
+                    Relation { operand: N, modulus: None, is_equal: false, ranges: &[(1, 1)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(1, 1), (21, 21), (41, 41), (61, 61), (81, 81)],
+                    },
+                ]],
+            ),
+        ],
+    ),
+];
+
+/// `(languages, rules)`, where the rules are checked in order and `other` is the fallback.
+pub(super) static ORDINAL_RULES: &[(&[&str], Rules)] = &[
+    (
+        &[
+            "af", "am", "an", "ar", "ast", "bg", "bs", "ce", "cs", "cv", "da", "de", "dsb", "el",
+            "es", "et", "eu", "fa", "fi", "fy", "gl", "gsw", "he", "hr", "hsb", "ia", "id", "ie",
+            "in", "is", "iw", "ja", "km", "kn", "ko", "ky", "lt", "lv", "ml", "mn", "my", "nb",
+            "nl", "no", "pa", "pl", "prg", "ps", "pt", "root", "ru", "sd", "sh", "si", "sk", "sl",
+            "sr", "sw", "ta", "te", "th", "tpi", "tr", "ur", "uz", "yue", "zh", "zu",
+        ],
+        &[],
+    ),
+    // one: n = 1
+    (
+        &["bal", "fil", "fr", "ga", "hy", "lo", "mo", "ms", "ro", "tl", "vi"],
+        &[(One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]])],
+    ),
+    // few: n % 10 = 2,3 and n % 100 != 12,13
+    (
+        &["be"],
+        &[(
+            Few,
+            &[&[
+                Relation {
+                    operand: N,
+                    modulus: Some(10),
+                    is_equal: true,
+                    ranges: &[(2, 2), (3, 3)],
+                },
+                Relation {
+                    operand: N,
+                    modulus: Some(100),
+                    is_equal: false,
+                    ranges: &[(12, 12), (13, 13)],
+                },
+            ]],
+        )],
+    ),
+    // one: n = 1,5
+    (
+        &["hu"],
+        &[(
+            One,
+            &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1), (5, 5)] }]],
+        )],
+    ),
+    // many: n = 11,8,80,800
+    (
+        &["it", "lld", "sc", "vec"],
+        &[(
+            Many,
+            &[&[Relation {
+                operand: N,
+                modulus: None,
+                is_equal: true,
+                ranges: &[(11, 11), (8, 8), (80, 80), (800, 800)],
+            }]],
+        )],
+    ),
+    // many: n % 10 = 6 or n % 10 = 9 or n % 10 = 0 and n != 0
+    (
+        &["kk"],
+        &[(
+            Many,
+            &[
+                &[Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(6, 6)] }],
+                &[Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(9, 9)] }],
+                &[
+                    Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(0, 0)] },
+                    Relation { operand: N, modulus: None, is_equal: false, ranges: &[(0, 0)] },
+                ],
+            ],
+        )],
+    ),
+    // many: n = 11,8,80..89,800..899
+    (
+        &["lij", "scn"],
+        &[(
+            Many,
+            &[&[Relation {
+                operand: N,
+                modulus: None,
+                is_equal: true,
+                ranges: &[(11, 11), (8, 8), (80, 89), (800, 899)],
+            }]],
+        )],
+    ),
+    // one: n = 1..4
+    (
+        &["ne"],
+        &[(One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 4)] }]])],
+    ),
+    // one: n % 10 = 1,2 and n % 100 != 11,12
+    (
+        &["sv"],
+        &[(
+            One,
+            &[&[
+                Relation {
+                    operand: N,
+                    modulus: Some(10),
+                    is_equal: true,
+                    ranges: &[(1, 1), (2, 2)],
+                },
+                Relation {
+                    operand: N,
+                    modulus: Some(100),
+                    is_equal: false,
+                    ranges: &[(11, 11), (12, 12)],
+                },
+            ]],
+        )],
+    ),
+    // few: n % 10 = 6,9 or n = 10
+    (
+        &["tk"],
+        &[(
+            Few,
+            &[
+                &[Relation {
+                    operand: N,
+                    modulus: Some(10),
+                    is_equal: true,
+                    ranges: &[(6, 6), (9, 9)],
+                }],
+                &[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(10, 10)] }],
+            ],
+        )],
+    ),
+    // few: n % 10 = 3 and n % 100 != 13
+    (
+        &["uk"],
+        &[(
+            Few,
+            &[&[
+                Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(3, 3)] },
+                Relation { operand: N, modulus: Some(100), is_equal: false, ranges: &[(13, 13)] },
+            ]],
+        )],
+    ),
+    // one: i = 1
+    // many: i = 0 or i % 100 = 2..20,40,60,80
+    (
+        &["ka"],
+        &[
+            (One, &[&[Relation { operand: I, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+            (
+                Many,
+                &[
+                    &[Relation { operand: I, modulus: None, is_equal: true, ranges: &[(0, 0)] }],
+                    &[Relation {
+                        operand: I,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(2, 20), (40, 40), (60, 60), (80, 80)],
+                    }],
+                ],
+            ),
+        ],
+    ),
+    // one: n = 1..4 or n % 100 = 1..4,21..24,41..44,61..64,81..84
+    // many: n = 5 or n % 100 = 5
+    (
+        &["kw"],
+        &[
+            (
+                One,
+                &[
+                    &[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 4)] }],
+                    &[Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(1, 4), (21, 24), (41, 44), (61, 64), (81, 84)],
+                    }],
+                ],
+            ),
+            (
+                Many,
+                &[
+                    &[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(5, 5)] }],
+                    &[Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(5, 5)],
+                    }],
+                ],
+            ),
+        ],
+    ),
+    // one: n = 1
+    // many: n % 10 = 4 and n % 100 != 14
+    (
+        &["sq"],
+        &[
+            (One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+            (
+                Many,
+                &[&[
+                    Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(4, 4)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(14, 14)],
+                    },
+                ]],
+            ),
+        ],
+    ),
+    // one: i % 10 = 1,2,5,7,8 or i % 100 = 20,50,70,80
+    // few: i % 10 = 3,4 or i % 1000 = 100,200,300,400,500,600,700,800,900
+    // many: i = 0 or i % 10 = 6 or i % 100 = 40,60,90
+    (
+        &["az"],
+        &[
+            (
+                One,
+                &[
+                    &[Relation {
+                        operand: I,
+                        modulus: Some(10),
+                        is_equal: true,
+                        ranges: &[(1, 1), (2, 2), (5, 5), (7, 7), (8, 8)],
+                    }],
+                    &[Relation {
+                        operand: I,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(20, 20), (50, 50), (70, 70), (80, 80)],
+                    }],
+                ],
+            ),
+            (
+                Few,
+                &[
+                    &[Relation {
+                        operand: I,
+                        modulus: Some(10),
+                        is_equal: true,
+                        ranges: &[(3, 3), (4, 4)],
+                    }],
+                    &[Relation {
+                        operand: I,
+                        modulus: Some(1000),
+                        is_equal: true,
+                        ranges: &[
+                            (100, 100),
+                            (200, 200),
+                            (300, 300),
+                            (400, 400),
+                            (500, 500),
+                            (600, 600),
+                            (700, 700),
+                            (800, 800),
+                            (900, 900),
+                        ],
+                    }],
+                ],
+            ),
+            (
+                Many,
+                &[
+                    &[Relation { operand: I, modulus: None, is_equal: true, ranges: &[(0, 0)] }],
+                    &[Relation {
+                        operand: I,
+                        modulus: Some(10),
+                        is_equal: true,
+                        ranges: &[(6, 6)],
+                    }],
+                    &[Relation {
+                        operand: I,
+                        modulus: Some(100),
+                        is_equal: true,
+                        ranges: &[(40, 40), (60, 60), (90, 90)],
+                    }],
+                ],
+            ),
+        ],
+    ),
+    // zero: i = 0
+    // one: i = 1
+    // few: i = 2,3,4,5,6
+    (
+        &["blo"],
+        &[
+            (Zero, &[&[Relation { operand: I, modulus: None, is_equal: true, ranges: &[(0, 0)] }]]),
+            (One, &[&[Relation { operand: I, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+            (
+                Few,
+                &[&[Relation {
+                    operand: I,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(2, 2), (3, 3), (4, 4), (5, 5), (6, 6)],
+                }]],
+            ),
+        ],
+    ),
+    // one: n = 1,3
+    // two: n = 2
+    // few: n = 4
+    (
+        &["ca"],
+        &[
+            (
+                One,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(1, 1), (3, 3)],
+                }]],
+            ),
+            (Two, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(2, 2)] }]]),
+            (Few, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(4, 4)] }]]),
+        ],
+    ),
+    // one: n % 10 = 1 and n % 100 != 11
+    // two: n % 10 = 2 and n % 100 != 12
+    // few: n % 10 = 3 and n % 100 != 13
+    (
+        &["en"],
+        &[
+            (
+                One,
+                &[&[
+                    Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(1, 1)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(11, 11)],
+                    },
+                ]],
+            ),
+            (
+                Two,
+                &[&[
+                    Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(2, 2)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(12, 12)],
+                    },
+                ]],
+            ),
+            (
+                Few,
+                &[&[
+                    Relation { operand: N, modulus: Some(10), is_equal: true, ranges: &[(3, 3)] },
+                    Relation {
+                        operand: N,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(13, 13)],
+                    },
+                ]],
+            ),
+        ],
+    ),
+    // one: n = 1,11
+    // two: n = 2,12
+    // few: n = 3,13
+    (
+        &["gd"],
+        &[
+            (
+                One,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(1, 1), (11, 11)],
+                }]],
+            ),
+            (
+                Two,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(2, 2), (12, 12)],
+                }]],
+            ),
+            (
+                Few,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(3, 3), (13, 13)],
+                }]],
+            ),
+        ],
+    ),
+    // one: n = 1
+    // two: n = 2,3
+    // few: n = 4
+    (
+        &["kok", "kok_latn", "mr"],
+        &[
+            (One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+            (
+                Two,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(2, 2), (3, 3)],
+                }]],
+            ),
+            (Few, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(4, 4)] }]]),
+        ],
+    ),
+    // one: i % 10 = 1 and i % 100 != 11
+    // two: i % 10 = 2 and i % 100 != 12
+    // many: i % 10 = 7,8 and i % 100 != 17,18
+    (
+        &["mk"],
+        &[
+            (
+                One,
+                &[&[
+                    Relation { operand: I, modulus: Some(10), is_equal: true, ranges: &[(1, 1)] },
+                    Relation {
+                        operand: I,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(11, 11)],
+                    },
+                ]],
+            ),
+            (
+                Two,
+                &[&[
+                    Relation { operand: I, modulus: Some(10), is_equal: true, ranges: &[(2, 2)] },
+                    Relation {
+                        operand: I,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(12, 12)],
+                    },
+                ]],
+            ),
+            (
+                Many,
+                &[&[
+                    Relation {
+                        operand: I,
+                        modulus: Some(10),
+                        is_equal: true,
+                        ranges: &[(7, 7), (8, 8)],
+                    },
+                    Relation {
+                        operand: I,
+                        modulus: Some(100),
+                        is_equal: false,
+                        ranges: &[(17, 17), (18, 18)],
+                    },
+                ]],
+            ),
+        ],
+    ),
+    // one: n = 1,5,7,8,9,10
+    // two: n = 2,3
+    // few: n = 4
+    // many: n = 6
+    (
+        &["as", "bn"],
+        &[
+            (
+                One,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(1, 1), (5, 5), (7, 7), (8, 8), (9, 9), (10, 10)],
+                }]],
+            ),
+            (
+                Two,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(2, 2), (3, 3)],
+                }]],
+            ),
+            (Few, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(4, 4)] }]]),
+            (Many, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(6, 6)] }]]),
+        ],
+    ),
+    // one: n = 1
+    // two: n = 2,3
+    // few: n = 4
+    // many: n = 6
+    (
+        &["gu", "hi"],
+        &[
+            (One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+            (
+                Two,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(2, 2), (3, 3)],
+                }]],
+            ),
+            (Few, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(4, 4)] }]]),
+            (Many, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(6, 6)] }]]),
+        ],
+    ),
+    // one: n = 1,5,7..9
+    // two: n = 2,3
+    // few: n = 4
+    // many: n = 6
+    (
+        &["or"],
+        &[
+            (
+                One,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(1, 1), (5, 5), (7, 9)],
+                }]],
+            ),
+            (
+                Two,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(2, 2), (3, 3)],
+                }]],
+            ),
+            (Few, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(4, 4)] }]]),
+            (Many, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(6, 6)] }]]),
+        ],
+    ),
+    // zero: n = 0,7,8,9
+    // one: n = 1
+    // two: n = 2
+    // few: n = 3,4
+    // many: n = 5,6
+    (
+        &["cy"],
+        &[
+            (
+                Zero,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(0, 0), (7, 7), (8, 8), (9, 9)],
+                }]],
+            ),
+            (One, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(1, 1)] }]]),
+            (Two, &[&[Relation { operand: N, modulus: None, is_equal: true, ranges: &[(2, 2)] }]]),
+            (
+                Few,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(3, 3), (4, 4)],
+                }]],
+            ),
+            (
+                Many,
+                &[&[Relation {
+                    operand: N,
+                    modulus: None,
+                    is_equal: true,
+                    ranges: &[(5, 5), (6, 6)],
+                }]],
+            ),
+        ],
+    ),
+];
@@ -0,0 +1,264 @@
+use crate::ast::*;
+use crate::printer::print_element;
+
+/// Layout options of `pretty_print_ast`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrettyPrintOptions {
+    /// The line width that `select`, `plural` and `selectordinal` arguments should fit in before
+    /// they are broken into one option per line.
+    pub width: usize,
+    /// The number of spaces per indentation level.
+    pub indent: usize,
+}
+
+impl Default for PrettyPrintOptions {
+    fn default() -> Self {
+        PrettyPrintOptions { width: 80, indent: 2 }
+    }
+}
+
+/// Print the AST as a formatted ICU message.
+///
+/// Literals and simple arguments are printed like `print_ast`. A `select`, `plural` or
+/// `selectordinal` argument that does not fit in the remaining width is broken into one option
+/// per line, indented one level deeper than the argument:
+///
+/// ```text
+/// {count, plural, offset:1
+///   =0 {Nobody}
+///   other {{gender, select,
+///     female {She and # others}
+///     other {They and # others}
+///   }}
+/// }
+/// ```
+///
+/// Only whitespace between the options of an argument is added, which the parser ignores, so
+/// the formatted message is equivalent to the original one.
+pub fn pretty_print_ast(ast: &[AstElement], options: &PrettyPrintOptions) -> String {
+    let mut printer = PrettyPrinter { options, output: String::new(), indent_level: 0 };
+    printer.print_message(ast, false, 0);
+    printer.output
+}
+
+/// Check mode of the formatter: returns true if the message is already formatted, i.e. it is
+/// exactly what `pretty_print_ast` prints for its AST.
+pub fn is_pretty_printed(message: &str, ast: &[AstElement], options: &PrettyPrintOptions) -> bool {
+    pretty_print_ast(ast, options) == message
+}
+
+struct PrettyPrinter<'o> {
+    options: &'o PrettyPrintOptions,
+    output: String,
+    indent_level: usize,
+}
+
+impl<'o> PrettyPrinter<'o> {
+    /// # Arguments
+    ///
+    /// * `trailing_width` - The number of characters that follow the message on its last line,
+    ///   e.g. the closing tag of its parent tag and the text after it.
+    fn print_message(&mut self, ast: &[AstElement], is_in_plural: bool, trailing_width: usize) {
+        for (index, element) in ast.iter().enumerate() {
+            let rest = &ast[index + 1..];
+            match element {
+                AstElement::Select { .. } | AstElement::Plural { .. } => {
+                    let trailing_width = line_width(rest, is_in_plural, trailing_width);
+                    self.print_plural_or_select(element, is_in_plural, trailing_width)
+                }
+                AstElement::Tag { value, children, .. } if !children.is_empty() => {
+                    self.output.push('<');
+                    self.output.push_str(value);
+                    self.output.push('>');
+                    let closing_tag_width = value.chars().count() + 3;
+                    let trailing_width =
+                        closing_tag_width + line_width(rest, is_in_plural, trailing_width);
+                    self.print_message(children, is_in_plural, trailing_width);
+                    self.output.push_str("</");
+                    self.output.push_str(value);
+                    self.output.push('>');
+                }
+                _ => print_element(&mut self.output, element, is_in_plural),
+            }
+        }
+    }
+
+    fn print_plural_or_select(
+        &mut self,
+        element: &AstElement,
+        is_in_plural: bool,
+        trailing_width: usize,
+    ) {
+        let mut flat = String::new();
+        print_element(&mut flat, element, is_in_plural);
+        let width = self.column() + flat.chars().count() + trailing_width;
+        if !flat.contains('\n') && width <= self.options.width {
+            self.output.push_str(&flat);
+            return;
+        }
+
+        let (options, is_in_plural) = match element {
+            AstElement::Select { value, options, .. } => {
+                self.output.push('{');
+                self.output.push_str(value);
+                self.output.push_str(", select,");
+                (options, false)
+            }
+            AstElement::Plural { value, plural_type, offset, options, .. } => {
+                self.output.push('{');
+                self.output.push_str(value);
+                self.output.push_str(match plural_type {
+                    PluralType::Cardinal => ", plural,",
+                    PluralType::Ordinal => ", selectordinal,",
+                });
+                if *offset != 0 {
+                    self.output.push_str(&format!(" offset:{}", offset));
+                }
+                (options, true)
+            }
+            _ => unreachable!(),
+        };
+
+        self.indent_level += 1;
+        for (selector, option) in &options.0 {
+            self.print_newline();
+            self.output.push_str(selector);
+            self.output.push_str(" {");
+            // The option is followed by its closing brace and a line break.
+            self.print_message(&option.value, is_in_plural, 1);
+            self.output.push('}');
+        }
+        self.indent_level -= 1;
+        self.print_newline();
+        self.output.push('}');
+    }
+
+    fn print_newline(&mut self) {
+        self.output.push('\n');
+        let indent = self.indent_level * self.options.indent;
+        self.output.push_str(&" ".repeat(indent));
+    }
+
+    /// The number of characters on the current line.
+    fn column(&self) -> usize {
+        let line_start = self.output.rfind('\n').map_or(0, |i| i + 1);
+        self.output[line_start..].chars().count()
+    }
+}
+
+/// The number of characters of the elements up to their first line break, which includes
+/// `trailing_width` if there is none. A `select` or `plural` argument ends the line, since it
+/// is broken if it does not fit.
+fn line_width(ast: &[AstElement], is_in_plural: bool, trailing_width: usize) -> usize {
+    let mut line = String::new();
+    for element in ast {
+        if matches!(element, AstElement::Select { .. } | AstElement::Plural { .. }) {
+            return line.chars().count();
+        }
+        print_element(&mut line, element, is_in_plural);
+        if let Some(line_end) = line.find('\n') {
+            return line[..line_end].chars().count();
+        }
+    }
+    line.chars().count() + trailing_width
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use crate::printer::print_ast;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    /// Assert that the message is formatted as `expected`, that the formatted message is
+    /// equivalent to the original one and that formatting is idempotent.
+    fn assert_pretty_printed(message: &str, options: &PrettyPrintOptions, expected: &str) {
+        let ast = Parser::new(message, None).parse().unwrap();
+        let formatted = pretty_print_ast(&ast, options);
+        assert_eq!(formatted, expected);
+
+        let reparsed = Parser::new(&formatted, None).parse().unwrap();
+        assert_eq!(print_ast(&reparsed), print_ast(&ast));
+        assert!(is_pretty_printed(&formatted, &reparsed, options));
+    }
+
+    #[test]
+    fn keep_short_arguments_on_one_line() {
+        assert_pretty_printed(
+            "Hi {name}, {n,plural,one{# item}other{# items}}",
+            &PrettyPrintOptions::default(),
+            "Hi {name}, {n, plural, one {# item} other {# items}}",
+        );
+    }
+
+    #[test]
+    fn break_long_arguments() {
+        assert_pretty_printed(
+            "{count, plural, offset:1 =0 {Nobody} other {{gender, select, female {She and # \
+             others} other {They and # others}}}}",
+            &PrettyPrintOptions { width: 40, indent: 2 },
+            indoc! {"
+                {count, plural, offset:1
+                  =0 {Nobody}
+                  other {{gender, select,
+                    female {She and # others}
+                    other {They and # others}
+                  }}
+                }"},
+        );
+    }
+
+    #[test]
+    fn configurable_indent() {
+        assert_pretty_printed(
+            "<b>{g, select, male {He} other {They}}</b> left",
+            &PrettyPrintOptions { width: 10, indent: 4 },
+            indoc! {"
+                <b>{g, select,
+                    male {He}
+                    other {They}
+                }</b> left"},
+        );
+    }
+
+    #[test]
+    fn count_text_after_closing_tag() {
+        let message = "<b>{g, select, male {He} other {They}}</b> left";
+        assert_pretty_printed(message, &PrettyPrintOptions { width: 47, indent: 2 }, message);
+        assert_pretty_printed(
+            message,
+            &PrettyPrintOptions { width: 46, indent: 2 },
+            indoc! {"
+                <b>{g, select,
+                  male {He}
+                  other {They}
+                }</b> left"},
+        );
+    }
+
+    #[test]
+    fn nested_argument_fits_after_break() {
+        assert_pretty_printed(
+            "{n, selectordinal, one {#st {g, select, a {A} other {B}}} other {#th}}",
+            &PrettyPrintOptions { width: 40, indent: 2 },
+            indoc! {"
+                {n, selectordinal,
+                  one {#st {g, select, a {A} other {B}}}
+                  other {#th}
+                }"},
+        );
+    }
+
+    #[test]
+    fn check_mode() {
+        let options = PrettyPrintOptions { width: 20, indent: 2 };
+        let formatted = "{g, select,\n  male {He}\n  other {They}\n}";
+        let ast = Parser::new(formatted, None).parse().unwrap();
+        assert!(is_pretty_printed(formatted, &ast, &options));
+
+        let message = "{g, select, male {He} other {They}}";
+        let ast = Parser::new(message, None).parse().unwrap();
+        assert!(!is_pretty_printed(message, &ast, &options));
+    }
+}
@@ -0,0 +1,289 @@
+use crate::ast::*;
+
+/// Print the AST back into a canonical ICU message. Adapted from:
+/// https://github.com/formatjs/formatjs/blob/main/packages/icu-messageformat-parser/printer.ts
+///
+/// Literal text is escaped so that parsing the printed message yields the same AST, modulo
+/// spans. Arguments are printed as `{name, type, style}`, selector clauses are separated by a
+/// single space, and tags without children are printed as self-closing tags.
+///
+/// `AstElement::Invalid` placeholders have no source text and are omitted.
+pub fn print_ast(ast: &[AstElement]) -> String {
+    let mut output = String::new();
+    print_message(&mut output, ast, false);
+    output
+}
+
+/// * `is_in_plural` - If true, the message is nested inside a `plural` or `selectordinal`
+///   argument, where `#` must be escaped.
+fn print_message(output: &mut String, ast: &[AstElement], is_in_plural: bool) {
+    for element in ast {
+        print_element(output, element, is_in_plural);
+    }
+}
+
+pub(crate) fn print_element(output: &mut String, element: &AstElement, is_in_plural: bool) {
+    match element {
+        AstElement::Literal { value, .. } => print_literal(output, value, is_in_plural),
+        AstElement::Argument { value, .. } => {
+            output.push('{');
+            output.push_str(value);
+            output.push('}');
+        }
+        AstElement::Number { value, style, .. } => {
+            print_simple_argument(output, value, "number", |output| match style {
+                None => false,
+                Some(NumberArgStyle::Style(style)) => {
+                    output.push_str(style);
+                    true
+                }
+                Some(NumberArgStyle::Skeleton(skeleton)) => {
+                    output.push_str("::");
+                    for (i, token) in skeleton.tokens.iter().enumerate() {
+                        if i > 0 {
+                            output.push(' ');
+                        }
+                        output.push_str(&token.stem);
+                        for option in &token.options {
+                            output.push('/');
+                            output.push_str(option);
+                        }
+                    }
+                    true
+                }
+            })
+        }
+        AstElement::Date { value, style, .. } | AstElement::Time { value, style, .. } => {
+            let arg_type = if matches!(element, AstElement::Date { .. }) { "date" } else { "time" };
+            print_simple_argument(output, value, arg_type, |output| match style {
+                None => false,
+                Some(DateTimeArgStyle::Style(style)) => {
+                    output.push_str(style);
+                    true
+                }
+                Some(DateTimeArgStyle::Skeleton(skeleton)) => {
+                    output.push_str("::");
+                    output.push_str(&skeleton.pattern);
+                    true
+                }
+            })
+        }
+        AstElement::Select { value, options, .. } => {
+            output.push('{');
+            output.push_str(value);
+            output.push_str(", select, ");
+            print_options(output, options, false);
+            output.push('}');
+        }
+        AstElement::Plural { value, plural_type, offset, options, .. } => {
+            output.push('{');
+            output.push_str(value);
+            output.push_str(match plural_type {
+                PluralType::Cardinal => ", plural, ",
+                PluralType::Ordinal => ", selectordinal, ",
+            });
+            if *offset != 0 {
+                output.push_str(&format!("offset:{} ", offset));
+            }
+            print_options(output, options, true);
+            output.push('}');
+        }
+        AstElement::Pound(_) => output.push('#'),
+        AstElement::Tag { value, children, .. } => {
+            output.push('<');
+            output.push_str(value);
+            if children.is_empty() {
+                output.push_str("/>");
+            } else {
+                output.push('>');
+                // `#` inside of a tag still belongs to the enclosing plural argument.
+                print_message(output, children, is_in_plural);
+                output.push_str("</");
+                output.push_str(value);
+                output.push('>');
+            }
+        }
+        AstElement::Invalid(_) => (),
+    }
+}
+
+/// Print `{name, type}` or `{name, type, style}` if `print_style` printed anything.
+fn print_simple_argument<F>(output: &mut String, value: &str, arg_type: &str, print_style: F)
+where
+    F: FnOnce(&mut String) -> bool,
+{
+    output.push('{');
+    output.push_str(value);
+    output.push_str(", ");
+    output.push_str(arg_type);
+
+    let mut style = String::new();
+    if print_style(&mut style) {
+        output.push_str(", ");
+        output.push_str(&style);
+    }
+    output.push('}');
+}
+
+fn print_options(output: &mut String, options: &PluralOrSelectOptions, is_in_plural: bool) {
+    for (i, (selector, option)) in options.0.iter().enumerate() {
+        if i > 0 {
+            output.push(' ');
+        }
+        output.push_str(selector);
+        output.push_str(" {");
+        print_message(output, &option.value, is_in_plural);
+        output.push('}');
+    }
+}
+
+/// Escape the literal text according to the quoting rules of the parser:
+///
+/// * An apostrophe is always doubled, so that it never starts a quote.
+/// * `{` and `}` are always quoted, `#` is quoted inside of a plural argument.
+/// * `<` is quoted if it would otherwise start an opening or closing tag.
+///
+/// Consecutive quoted characters share a single quote, since `'{''}'` would be read as `{'}`.
+fn print_literal(output: &mut String, value: &str, is_in_plural: bool) {
+    let mut is_quoted = false;
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            // A doubled apostrophe is an apostrophe both inside and outside of a quote.
+            output.push_str("''");
+            continue;
+        }
+        let needs_quote = match c {
+            '{' | '}' => true,
+            '#' => is_in_plural,
+            '<' => matches!(chars.peek(), Some(next) if next.is_ascii_lowercase() || *next == '/'),
+            _ => false,
+        };
+        if needs_quote != is_quoted {
+            output.push('\'');
+            is_quoted = needs_quote;
+        }
+        output.push(c);
+    }
+    if is_quoted {
+        output.push('\'');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use pretty_assertions::assert_eq;
+    use serde_json::Value;
+
+    /// Serialize the AST to JSON without any location information.
+    fn to_json_without_location(ast: &[AstElement]) -> Value {
+        fn strip(value: &mut Value) {
+            match value {
+                Value::Object(map) => {
+                    map.remove("location");
+                    map.remove("span");
+                    map.values_mut().for_each(strip);
+                }
+                Value::Array(values) => values.iter_mut().for_each(strip),
+                _ => (),
+            }
+        }
+        let mut json = serde_json::to_value(ast).unwrap();
+        strip(&mut json);
+        json
+    }
+
+    /// Assert that the message is printed as `expected` and that the printed message parses into
+    /// the same AST.
+    fn assert_printed(message: &str, expected: &str) {
+        let ast = Parser::new(message, None).parse().unwrap();
+        let printed = print_ast(&ast);
+        assert_eq!(printed, expected);
+
+        let reparsed = Parser::new(&printed, None).parse().unwrap();
+        assert_eq!(to_json_without_location(&reparsed), to_json_without_location(&ast));
+    }
+
+    #[test]
+    fn print_literal_and_arguments() {
+        assert_printed("Hello {name}!", "Hello {name}!");
+        assert_printed("{ n , number }", "{n, number}");
+        assert_printed("{n,number,percent}", "{n, number, percent}");
+        assert_printed(
+            "{d, date, short} {t, time, ::hh:mm a}",
+            "{d, date, short} {t, time, ::hh:mm a}",
+        );
+    }
+
+    #[test]
+    fn print_number_skeleton() {
+        assert_printed(
+            "{n, number, ::  currency/GBP .00##/@@@ unit-width-full-name}",
+            "{n, number, ::currency/GBP .00##/@@@ unit-width-full-name}",
+        );
+    }
+
+    #[test]
+    fn print_select_and_plural() {
+        assert_printed(
+            "{g, select, male {He} female {She} other {They}}",
+            "{g, select, male {He} female {She} other {They}}",
+        );
+        assert_printed(
+            "{n, plural, offset:1 =0 {none} one {# item} other {# items}}",
+            "{n, plural, offset:1 =0 {none} one {# item} other {# items}}",
+        );
+        assert_printed(
+            "{n,selectordinal,one{#st}two{#nd}few{#rd}other{#th}}",
+            "{n, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}",
+        );
+    }
+
+    #[test]
+    fn print_tags() {
+        assert_printed("<b>bold {name}</b> <br/> <i></i>", "<b>bold {name}</b> <br/> <i/>");
+        assert_printed(
+            "{n, plural, other {<b>#</b> items}}",
+            "{n, plural, other {<b>#</b> items}}",
+        );
+    }
+
+    #[test]
+    fn print_escaped_literals() {
+        assert_printed("I don't know", "I don''t know");
+        assert_printed("'{a''b}'", "'{'a''b'}'");
+        assert_printed("a}", "a'}'");
+        assert_printed("'<a>", "'<'a>");
+        assert_printed("a < b <1", "a < b <1");
+        assert_printed("a''{b}", "a''{b}");
+        assert_printed("'{''", "'{'''");
+        assert_printed("'{}<b>' and <", "'{}<'b> and <");
+        assert_printed("'{''}'", "'{''}'");
+    }
+
+    #[test]
+    fn print_escaped_pound_sign() {
+        assert_printed("{n, plural, other {'#' is #}} #", "{n, plural, other {'#' is #}} #");
+        // `#` is not special in a select argument, even if nested in a plural argument.
+        assert_printed(
+            "{n, plural, other {{g, select, other {#}}}}",
+            "{n, plural, other {{g, select, other {#}}}}",
+        );
+    }
+
+    #[test]
+    fn print_nested_quotes() {
+        assert_printed(
+            "{n, plural, one {'{'# '}'} other {'}'{g, select, other {'{}'}}}}",
+            "{n, plural, one {'{'# '}'} other {'}'{g, select, other {'{}'}}}}",
+        );
+    }
+
+    #[test]
+    fn omit_invalid_elements() {
+        let (ast, _) = Parser::new("a {} b", None).parse_with_recovery();
+        assert_eq!(print_ast(&ast), "a  b");
+    }
+}
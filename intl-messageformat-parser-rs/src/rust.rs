@@ -0,0 +1,423 @@
+use crate::arguments::{extract_arguments, ArgumentKind, MessageArgument};
+use crate::ast::{AstElement, DateTimeArgStyle, Error, NumberArgStyle, Span};
+use crate::formatter::number_format_options;
+use crate::parser::{Parser, ParserOptions};
+use crate::visit::Visitor;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fmt::{self, Write};
+
+/// Strict and reserved keywords, which get a trailing `_` as names.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// The helper function of the generated module, which the names of messages and arguments must
+/// not shadow.
+const FORMAT_MESSAGE: &str = "format_message";
+
+/// Options of `generate_rust_module`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RustOptions {
+    /// The locale of the plural rules that the messages are formatted with.
+    pub locale: String,
+    /// The options to parse the messages with.
+    pub parser_options: ParserOptions,
+}
+
+impl Default for RustOptions {
+    fn default() -> Self {
+        RustOptions { locale: "en".to_string(), parser_options: ParserOptions::default() }
+    }
+}
+
+/// An error that prevents generating the function of a message of the catalog.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RustError {
+    /// The message failed to parse.
+    Parse { id: String, error: Error },
+    /// The argument is used as values of different kinds, so it has no sensible type.
+    ConflictingArgument { id: String, argument: MessageArgument<'static> },
+    /// The argument has a style that `format` cannot format, e.g. `date`, so its function
+    /// would always fail.
+    UnsupportedStyle { id: String, name: String, style: &'static str },
+}
+
+impl fmt::Display for RustError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RustError::Parse { id, error } => write!(f, "message `{}`: {}", id, error),
+            RustError::ConflictingArgument { id, argument } => write!(
+                f,
+                "message `{}`: argument `{}` is used as values of different kinds",
+                id, argument.name
+            ),
+            RustError::UnsupportedStyle { id, name, style } => write!(
+                f,
+                "message `{}`: argument `{}` cannot be formatted with the style `{}`",
+                id, name, style
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RustError {}
+
+/// Generate Rust source with a typed function for every message in the catalog of message ids
+/// to ICU messages, which formats it with `format`. The source is meant to be included into a
+/// module of its own with `include!`, by a crate that depends on this one.
+///
+/// Each function is named after the id of its message in snake_case, and takes the values of
+/// the arguments in the order they first appear in the message, e.g. for the message
+/// `app.inbox` = `{count, plural, one {# <b>new</b> message} other {...}}`:
+///
+/// ```ignore
+/// pub fn app_inbox(count: f64, b: impl Fn(String) -> String) -> String
+/// ```
+///
+/// Numbers, including `plural` arguments, are `f64`, tags are functions of their formatted
+/// children, and other arguments, including `select` arguments, are `&str`. Messages with a
+/// `select` or `plural` without an `other` option cannot format every value, so they return
+/// `Result<String, FormatError>` instead. Arguments that `format` cannot format, such as `date`
+/// arguments or `currency` numbers, are reported as `RustError::UnsupportedStyle`. The messages
+/// are sorted by id so that the output is stable.
+///
+/// The AST of the messages is not generated: each function embeds its message as a string,
+/// which is parsed the first time the function is called and kept in a `OnceLock`. That parse
+/// unwraps its result, because the message already parsed with the same options here. It can
+/// only panic if the crate that formats the messages is a version of this one whose parser
+/// rejects the message.
+pub fn generate_rust_module<I, K, V>(
+    catalog: I,
+    options: &RustOptions,
+) -> Result<String, Vec<RustError>>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let mut catalog: Vec<(K, V)> = catalog.into_iter().collect();
+    catalog.sort_by(|(a, _), (b, _)| a.as_ref().cmp(b.as_ref()));
+
+    let mut errors = vec![];
+    let mut messages = vec![];
+    for (id, message) in &catalog {
+        let (id, message) = (id.as_ref(), message.as_ref());
+        match Parser::new(message, Some(&options.parser_options)).parse() {
+            Ok(ast) => {
+                let arguments = extract_arguments(&ast);
+                for argument in &arguments {
+                    if argument.is_conflicting() {
+                        errors.push(RustError::ConflictingArgument {
+                            id: id.to_string(),
+                            argument: argument.clone().into_owned(),
+                        });
+                    }
+                }
+                let mut unsupported_styles = UnsupportedStyles(vec![]);
+                unsupported_styles.visit_ast(&ast);
+                for (name, style) in unsupported_styles.0 {
+                    errors.push(RustError::UnsupportedStyle { id: id.to_string(), name, style });
+                }
+                messages.push((id, message, arguments, is_exhaustive(&ast)));
+            }
+            Err(error) => errors.push(RustError::Parse { id: id.to_string(), error }),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut output = String::new();
+    writeln!(output, "// Generated from the message catalog. Do not edit.").unwrap();
+    writeln!(output).unwrap();
+    writeln!(output, "const LOCALE: &str = {:?};", options.locale).unwrap();
+    writeln!(output).unwrap();
+    write_format_message(&mut output, &options.parser_options);
+
+    let mut function_names: HashSet<String> = HashSet::new();
+    function_names.insert(FORMAT_MESSAGE.to_string());
+    for (id, message, arguments, is_exhaustive) in messages {
+        let function_name = unique_name(identifier(id, "message"), &mut function_names);
+        let mut parameter_names = HashSet::new();
+        parameter_names.insert(FORMAT_MESSAGE.to_string());
+        let parameters: Vec<(String, &MessageArgument)> = arguments
+            .iter()
+            .map(|argument| {
+                let name = identifier(&argument.name, "value");
+                (unique_name(name, &mut parameter_names), argument)
+            })
+            .collect();
+
+        writeln!(output).unwrap();
+        writeln!(output, "/// The message `{}`:", id).unwrap();
+        writeln!(output, "///").unwrap();
+        writeln!(output, "/// ```text").unwrap();
+        for line in message.lines() {
+            writeln!(output, "/// {}", line).unwrap();
+        }
+        writeln!(output, "/// ```").unwrap();
+        let parameter_list: Vec<String> = parameters
+            .iter()
+            .map(|(name, argument)| format!("{}: {}", name, parameter_type(argument.kind)))
+            .collect();
+        let return_type = if is_exhaustive {
+            "String"
+        } else {
+            "Result<String, ::intl_messageformat_parser_rs::FormatError>"
+        };
+        writeln!(
+            output,
+            "pub fn {}({}) -> {} {{",
+            function_name,
+            parameter_list.join(", "),
+            return_type
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "    static AST: ::std::sync::OnceLock<::intl_messageformat_parser_rs::Ast<'static>> ="
+        )
+        .unwrap();
+        writeln!(output, "        ::std::sync::OnceLock::new();").unwrap();
+        if parameters.is_empty() {
+            writeln!(output, "    let values = vec![];").unwrap();
+        } else {
+            writeln!(output, "    let values = vec![").unwrap();
+            for (name, argument) in &parameters {
+                let value = match argument.kind {
+                    ArgumentKind::Tag => {
+                        format!(
+                            "::intl_messageformat_parser_rs::FormatValue::Tag(Box::new({}))",
+                            name
+                        )
+                    }
+                    _ => format!("{}.into()", name),
+                };
+                writeln!(output, "        ({:?}, {}),", argument.name, value).unwrap();
+            }
+            writeln!(output, "    ];").unwrap();
+        }
+        let call = format!("{}(&AST, {:?}, values)", FORMAT_MESSAGE, message);
+        if is_exhaustive {
+            writeln!(output, "    {}.unwrap()", call).unwrap();
+        } else {
+            writeln!(output, "    {}", call).unwrap();
+        }
+        writeln!(output, "}}").unwrap();
+    }
+    Ok(output)
+}
+
+fn write_format_message(output: &mut String, parser_options: &ParserOptions) {
+    let parser_options = if parser_options.should_ignore_tag {
+        "Some(&::intl_messageformat_parser_rs::ParserOptions {
+            should_ignore_tag: true,
+            ..Default::default()
+        })"
+    } else {
+        "None"
+    };
+    writeln!(
+        output,
+        "/// Format the message with the values, parsing it the first time. The message was parsed
+/// when it was generated and the values have the types of its arguments, so this only fails
+/// when no option of a `select` or `plural` matches, and only panics if the parser of
+/// `intl_messageformat_parser_rs` changed since.
+fn {name}(
+    ast: &'static ::std::sync::OnceLock<::intl_messageformat_parser_rs::Ast<'static>>,
+    message: &'static str,
+    values: Vec<(&str, ::intl_messageformat_parser_rs::FormatValue)>,
+) -> Result<String, ::intl_messageformat_parser_rs::FormatError> {{
+    let ast = ast.get_or_init(|| {{
+        ::intl_messageformat_parser_rs::Parser::new(message, {options})
+            .parse()
+            .expect(\"the message should parse as it did when it was generated\")
+    }});
+    ::intl_messageformat_parser_rs::format(ast, LOCALE, &values.into_iter().collect())
+}}",
+        name = FORMAT_MESSAGE,
+        options = parser_options
+    )
+    .unwrap();
+}
+
+fn parameter_type(kind: ArgumentKind) -> &'static str {
+    match kind {
+        ArgumentKind::Number => "f64",
+        ArgumentKind::Tag => "impl Fn(String) -> String",
+        ArgumentKind::String => "&str",
+        // Timestamps in milliseconds like in JS, although `date` and `time` arguments are
+        // rejected until `format` formats them.
+        ArgumentKind::Date => "f64",
+    }
+}
+
+/// The arguments with a style that `format` cannot format, without duplicates.
+struct UnsupportedStyles(Vec<(String, &'static str)>);
+
+impl UnsupportedStyles {
+    fn add(&mut self, name: &str, style: &'static str) {
+        if !self.0.iter().any(|(n, s)| n == name && *s == style) {
+            self.0.push((name.to_string(), style));
+        }
+    }
+}
+
+impl<'s> Visitor<'s> for UnsupportedStyles {
+    fn visit_number(
+        &mut self,
+        value: &Cow<'s, str>,
+        style: &Option<NumberArgStyle<'s>>,
+        _span: &Span,
+    ) {
+        if let Err(style) = number_format_options(style.as_ref()) {
+            self.add(value, style);
+        }
+    }
+
+    fn visit_date(
+        &mut self,
+        value: &Cow<'s, str>,
+        _style: &Option<DateTimeArgStyle<'s>>,
+        _span: &Span,
+    ) {
+        self.add(value, "date");
+    }
+
+    fn visit_time(
+        &mut self,
+        value: &Cow<'s, str>,
+        _style: &Option<DateTimeArgStyle<'s>>,
+        _span: &Span,
+    ) {
+        self.add(value, "time");
+    }
+}
+
+/// Whether every `select` and `plural` of the message has an `other` option.
+fn is_exhaustive(ast: &[AstElement]) -> bool {
+    ast.iter().all(|element| match element {
+        AstElement::Select { options, .. } | AstElement::Plural { options, .. } => {
+            options.0.iter().any(|(selector, _)| selector == "other")
+                && options.0.iter().all(|(_, option)| is_exhaustive(&option.value))
+        }
+        AstElement::Tag { children, .. } => is_exhaustive(children),
+        _ => true,
+    })
+}
+
+/// The snake_case identifier of the name, e.g. `app_inbox` for `app.inbox` and `user_name` for
+/// `userName`. The fallback prefixes names that start with a digit, and replaces names without
+/// ASCII letters or digits.
+fn identifier(name: &str, fallback: &str) -> String {
+    let mut identifier = String::new();
+    let mut previous: Option<char> = None;
+    for ch in name.chars() {
+        if ch.is_ascii_alphanumeric() {
+            let is_word_start = ch.is_ascii_uppercase()
+                && previous.is_some_and(|previous| {
+                    previous.is_ascii_lowercase() || previous.is_ascii_digit()
+                });
+            if is_word_start {
+                identifier.push('_');
+            }
+            identifier.push(ch.to_ascii_lowercase());
+        } else if !identifier.is_empty() && !identifier.ends_with('_') {
+            identifier.push('_');
+        }
+        previous = Some(ch);
+    }
+    while identifier.ends_with('_') {
+        identifier.pop();
+    }
+    if identifier.is_empty() {
+        return fallback.to_string();
+    }
+    if identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        identifier = format!("{}_{}", fallback, identifier);
+    }
+    if KEYWORDS.contains(&identifier.as_str()) || identifier == FORMAT_MESSAGE {
+        identifier.push('_');
+    }
+    identifier
+}
+
+fn unique_name(name: String, names: &mut HashSet<String>) -> String {
+    let mut unique_name = name.clone();
+    let mut suffix = 2;
+    while !names.insert(unique_name.clone()) {
+        unique_name = format!("{}_{}", name, suffix);
+        suffix += 1;
+    }
+    unique_name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn name_identifiers() {
+        let names: Vec<String> = ["app.inbox", "userName", "HTTPError", "2fa", "type", "--", "é"]
+            .iter()
+            .map(|name| identifier(name, "value"))
+            .collect();
+        assert_eq!(
+            names,
+            vec!["app_inbox", "user_name", "httperror", "value_2fa", "type_", "value", "value"]
+        );
+
+        let mut names = HashSet::new();
+        assert_eq!(unique_name("a_b".to_string(), &mut names), "a_b");
+        assert_eq!(unique_name("a_b".to_string(), &mut names), "a_b_2");
+    }
+
+    #[test]
+    fn generate_functions() {
+        let options = RustOptions {
+            locale: "fr".to_string(),
+            parser_options: ParserOptions { should_ignore_tag: true, ..Default::default() },
+        };
+        let source =
+            generate_rust_module(vec![("a.b", "<b>{n, number}</b>"), ("a_b", "{x}")], &options)
+                .unwrap();
+        assert!(source.contains("const LOCALE: &str = \"fr\";"));
+        assert!(source.contains("should_ignore_tag: true,"));
+        assert!(source.contains("pub fn a_b(n: f64) -> String {"));
+        assert!(source.contains("pub fn a_b_2(x: &str) -> String {"));
+    }
+
+    #[test]
+    fn report_all_errors() {
+        let catalog = vec![
+            ("a", "{n, date} {n, plural, other {#}}"),
+            ("b", "{oops"),
+            ("c", "ok"),
+            ("d", "{n, number, ::compact-short} {m, number, currency} {n, number, ::K}"),
+        ];
+        let errors = generate_rust_module(catalog, &RustOptions::default())
+            .unwrap_err()
+            .iter()
+            .map(|error| match error {
+                RustError::Parse { id, error } => format!("{}: {:?}", id, error.kind),
+                error => error.to_string(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                "message `a`: argument `n` is used as values of different kinds",
+                "message `a`: argument `n` cannot be formatted with the style `date`",
+                "b: ExpectArgumentClosingBrace",
+                "message `d`: argument `n` cannot be formatted with the style `compact`",
+                "message `d`: argument `m` cannot be formatted with the style `currency`",
+            ]
+        );
+    }
+}
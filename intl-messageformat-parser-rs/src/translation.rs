@@ -0,0 +1,556 @@
+use crate::arguments::{
+    extract_arguments, ArgumentKind, ArgumentType, ArgumentUse, MessageArgument,
+};
+use crate::ast::*;
+use crate::parser::{Parser, ParserOptions};
+use crate::plural_rules::{PluralCategory, PluralRules};
+use crate::visit::{walk_ast, walk_options, Visitor};
+use std::borrow::Cow;
+use std::error;
+use std::fmt;
+
+/// Options of `check_translation`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TranslationCheckOptions {
+    /// The options to parse both messages with, except for `locale`, which is the locale of the
+    /// translation. Instead of failing to parse the translation, its `plural` and
+    /// `selectordinal` selectors that are not plural categories of the locale are reported as
+    /// `InvalidPluralCategory` issues. The source is not checked against it.
+    pub parser_options: ParserOptions,
+}
+
+/// A difference between a message and its translation that is likely a mistake.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TranslationIssue {
+    pub kind: TranslationIssueKind,
+    /// Where the issue is in the source message, if anywhere.
+    pub source_span: Option<Span>,
+    /// Where the issue is in the translated message, if anywhere.
+    pub translation_span: Option<Span>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TranslationIssueKind {
+    /// The argument of the source is not in the translation.
+    MissingArgument(String),
+    /// The argument of the translation is not in the source.
+    ExtraArgument(String),
+    /// The argument is formatted from a different kind of value, e.g. `{d, date}` in the
+    /// source but `{d, number}` in the translation.
+    ChangedArgumentType { name: String, source: ArgumentType, translation: ArgumentType },
+    /// The tag of the source is not in the translation.
+    MissingTag(String),
+    /// The tag of the translation is not in the source.
+    ExtraTag(String),
+    /// The tag is nested in a different tag, or in none, than in the source.
+    ChangedTagNesting {
+        name: String,
+        source_parent: Option<String>,
+        translation_parent: Option<String>,
+    },
+    /// A selector of the `select` argument in the source is not in the translation.
+    MissingSelectKey { argument: String, key: String },
+    /// The selector of the translated `plural` or `selectordinal` argument is not a plural
+    /// category of the locale of the translation.
+    InvalidPluralCategory { argument: String, category: String },
+}
+
+impl fmt::Display for TranslationIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::TranslationIssueKind::*;
+        match self {
+            MissingArgument(name) => write!(f, "argument `{}` is missing", name),
+            ExtraArgument(name) => write!(f, "argument `{}` is not in the source", name),
+            ChangedArgumentType { name, source, translation } => write!(
+                f,
+                "argument `{}` is a {} but a {} in the source",
+                name,
+                argument_type_name(*translation),
+                argument_type_name(*source)
+            ),
+            MissingTag(name) => write!(f, "tag `<{}>` is missing", name),
+            ExtraTag(name) => write!(f, "tag `<{}>` is not in the source", name),
+            ChangedTagNesting { name, source_parent, translation_parent } => {
+                let parent = |parent: &Option<String>| match parent {
+                    Some(parent) => format!("in `<{}>`", parent),
+                    None => "at the top level".to_string(),
+                };
+                write!(
+                    f,
+                    "tag `<{}>` is {} but {} in the source",
+                    name,
+                    parent(translation_parent),
+                    parent(source_parent)
+                )
+            }
+            MissingSelectKey { argument, key } => {
+                write!(f, "`{}` clause of argument `{}` is missing", key, argument)
+            }
+            InvalidPluralCategory { argument, category } => write!(
+                f,
+                "`{}` of argument `{}` is not a plural category of the locale",
+                category, argument
+            ),
+        }
+    }
+}
+
+fn argument_type_name(arg_type: ArgumentType) -> &'static str {
+    match arg_type {
+        ArgumentType::Plain => "plain argument",
+        ArgumentType::Number => "`number`",
+        ArgumentType::Date => "`date`",
+        ArgumentType::Time => "`time`",
+        ArgumentType::Select => "`select`",
+        ArgumentType::Plural => "`plural`",
+        ArgumentType::SelectOrdinal => "`selectordinal`",
+        ArgumentType::Tag => "tag",
+    }
+}
+
+/// The source or the translation failed to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TranslationCheckError {
+    Source(Error),
+    Translation(Error),
+}
+
+impl fmt::Display for TranslationCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TranslationCheckError::Source(error) => write!(f, "source: {}", error),
+            TranslationCheckError::Translation(error) => write!(f, "translation: {}", error),
+        }
+    }
+}
+
+impl error::Error for TranslationCheckError {}
+
+/// Compare the translation of a message against the source message.
+///
+/// The issues are reported in the order of the arguments and tags of the source, followed by
+/// those only in the translation.
+pub fn check_translation(
+    source: &str,
+    translation: &str,
+    options: &TranslationCheckOptions,
+) -> Result<Vec<TranslationIssue>, TranslationCheckError> {
+    let locale = options.parser_options.locale.as_deref();
+    let parser_options = &ParserOptions { locale: None, ..options.parser_options.clone() };
+    let source_ast =
+        Parser::new(source, Some(parser_options)).parse().map_err(TranslationCheckError::Source)?;
+    let translation_ast = Parser::new(translation, Some(parser_options))
+        .parse()
+        .map_err(TranslationCheckError::Translation)?;
+    Ok(check_translation_ast(&source_ast, &translation_ast, locale))
+}
+
+/// Like `check_translation`, for messages that are already parsed, with `locale` as the locale
+/// of the translation.
+pub fn check_translation_ast(
+    source: &[AstElement],
+    translation: &[AstElement],
+    locale: Option<&str>,
+) -> Vec<TranslationIssue> {
+    let source_arguments = extract_arguments(source);
+    let translation_arguments = extract_arguments(translation);
+    let source_tags = collect_tags(source);
+    let translation_tags = collect_tags(translation);
+    let mut issues = vec![];
+
+    for source_argument in &source_arguments {
+        let name = &source_argument.name;
+        let translation_argument = translation_arguments.iter().find(|a| &a.name == name);
+        let is_tag = source_argument.kind == ArgumentKind::Tag;
+        let translation_argument = match translation_argument {
+            Some(argument) => argument,
+            None => {
+                issues.push(TranslationIssue {
+                    kind: if is_tag {
+                        TranslationIssueKind::MissingTag(name.to_string())
+                    } else {
+                        TranslationIssueKind::MissingArgument(name.to_string())
+                    },
+                    source_span: Some(source_argument.uses[0].span),
+                    translation_span: None,
+                });
+                continue;
+            }
+        };
+
+        let changed_uses = match (typed_use(source_argument), typed_use(translation_argument)) {
+            (Some(source_use), Some(translation_use))
+                if source_use.arg_type.kind() != translation_use.arg_type.kind() =>
+            {
+                Some((source_use, translation_use))
+            }
+            // A `select` or `plural` flattened to a plain `{arg}` loses its clauses.
+            (Some(source_use), None)
+                if matches!(
+                    source_use.arg_type,
+                    ArgumentType::Select | ArgumentType::Plural | ArgumentType::SelectOrdinal
+                ) =>
+            {
+                Some((source_use, &translation_argument.uses[0]))
+            }
+            _ => None,
+        };
+        if let Some((source_use, translation_use)) = changed_uses {
+            issues.push(TranslationIssue {
+                kind: TranslationIssueKind::ChangedArgumentType {
+                    name: name.to_string(),
+                    source: source_use.arg_type,
+                    translation: translation_use.arg_type,
+                },
+                source_span: Some(source_use.span),
+                translation_span: Some(translation_use.span),
+            });
+            continue;
+        }
+
+        if is_tag {
+            let source_tag = source_tags.iter().find(|tag| &tag.name == name);
+            let translation_tag = translation_tags.iter().find(|tag| &tag.name == name);
+            if let (Some(source_tag), Some(translation_tag)) = (source_tag, translation_tag) {
+                if source_tag.parent != translation_tag.parent {
+                    issues.push(TranslationIssue {
+                        kind: TranslationIssueKind::ChangedTagNesting {
+                            name: name.to_string(),
+                            source_parent: source_tag.parent.as_deref().map(String::from),
+                            translation_parent: translation_tag.parent.as_deref().map(String::from),
+                        },
+                        source_span: Some(source_tag.span),
+                        translation_span: Some(translation_tag.span),
+                    });
+                }
+            }
+        }
+
+        let select_use = |argument: &MessageArgument| {
+            argument.uses.iter().find(|u| u.arg_type == ArgumentType::Select).map(|u| u.span)
+        };
+        if let (Some(source_span), Some(translation_span)) =
+            (select_use(source_argument), select_use(translation_argument))
+        {
+            for key in &source_argument.select_keys {
+                if !translation_argument.select_keys.contains(key) {
+                    issues.push(TranslationIssue {
+                        kind: TranslationIssueKind::MissingSelectKey {
+                            argument: name.to_string(),
+                            key: key.to_string(),
+                        },
+                        source_span: Some(source_span),
+                        translation_span: Some(translation_span),
+                    });
+                }
+            }
+        }
+    }
+
+    for translation_argument in &translation_arguments {
+        let name = &translation_argument.name;
+        if !source_arguments.iter().any(|a| &a.name == name) {
+            issues.push(TranslationIssue {
+                kind: if translation_argument.kind == ArgumentKind::Tag {
+                    TranslationIssueKind::ExtraTag(name.to_string())
+                } else {
+                    TranslationIssueKind::ExtraArgument(name.to_string())
+                },
+                source_span: None,
+                translation_span: Some(translation_argument.uses[0].span),
+            });
+        }
+    }
+
+    if let Some(locale) = locale {
+        let mut checker = PluralCategoryChecker {
+            source_arguments: &source_arguments,
+            cardinal_rules: PluralRules::try_new(locale, PluralType::Cardinal),
+            ordinal_rules: PluralRules::try_new(locale, PluralType::Ordinal),
+            issues: &mut issues,
+        };
+        checker.visit_ast(translation);
+    }
+
+    issues
+}
+
+/// The first use of the argument that is not a plain `{arg}`.
+fn typed_use<'a>(argument: &'a MessageArgument) -> Option<&'a ArgumentUse> {
+    argument.uses.iter().find(|argument_use| argument_use.arg_type != ArgumentType::Plain)
+}
+
+struct TagInfo<'s> {
+    name: Cow<'s, str>,
+    /// The name of the innermost enclosing tag.
+    parent: Option<Cow<'s, str>>,
+    span: Span,
+}
+
+/// Collect the tags of the message with their enclosing tags, in order.
+fn collect_tags<'s>(ast: &[AstElement<'s>]) -> Vec<TagInfo<'s>> {
+    struct TagCollector<'s> {
+        tags: Vec<TagInfo<'s>>,
+        open_tags: Vec<Cow<'s, str>>,
+    }
+
+    impl<'s> Visitor<'s> for TagCollector<'s> {
+        fn visit_tag(&mut self, value: &Cow<'s, str>, children: &[AstElement<'s>], span: &Span) {
+            self.tags.push(TagInfo {
+                name: value.clone(),
+                parent: self.open_tags.last().cloned(),
+                span: *span,
+            });
+            self.open_tags.push(value.clone());
+            walk_ast(self, children);
+            self.open_tags.pop();
+        }
+    }
+
+    let mut collector = TagCollector { tags: vec![], open_tags: vec![] };
+    collector.visit_ast(ast);
+    collector.tags
+}
+
+struct PluralCategoryChecker<'a, 's> {
+    source_arguments: &'a [MessageArgument<'s>],
+    cardinal_rules: Option<PluralRules>,
+    ordinal_rules: Option<PluralRules>,
+    issues: &'a mut Vec<TranslationIssue>,
+}
+
+impl<'a, 's> Visitor<'s> for PluralCategoryChecker<'a, '_> {
+    fn visit_plural(
+        &mut self,
+        value: &Cow<'s, str>,
+        plural_type: &PluralType,
+        _offset: i64,
+        options: &PluralOrSelectOptions<'s>,
+        _span: &Span,
+    ) {
+        let rules = match plural_type {
+            PluralType::Cardinal => &self.cardinal_rules,
+            PluralType::Ordinal => &self.ordinal_rules,
+        };
+        if let Some(rules) = rules {
+            let categories = rules.categories();
+            let source_span = self
+                .source_arguments
+                .iter()
+                .find(|argument| argument.name == *value)
+                .and_then(typed_use)
+                .map(|argument_use| argument_use.span);
+            for (selector, option) in &options.0 {
+                let is_invalid = PluralCategory::from_name(selector)
+                    .is_some_and(|category| !categories.contains(&category));
+                if is_invalid {
+                    self.issues.push(TranslationIssue {
+                        kind: TranslationIssueKind::InvalidPluralCategory {
+                            argument: value.to_string(),
+                            category: selector.to_string(),
+                        },
+                        source_span,
+                        translation_span: Some(option.selector_span),
+                    });
+                }
+            }
+        }
+        walk_options(self, options);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn span(start: usize, end: usize) -> Option<Span> {
+        Some(Span::new(Position::new(start, 1, start + 1), Position::new(end, 1, end + 1)))
+    }
+
+    fn check(source: &str, translation: &str, locale: Option<&str>) -> Vec<TranslationIssue> {
+        let parser_options =
+            ParserOptions { locale: locale.map(|locale| locale.to_string()), ..Default::default() };
+        let options = TranslationCheckOptions { parser_options };
+        check_translation(source, translation, &options).unwrap()
+    }
+
+    #[test]
+    fn consistent() {
+        assert_eq!(
+            check(
+                "{count, plural, one {# <b>file</b>} other {# <b>files</b>}} by {name}",
+                "{name} : {count, plural, one {# <b>fichier</b>} many {#} other {# <b>fichiers</b>}}",
+                Some("fr")
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn missing_and_extra_arguments() {
+        assert_eq!(
+            check("Hi {name}, <b>{n, number}</b>", "Salut {nom}, {n, number}", None),
+            vec![
+                TranslationIssue {
+                    kind: TranslationIssueKind::MissingArgument("name".to_string()),
+                    source_span: span(3, 9),
+                    translation_span: None,
+                },
+                TranslationIssue {
+                    kind: TranslationIssueKind::MissingTag("b".to_string()),
+                    source_span: span(11, 29),
+                    translation_span: None,
+                },
+                TranslationIssue {
+                    kind: TranslationIssueKind::ExtraArgument("nom".to_string()),
+                    source_span: None,
+                    translation_span: span(6, 11),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn changed_argument_type() {
+        assert_eq!(
+            check("{d} on {d, date}", "{d, number}", None),
+            vec![TranslationIssue {
+                kind: TranslationIssueKind::ChangedArgumentType {
+                    name: "d".to_string(),
+                    source: ArgumentType::Date,
+                    translation: ArgumentType::Number,
+                },
+                source_span: span(7, 16),
+                translation_span: span(0, 11),
+            }]
+        );
+        assert_eq!(
+            check("{d} on {d, date}", "{d, number}", None)[0].kind.to_string(),
+            "argument `d` is a `number` but a `date` in the source"
+        );
+    }
+
+    #[test]
+    fn flattened_select_and_plural() {
+        assert_eq!(
+            check(
+                "{g, select, male {He} other {They}} and {n, plural, other {# cats}}",
+                "{g} {n}",
+                None
+            ),
+            vec![
+                TranslationIssue {
+                    kind: TranslationIssueKind::ChangedArgumentType {
+                        name: "g".to_string(),
+                        source: ArgumentType::Select,
+                        translation: ArgumentType::Plain,
+                    },
+                    source_span: span(0, 35),
+                    translation_span: span(0, 3),
+                },
+                TranslationIssue {
+                    kind: TranslationIssueKind::ChangedArgumentType {
+                        name: "n".to_string(),
+                        source: ArgumentType::Plural,
+                        translation: ArgumentType::Plain,
+                    },
+                    source_span: span(40, 67),
+                    translation_span: span(4, 7),
+                },
+            ]
+        );
+        assert_eq!(
+            check("{g, select, other {They}}", "{g}", None)[0].kind.to_string(),
+            "argument `g` is a plain argument but a `select` in the source"
+        );
+        // A plain use of a formatted argument is fine, since it accepts any value.
+        assert_eq!(check("{d, date}", "{d}", None), vec![]);
+    }
+
+    #[test]
+    fn changed_tag_nesting() {
+        assert_eq!(
+            check("<a><b>x</b></a>", "<b><a>x</a></b>", None),
+            vec![
+                TranslationIssue {
+                    kind: TranslationIssueKind::ChangedTagNesting {
+                        name: "a".to_string(),
+                        source_parent: None,
+                        translation_parent: Some("b".to_string()),
+                    },
+                    source_span: span(0, 15),
+                    translation_span: span(3, 11),
+                },
+                TranslationIssue {
+                    kind: TranslationIssueKind::ChangedTagNesting {
+                        name: "b".to_string(),
+                        source_parent: Some("a".to_string()),
+                        translation_parent: None,
+                    },
+                    source_span: span(3, 11),
+                    translation_span: span(0, 15),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_select_key() {
+        assert_eq!(
+            check(
+                "{g, select, male {He} female {She} other {They}}",
+                "{g, select, male {Il} other {Iel}}",
+                None
+            ),
+            vec![TranslationIssue {
+                kind: TranslationIssueKind::MissingSelectKey {
+                    argument: "g".to_string(),
+                    key: "female".to_string(),
+                },
+                source_span: span(0, 48),
+                translation_span: span(0, 34),
+            }]
+        );
+    }
+
+    #[test]
+    fn invalid_plural_category() {
+        let issues = check(
+            "{n, plural, one {#} other {#}}",
+            "{n, plural, one {#} few {#} other {#}}",
+            Some("ja"),
+        );
+        assert_eq!(
+            issues,
+            vec![
+                TranslationIssue {
+                    kind: TranslationIssueKind::InvalidPluralCategory {
+                        argument: "n".to_string(),
+                        category: "one".to_string(),
+                    },
+                    source_span: span(0, 30),
+                    translation_span: span(12, 15),
+                },
+                TranslationIssue {
+                    kind: TranslationIssueKind::InvalidPluralCategory {
+                        argument: "n".to_string(),
+                        category: "few".to_string(),
+                    },
+                    source_span: span(0, 30),
+                    translation_span: span(20, 23),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_error() {
+        assert_eq!(
+            check_translation("{a}", "{a", &TranslationCheckOptions::default())
+                .unwrap_err()
+                .to_string(),
+            "translation: expected a closing brace `}` for the argument at line 1, column 1"
+        );
+    }
+}
@@ -0,0 +1,333 @@
+use crate::arguments::{extract_arguments, ArgumentKind, MessageArgument};
+use crate::ast::Error;
+use crate::parser::{Parser, ParserOptions};
+use std::collections::HashSet;
+use std::fmt::{self, Write};
+
+/// Options of `generate_type_declarations`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TypeScriptOptions {
+    /// Written at the top of the file, e.g. to import the types used by `tag_type`.
+    pub header: String,
+    /// The type of the values of tags, which format the children of the tag.
+    pub tag_type: String,
+    /// The options to parse the messages with.
+    pub parser_options: ParserOptions,
+}
+
+impl Default for TypeScriptOptions {
+    fn default() -> Self {
+        TypeScriptOptions {
+            header: "import type { ReactNode } from 'react';\n".to_string(),
+            tag_type: "(chunks: ReactNode[]) => ReactNode".to_string(),
+            parser_options: ParserOptions::default(),
+        }
+    }
+}
+
+/// An error that prevents typing a message of the catalog.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TypeScriptError {
+    /// The message failed to parse.
+    Parse { id: String, error: Error },
+    /// The argument is used as values of different kinds, so it has no sensible type.
+    ConflictingArgument { id: String, argument: MessageArgument<'static> },
+}
+
+impl fmt::Display for TypeScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TypeScriptError::Parse { id, error } => write!(f, "message `{}`: {}", id, error),
+            TypeScriptError::ConflictingArgument { id, argument } => write!(
+                f,
+                "message `{}`: argument `{}` is used as values of different kinds",
+                id, argument.name
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TypeScriptError {}
+
+/// Generate a TypeScript declaration file with the types of the values of every message in
+/// the catalog of message ids to ICU messages.
+///
+/// Each message gets an interface of its values, named after its id, e.g. for the message
+/// `app.inbox` = `{count, plural, one {# <b>new</b> message} other {...}}`:
+///
+/// ```ts
+/// export interface AppInboxValues {
+///   count: number;
+///   b: (chunks: ReactNode[]) => ReactNode;
+/// }
+/// ```
+///
+/// and the `MessageValues` interface maps the ids to these interfaces. `select` arguments are
+/// typed as the union of their selectors, which is widened to any string by an `other` clause.
+/// The messages are sorted by id so that the output is stable.
+pub fn generate_type_declarations<I, K, V>(
+    catalog: I,
+    options: &TypeScriptOptions,
+) -> Result<String, Vec<TypeScriptError>>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let mut catalog: Vec<(K, V)> = catalog.into_iter().collect();
+    catalog.sort_by(|(a, _), (b, _)| a.as_ref().cmp(b.as_ref()));
+
+    let mut errors = vec![];
+    let mut messages = vec![];
+    for (id, message) in &catalog {
+        let id = id.as_ref();
+        match Parser::new(message.as_ref(), Some(&options.parser_options)).parse() {
+            Ok(ast) => {
+                let arguments = extract_arguments(&ast);
+                for argument in &arguments {
+                    if argument.is_conflicting() {
+                        errors.push(TypeScriptError::ConflictingArgument {
+                            id: id.to_string(),
+                            argument: argument.clone().into_owned(),
+                        });
+                    }
+                }
+                messages.push((id, arguments));
+            }
+            Err(error) => errors.push(TypeScriptError::Parse { id: id.to_string(), error }),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut output = String::new();
+    writeln!(output, "// Generated from the message catalog. Do not edit.").unwrap();
+    output.push_str(&options.header);
+
+    let mut interface_names = HashSet::new();
+    let mut index = vec![];
+    for (id, arguments) in messages {
+        let interface_name = unique_interface_name(id, &mut interface_names);
+        writeln!(output).unwrap();
+        if arguments.is_empty() {
+            writeln!(output, "export interface {} {{}}", interface_name).unwrap();
+        } else {
+            writeln!(output, "export interface {} {{", interface_name).unwrap();
+            for argument in &arguments {
+                writeln!(
+                    output,
+                    "  {}: {};",
+                    property_name(&argument.name),
+                    argument_type(argument, options)
+                )
+                .unwrap();
+            }
+            writeln!(output, "}}").unwrap();
+        }
+        index.push((id, interface_name));
+    }
+
+    writeln!(output).unwrap();
+    writeln!(output, "export interface MessageValues {{").unwrap();
+    for (id, interface_name) in index {
+        writeln!(output, "  {}: {};", string_literal(id), interface_name).unwrap();
+    }
+    writeln!(output, "}}").unwrap();
+    Ok(output)
+}
+
+fn argument_type(argument: &MessageArgument, options: &TypeScriptOptions) -> String {
+    match argument.kind {
+        ArgumentKind::String if argument.select_keys.is_empty() => "string".to_string(),
+        ArgumentKind::String => {
+            let mut types: Vec<String> = argument
+                .select_keys
+                .iter()
+                .filter(|key| *key != "other")
+                .map(|key| string_literal(key))
+                .collect();
+            if argument.select_keys.iter().any(|key| key == "other") {
+                if types.is_empty() {
+                    return "string".to_string();
+                }
+                // Accepts any string but keeps the selectors for autocompletion.
+                types.push("(string & {})".to_string());
+            }
+            types.join(" | ")
+        }
+        ArgumentKind::Number => "number".to_string(),
+        ArgumentKind::Date => "Date | number".to_string(),
+        ArgumentKind::Tag => options.tag_type.clone(),
+    }
+}
+
+/// The PascalCase interface name of the message id, e.g. `AppInboxValues` for `app.inbox`.
+fn unique_interface_name(id: &str, names: &mut HashSet<String>) -> String {
+    let mut name: String = id
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|first| first.to_ascii_uppercase()).into_iter().chain(chars)
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    name.push_str("Values");
+
+    let mut unique_name = name.clone();
+    let mut suffix = 2;
+    while !names.insert(unique_name.clone()) {
+        unique_name = format!("{}{}", name, suffix);
+        suffix += 1;
+    }
+    unique_name
+}
+
+fn property_name(name: &str) -> String {
+    if is_identifier(name) {
+        name.to_string()
+    } else {
+        string_literal(name)
+    }
+}
+
+pub(crate) fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+pub(crate) fn string_literal(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('\'');
+    for ch in value.chars() {
+        match ch {
+            '\'' => literal.push_str("\\'"),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            ch => literal.push(ch),
+        }
+    }
+    literal.push('\'');
+    literal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
+
+    #[test]
+    fn generate() {
+        let catalog: HashMap<&str, &str> = vec![
+            (
+                "app.inbox",
+                "{count, plural, one {# <b>new</b> message} other {# <b>new</b> messages}}",
+            ),
+            ("greeting", "Hello {name}, it is {now, time, short}"),
+            ("pronoun", "{gender, select, female {She} male {He} other {They}} {0}"),
+            ("status", "{status, select, on {On} off {Off}}"),
+            ("app-inbox", "{n, number, ::percent}"),
+            ("empty", "No arguments"),
+            ("other", "{x, select, other {{x}}}"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            generate_type_declarations(catalog, &TypeScriptOptions::default()),
+            Ok(indoc! {"
+                // Generated from the message catalog. Do not edit.
+                import type { ReactNode } from 'react';
+
+                export interface AppInboxValues {
+                  n: number;
+                }
+
+                export interface AppInboxValues2 {
+                  count: number;
+                  b: (chunks: ReactNode[]) => ReactNode;
+                }
+
+                export interface EmptyValues {}
+
+                export interface GreetingValues {
+                  name: string;
+                  now: Date | number;
+                }
+
+                export interface OtherValues {
+                  x: string;
+                }
+
+                export interface PronounValues {
+                  gender: 'female' | 'male' | (string & {});
+                  '0': string;
+                }
+
+                export interface StatusValues {
+                  status: 'on' | 'off';
+                }
+
+                export interface MessageValues {
+                  'app-inbox': AppInboxValues;
+                  'app.inbox': AppInboxValues2;
+                  'empty': EmptyValues;
+                  'greeting': GreetingValues;
+                  'other': OtherValues;
+                  'pronoun': PronounValues;
+                  'status': StatusValues;
+                }
+            "}
+            .to_string())
+        );
+    }
+
+    #[test]
+    fn report_all_errors() {
+        let catalog = vec![("a", "{n, date} {n, plural, other {#}}"), ("b", "{oops"), ("c", "ok")];
+        let errors = generate_type_declarations(catalog, &TypeScriptOptions::default())
+            .unwrap_err()
+            .iter()
+            .map(|error| match error {
+                TypeScriptError::Parse { id, error } => format!("{}: {:?}", id, error.kind),
+                error => error.to_string(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                "message `a`: argument `n` is used as values of different kinds",
+                "b: ExpectArgumentClosingBrace",
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_tag_type() {
+        let options = TypeScriptOptions {
+            header: String::new(),
+            tag_type: "(chunks: string[]) => string".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            generate_type_declarations(vec![("x", "<i>it's</i>")], &options),
+            Ok(indoc! {"
+                // Generated from the message catalog. Do not edit.
+
+                export interface XValues {
+                  i: (chunks: string[]) => string;
+                }
+
+                export interface MessageValues {
+                  'x': XValues;
+                }
+            "}
+            .to_string())
+        );
+    }
+}
@@ -0,0 +1,532 @@
+//! Traversal of the AST.
+//!
+//! `Visitor` walks the AST by reference, `VisitorMut` walks it by mutable reference and `Fold`
+//! consumes it and builds a new one. Every method has a default implementation that recurses
+//! into the children of the node by calling the `walk_*` (or `fold_*`) function of the same
+//! name, so an implementation only overrides the methods of the nodes it cares about. An
+//! overriding method calls the walk function itself if it wants to keep recursing.
+//!
+//! ```
+//! use intl_messageformat_parser_rs::visit::Visitor;
+//! use intl_messageformat_parser_rs::{Parser, Span};
+//!
+//! use std::borrow::Cow;
+//!
+//! /// Collects the names of the plain `{arg}` arguments, borrowed from the message.
+//! struct Arguments<'s>(Vec<Cow<'s, str>>);
+//!
+//! impl<'s> Visitor<'s> for Arguments<'s> {
+//!     fn visit_argument(&mut self, value: &Cow<'s, str>, _span: &Span) {
+//!         self.0.push(value.clone());
+//!     }
+//! }
+//!
+//! let ast = Parser::new("{a} {n, plural, other {<b>{c}</b>}}", None).parse().unwrap();
+//! let mut arguments = Arguments(vec![]);
+//! arguments.visit_ast(&ast);
+//! assert_eq!(arguments.0, vec!["a", "c"]);
+//! ```
+
+use crate::ast::*;
+use std::borrow::Cow;
+
+/// Walks the AST by reference. Names and selectors are passed as they are in the AST, so that
+/// they can be kept without a copy while they borrow from the message.
+#[allow(clippy::ptr_arg)]
+pub trait Visitor<'s> {
+    fn visit_ast(&mut self, ast: &[AstElement<'s>]) {
+        walk_ast(self, ast)
+    }
+
+    /// Dispatches to the method of the variant.
+    fn visit_element(&mut self, element: &AstElement<'s>) {
+        walk_element(self, element)
+    }
+
+    fn visit_literal(&mut self, _value: &str, _span: &Span) {}
+
+    fn visit_argument(&mut self, _value: &Cow<'s, str>, _span: &Span) {}
+
+    fn visit_number(
+        &mut self,
+        _value: &Cow<'s, str>,
+        _style: &Option<NumberArgStyle<'s>>,
+        _span: &Span,
+    ) {
+    }
+
+    fn visit_date(
+        &mut self,
+        _value: &Cow<'s, str>,
+        _style: &Option<DateTimeArgStyle<'s>>,
+        _span: &Span,
+    ) {
+    }
+
+    fn visit_time(
+        &mut self,
+        _value: &Cow<'s, str>,
+        _style: &Option<DateTimeArgStyle<'s>>,
+        _span: &Span,
+    ) {
+    }
+
+    fn visit_select(
+        &mut self,
+        _value: &Cow<'s, str>,
+        options: &PluralOrSelectOptions<'s>,
+        _span: &Span,
+    ) {
+        walk_options(self, options)
+    }
+
+    fn visit_plural(
+        &mut self,
+        _value: &Cow<'s, str>,
+        _plural_type: &PluralType,
+        _offset: i64,
+        options: &PluralOrSelectOptions<'s>,
+        _span: &Span,
+    ) {
+        walk_options(self, options)
+    }
+
+    /// Visits a clause of a `select`, `plural` or `selectordinal` argument.
+    fn visit_option(&mut self, _selector: &Cow<'s, str>, option: &PluralOrSelectOption<'s>) {
+        walk_option(self, option)
+    }
+
+    fn visit_pound(&mut self, _span: &Span) {}
+
+    fn visit_tag(&mut self, _value: &Cow<'s, str>, children: &[AstElement<'s>], _span: &Span) {
+        walk_ast(self, children)
+    }
+
+    fn visit_invalid(&mut self, _span: &Span) {}
+}
+
+pub fn walk_ast<'s, V: Visitor<'s> + ?Sized>(visitor: &mut V, ast: &[AstElement<'s>]) {
+    for element in ast {
+        visitor.visit_element(element);
+    }
+}
+
+pub fn walk_element<'s, V: Visitor<'s> + ?Sized>(visitor: &mut V, element: &AstElement<'s>) {
+    match element {
+        AstElement::Literal { value, span } => visitor.visit_literal(value, span),
+        AstElement::Argument { value, span } => visitor.visit_argument(value, span),
+        AstElement::Number { value, span, style } => visitor.visit_number(value, style, span),
+        AstElement::Date { value, span, style } => visitor.visit_date(value, style, span),
+        AstElement::Time { value, span, style } => visitor.visit_time(value, style, span),
+        AstElement::Select { value, span, options } => visitor.visit_select(value, options, span),
+        AstElement::Plural { value, plural_type, span, offset, options } => {
+            visitor.visit_plural(value, plural_type, *offset, options, span)
+        }
+        AstElement::Pound(span) => visitor.visit_pound(span),
+        AstElement::Tag { value, span, children } => visitor.visit_tag(value, children, span),
+        AstElement::Invalid(span) => visitor.visit_invalid(span),
+    }
+}
+
+pub fn walk_options<'s, V: Visitor<'s> + ?Sized>(
+    visitor: &mut V,
+    options: &PluralOrSelectOptions<'s>,
+) {
+    for (selector, option) in &options.0 {
+        visitor.visit_option(selector, option);
+    }
+}
+
+pub fn walk_option<'s, V: Visitor<'s> + ?Sized>(
+    visitor: &mut V,
+    option: &PluralOrSelectOption<'s>,
+) {
+    visitor.visit_ast(&option.value)
+}
+
+/// Walks the AST by mutable reference, e.g. to rename arguments in place.
+pub trait VisitorMut<'s> {
+    /// Takes the `Vec` so that elements can be inserted and removed.
+    fn visit_ast_mut(&mut self, ast: &mut Ast<'s>) {
+        walk_ast_mut(self, ast)
+    }
+
+    /// Dispatches to the method of the variant. Override this to replace whole elements.
+    fn visit_element_mut(&mut self, element: &mut AstElement<'s>) {
+        walk_element_mut(self, element)
+    }
+
+    fn visit_literal_mut(&mut self, _value: &mut String, _span: &mut Span) {}
+
+    fn visit_argument_mut(&mut self, _value: &mut Cow<'s, str>, _span: &mut Span) {}
+
+    fn visit_number_mut(
+        &mut self,
+        _value: &mut Cow<'s, str>,
+        _style: &mut Option<NumberArgStyle<'s>>,
+        _span: &mut Span,
+    ) {
+    }
+
+    fn visit_date_mut(
+        &mut self,
+        _value: &mut Cow<'s, str>,
+        _style: &mut Option<DateTimeArgStyle<'s>>,
+        _span: &mut Span,
+    ) {
+    }
+
+    fn visit_time_mut(
+        &mut self,
+        _value: &mut Cow<'s, str>,
+        _style: &mut Option<DateTimeArgStyle<'s>>,
+        _span: &mut Span,
+    ) {
+    }
+
+    fn visit_select_mut(
+        &mut self,
+        _value: &mut Cow<'s, str>,
+        options: &mut PluralOrSelectOptions<'s>,
+        _span: &mut Span,
+    ) {
+        walk_options_mut(self, options)
+    }
+
+    fn visit_plural_mut(
+        &mut self,
+        _value: &mut Cow<'s, str>,
+        _plural_type: &mut PluralType,
+        _offset: &mut i64,
+        options: &mut PluralOrSelectOptions<'s>,
+        _span: &mut Span,
+    ) {
+        walk_options_mut(self, options)
+    }
+
+    /// Visits a clause of a `select`, `plural` or `selectordinal` argument.
+    fn visit_option_mut(
+        &mut self,
+        _selector: &mut Cow<'s, str>,
+        option: &mut PluralOrSelectOption<'s>,
+    ) {
+        walk_option_mut(self, option)
+    }
+
+    fn visit_pound_mut(&mut self, _span: &mut Span) {}
+
+    fn visit_tag_mut(
+        &mut self,
+        _value: &mut Cow<'s, str>,
+        children: &mut Ast<'s>,
+        _span: &mut Span,
+    ) {
+        walk_ast_mut(self, children)
+    }
+
+    fn visit_invalid_mut(&mut self, _span: &mut Span) {}
+}
+
+pub fn walk_ast_mut<'s, V: VisitorMut<'s> + ?Sized>(visitor: &mut V, ast: &mut Ast<'s>) {
+    for element in ast {
+        visitor.visit_element_mut(element);
+    }
+}
+
+pub fn walk_element_mut<'s, V: VisitorMut<'s> + ?Sized>(
+    visitor: &mut V,
+    element: &mut AstElement<'s>,
+) {
+    match element {
+        AstElement::Literal { value, span } => visitor.visit_literal_mut(value, span),
+        AstElement::Argument { value, span } => visitor.visit_argument_mut(value, span),
+        AstElement::Number { value, span, style } => visitor.visit_number_mut(value, style, span),
+        AstElement::Date { value, span, style } => visitor.visit_date_mut(value, style, span),
+        AstElement::Time { value, span, style } => visitor.visit_time_mut(value, style, span),
+        AstElement::Select { value, span, options } => {
+            visitor.visit_select_mut(value, options, span)
+        }
+        AstElement::Plural { value, plural_type, span, offset, options } => {
+            visitor.visit_plural_mut(value, plural_type, offset, options, span)
+        }
+        AstElement::Pound(span) => visitor.visit_pound_mut(span),
+        AstElement::Tag { value, span, children } => visitor.visit_tag_mut(value, children, span),
+        AstElement::Invalid(span) => visitor.visit_invalid_mut(span),
+    }
+}
+
+pub fn walk_options_mut<'s, V: VisitorMut<'s> + ?Sized>(
+    visitor: &mut V,
+    options: &mut PluralOrSelectOptions<'s>,
+) {
+    for (selector, option) in &mut options.0 {
+        visitor.visit_option_mut(selector, option);
+    }
+}
+
+pub fn walk_option_mut<'s, V: VisitorMut<'s> + ?Sized>(
+    visitor: &mut V,
+    option: &mut PluralOrSelectOption<'s>,
+) {
+    visitor.visit_ast_mut(&mut option.value)
+}
+
+/// Consumes the AST and builds a new one, e.g. to desugar or strip parts of a message.
+pub trait Fold<'s> {
+    fn fold_ast(&mut self, ast: Ast<'s>) -> Ast<'s> {
+        fold_ast(self, ast)
+    }
+
+    /// Folds the children and the spans of the element.
+    fn fold_element(&mut self, element: AstElement<'s>) -> AstElement<'s> {
+        fold_element(self, element)
+    }
+
+    /// Folds a clause of a `select`, `plural` or `selectordinal` argument.
+    fn fold_option(
+        &mut self,
+        selector: Cow<'s, str>,
+        option: PluralOrSelectOption<'s>,
+    ) -> (Cow<'s, str>, PluralOrSelectOption<'s>) {
+        fold_option(self, selector, option)
+    }
+
+    /// Folds every span in the AST, including the spans of skeletons, clauses and selectors.
+    fn fold_span(&mut self, span: Span) -> Span {
+        span
+    }
+}
+
+pub fn fold_ast<'s, F: Fold<'s> + ?Sized>(folder: &mut F, ast: Ast<'s>) -> Ast<'s> {
+    ast.into_iter().map(|element| folder.fold_element(element)).collect()
+}
+
+pub fn fold_element<'s, F: Fold<'s> + ?Sized>(
+    folder: &mut F,
+    element: AstElement<'s>,
+) -> AstElement<'s> {
+    match element {
+        AstElement::Literal { value, span } => {
+            AstElement::Literal { value, span: folder.fold_span(span) }
+        }
+        AstElement::Argument { value, span } => {
+            AstElement::Argument { value, span: folder.fold_span(span) }
+        }
+        AstElement::Number { value, span, style } => AstElement::Number {
+            value,
+            span: folder.fold_span(span),
+            style: style.map(|style| match style {
+                NumberArgStyle::Skeleton(skeleton) => NumberArgStyle::Skeleton(NumberSkeleton {
+                    span: folder.fold_span(skeleton.span),
+                    ..skeleton
+                }),
+                style => style,
+            }),
+        },
+        AstElement::Date { value, span, style } => AstElement::Date {
+            value,
+            span: folder.fold_span(span),
+            style: fold_date_time_style(folder, style),
+        },
+        AstElement::Time { value, span, style } => AstElement::Time {
+            value,
+            span: folder.fold_span(span),
+            style: fold_date_time_style(folder, style),
+        },
+        AstElement::Select { value, span, options } => AstElement::Select {
+            value,
+            span: folder.fold_span(span),
+            options: fold_options(folder, options),
+        },
+        AstElement::Plural { value, plural_type, span, offset, options } => AstElement::Plural {
+            value,
+            plural_type,
+            span: folder.fold_span(span),
+            offset,
+            options: fold_options(folder, options),
+        },
+        AstElement::Pound(span) => AstElement::Pound(folder.fold_span(span)),
+        AstElement::Tag { value, span, children } => AstElement::Tag {
+            value,
+            span: folder.fold_span(span),
+            children: Box::new(folder.fold_ast(*children)),
+        },
+        AstElement::Invalid(span) => AstElement::Invalid(folder.fold_span(span)),
+    }
+}
+
+pub fn fold_option<'s, F: Fold<'s> + ?Sized>(
+    folder: &mut F,
+    selector: Cow<'s, str>,
+    option: PluralOrSelectOption<'s>,
+) -> (Cow<'s, str>, PluralOrSelectOption<'s>) {
+    let value = folder.fold_ast(option.value);
+    let span = folder.fold_span(option.span);
+    let selector_span = folder.fold_span(option.selector_span);
+    (selector, PluralOrSelectOption { value, span, selector_span })
+}
+
+fn fold_options<'s, F: Fold<'s> + ?Sized>(
+    folder: &mut F,
+    options: PluralOrSelectOptions<'s>,
+) -> PluralOrSelectOptions<'s> {
+    PluralOrSelectOptions(
+        options
+            .0
+            .into_iter()
+            .map(|(selector, option)| folder.fold_option(selector, option))
+            .collect(),
+    )
+}
+
+fn fold_date_time_style<'s, F: Fold<'s> + ?Sized>(
+    folder: &mut F,
+    style: Option<DateTimeArgStyle<'s>>,
+) -> Option<DateTimeArgStyle<'s>> {
+    style.map(|style| match style {
+        DateTimeArgStyle::Skeleton(skeleton) => DateTimeArgStyle::Skeleton(DateTimeSkeleton {
+            span: folder.fold_span(skeleton.span),
+            ..skeleton
+        }),
+        style => style,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{Parser, ParserOptions};
+    use crate::printer::print_ast;
+    use pretty_assertions::assert_eq;
+
+    const MESSAGE: &str =
+        "Hi {name}, {n, plural, offset:1 =0 {<b>no</b> one} other {# and {who}}} \
+                           {g, select, x {{d, date, ::yMd}} other {{n, number}}}";
+
+    #[test]
+    fn visit_with_spans() {
+        #[derive(Default)]
+        struct Collect(Vec<String>);
+
+        impl<'s> Visitor<'s> for Collect {
+            fn visit_argument(&mut self, value: &Cow<'s, str>, span: &Span) {
+                self.0.push(format!("{}@{}", value, span.start.offset));
+            }
+
+            fn visit_plural(
+                &mut self,
+                value: &Cow<'s, str>,
+                _plural_type: &PluralType,
+                offset: i64,
+                options: &PluralOrSelectOptions<'s>,
+                _span: &Span,
+            ) {
+                self.0.push(format!("{} offset:{}", value, offset));
+                walk_options(self, options);
+            }
+
+            fn visit_option(&mut self, selector: &Cow<'s, str>, option: &PluralOrSelectOption<'s>) {
+                self.0.push(selector.to_string());
+                walk_option(self, option);
+            }
+
+            fn visit_tag(
+                &mut self,
+                value: &Cow<'s, str>,
+                _children: &[AstElement<'s>],
+                _span: &Span,
+            ) {
+                // Does not walk the children.
+                self.0.push(format!("<{}>", value));
+            }
+
+            fn visit_pound(&mut self, span: &Span) {
+                self.0.push(format!("#@{}", span.start.offset));
+            }
+        }
+
+        let ast = Parser::new(MESSAGE, None).parse().unwrap();
+        let mut collect = Collect::default();
+        collect.visit_ast(&ast);
+        assert_eq!(
+            collect.0,
+            vec!["name@3", "n offset:1", "=0", "<b>", "other", "#@58", "who@64", "x", "other"]
+        );
+    }
+
+    #[test]
+    fn visit_mut_renames_arguments() {
+        struct Rename;
+
+        impl<'s> VisitorMut<'s> for Rename {
+            fn visit_argument_mut(&mut self, value: &mut Cow<'s, str>, _span: &mut Span) {
+                *value = format!("user_{}", value).into();
+            }
+
+            fn visit_plural_mut(
+                &mut self,
+                value: &mut Cow<'s, str>,
+                _plural_type: &mut PluralType,
+                offset: &mut i64,
+                options: &mut PluralOrSelectOptions<'s>,
+                _span: &mut Span,
+            ) {
+                *value = "count".into();
+                *offset = 0;
+                walk_options_mut(self, options);
+            }
+
+            fn visit_ast_mut(&mut self, ast: &mut Ast<'s>) {
+                ast.retain(|element| !matches!(element, AstElement::Tag { .. }));
+                walk_ast_mut(self, ast);
+            }
+        }
+
+        let mut ast = Parser::new(MESSAGE, None).parse().unwrap();
+        Rename.visit_ast_mut(&mut ast);
+        assert_eq!(
+            print_ast(&ast),
+            "Hi {user_name}, {count, plural, =0 { one} other {# and {user_who}}} \
+             {g, select, x {{d, date, ::yMd}} other {{n, number}}}"
+        );
+    }
+
+    #[test]
+    fn fold_strips_spans() {
+        struct StripSpans;
+
+        impl<'s> Fold<'s> for StripSpans {
+            fn fold_span(&mut self, _span: Span) -> Span {
+                Span::default()
+            }
+        }
+
+        let ast = Parser::new(MESSAGE, None).parse().unwrap();
+        let options = ParserOptions { should_omit_location: true, ..Default::default() };
+        assert_eq!(StripSpans.fold_ast(ast), Parser::new(MESSAGE, Some(&options)).parse().unwrap());
+    }
+
+    #[test]
+    fn fold_replaces_elements() {
+        /// Replaces the date arguments with their names as literal text.
+        struct DateToLiteral;
+
+        impl<'s> Fold<'s> for DateToLiteral {
+            fn fold_element(&mut self, element: AstElement<'s>) -> AstElement<'s> {
+                match element {
+                    AstElement::Date { value, span, .. } => {
+                        AstElement::Literal { value: value.into_owned(), span }
+                    }
+                    element => fold_element(self, element),
+                }
+            }
+        }
+
+        let ast = DateToLiteral.fold_ast(Parser::new(MESSAGE, None).parse().unwrap());
+        assert_eq!(
+            print_ast(&ast),
+            "Hi {name}, {n, plural, offset:1 =0 {<b>no</b> one} other {# and {who}}} \
+             {g, select, x {d} other {{n, number}}}"
+        );
+    }
+}
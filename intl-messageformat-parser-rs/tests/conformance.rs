@@ -0,0 +1,256 @@
+//! Conformance tests against the formatjs parser.
+//!
+//! Every JSON file in `tests/fixtures/formatjs` is a corpus of cases like:
+//!
+//! ```json
+//! {
+//!   "message": "Hello, {name}!",
+//!   "options": {"captureLocation": true},
+//!   "ast": [...]
+//! }
+//! ```
+//!
+//! where `options` are passed to `parse` from `@formatjs/icu-messageformat-parser`, and `ast` is
+//! what it returns for the message, i.e. the `MessageFormatElement` types in
+//! `intl-messageformat-parser-rs-napi/src/index.ts`. A message that fails to parse has an
+//! `error` with the formatjs `ErrorKind` and `location` instead of `ast`. Like formatjs,
+//! offsets are in UTF-16 code units and `location` is only captured when asked for.
+//!
+//! The `ast` and `error` of the cases are generated by `scripts/generate-formatjs-fixtures.mjs`.
+//!
+//! All cases are run, and every mismatch is reported with the JSON paths that differ.
+
+use intl_messageformat_parser_rs::visit::{walk_option_mut, walk_options_mut};
+use intl_messageformat_parser_rs::{
+    Ast, Error, ErrorKind, OffsetEncoding, Parser, ParserOptions, PluralOrSelectOption,
+    PluralOrSelectOptions, PluralType, Span, VisitorMut,
+};
+use serde::Deserialize;
+use serde_json::value::RawValue;
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::fs;
+use std::path::Path;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Case {
+    message: String,
+    #[serde(default)]
+    options: FormatjsOptions,
+    /// Kept as written, since `Value` sorts the keys of the select and plural options.
+    ast: Option<Box<RawValue>>,
+    error: Option<Value>,
+}
+
+/// The subset of the formatjs `ParserOptions` that this parser supports.
+#[derive(Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+struct FormatjsOptions {
+    ignore_tag: bool,
+    requires_other_clause: bool,
+    should_parse_skeletons: bool,
+    capture_location: bool,
+}
+
+/// The defaults of `parse`, which are not those of the formatjs `Parser`.
+impl Default for FormatjsOptions {
+    fn default() -> FormatjsOptions {
+        FormatjsOptions {
+            ignore_tag: false,
+            requires_other_clause: true,
+            should_parse_skeletons: true,
+            capture_location: false,
+        }
+    }
+}
+
+impl From<&FormatjsOptions> for ParserOptions {
+    fn from(options: &FormatjsOptions) -> ParserOptions {
+        ParserOptions {
+            should_ignore_tag: options.ignore_tag,
+            requires_other_clause: options.requires_other_clause,
+            should_omit_location: !options.capture_location,
+            offset_encoding: OffsetEncoding::Utf16,
+            ..Default::default()
+        }
+    }
+}
+
+/// All cases of the corpus, named after their file and index.
+fn corpus() -> Vec<(String, Case)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/formatjs");
+    let mut paths: Vec<_> = fs::read_dir(dir).unwrap().map(|entry| entry.unwrap().path()).collect();
+    paths.sort();
+
+    let mut corpus = vec![];
+    for path in paths {
+        let cases: Vec<Case> = serde_json::from_str(&fs::read_to_string(&path).unwrap())
+            .unwrap_or_else(|err| panic!("invalid corpus {}: {}", path.display(), err));
+        let file_name = path.file_name().unwrap().to_string_lossy();
+        for (index, case) in cases.into_iter().enumerate() {
+            assert!(
+                case.ast.is_some() != case.error.is_some(),
+                "{}[{}] must have either `ast` or `error`",
+                file_name,
+                index
+            );
+            assert!(
+                case.options.should_parse_skeletons,
+                "{}[{}]: skeletons are always parsed",
+                file_name, index
+            );
+            corpus.push((format!("{}[{}]", file_name, index), case));
+        }
+    }
+    corpus
+}
+
+/// The name of the error kind in formatjs, e.g. `EXPECT_ARGUMENT_CLOSING_BRACE`.
+fn formatjs_error_kind(kind: &ErrorKind) -> String {
+    let mut name = String::new();
+    for (index, ch) in format!("{:?}", kind).chars().enumerate() {
+        if ch.is_ascii_uppercase() && index > 0 {
+            name.push('_');
+        }
+        name.push(ch.to_ascii_uppercase());
+    }
+    name
+}
+
+/// The result of the case in the corpus format.
+fn run(case: &Case) -> Value {
+    match Parser::new(&case.message, Some(&(&case.options).into())).parse() {
+        Ok(ast) => json!({ "ast": ast }),
+        Err(Error { kind, span, .. }) => {
+            json!({ "error": { "kind": formatjs_error_kind(&kind), "location": span } })
+        }
+    }
+}
+
+/// Collect the JSON paths where the actual value differs from the expected one.
+fn diff(path: &str, expected: &Value, actual: &Value, diffs: &mut Vec<String>) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected) in expected {
+                let path = format!("{}.{}", path, key);
+                match actual.get(key) {
+                    Some(actual) => diff(&path, expected, actual, diffs),
+                    None => diffs.push(format!("{}: missing, expected {}", path, expected)),
+                }
+            }
+            for (key, actual) in actual {
+                if !expected.contains_key(key) {
+                    diffs.push(format!("{}.{}: unexpected {}", path, key, actual));
+                }
+            }
+        }
+        (Value::Array(expected_items), Value::Array(actual_items))
+            if expected_items.len() == actual_items.len() =>
+        {
+            for (index, (expected, actual)) in expected_items.iter().zip(actual_items).enumerate() {
+                diff(&format!("{}[{}]", path, index), expected, actual, diffs);
+            }
+        }
+        _ if expected != actual => {
+            diffs.push(format!("{}: expected {}, got {}", path, expected, actual))
+        }
+        _ => (),
+    }
+}
+
+#[test]
+fn formatjs_conformance() {
+    let corpus = corpus();
+    let mut failures = vec![];
+    for (name, case) in &corpus {
+        let expected = match (&case.ast, &case.error) {
+            (Some(ast), _) => json!({ "ast": ast }),
+            (_, error) => json!({ "error": error }),
+        };
+        let mut diffs = vec![];
+        diff("", &expected, &run(case), &mut diffs);
+        if !diffs.is_empty() {
+            failures.push(format!("{} {:?}\n    {}", name, case.message, diffs.join("\n    ")));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} of {} cases differ from formatjs:\n\n{}\n",
+        failures.len(),
+        corpus.len(),
+        failures.join("\n\n")
+    );
+}
+
+/// Orders the options of select and plural arguments like the keys of a JS object, which lists
+/// array indices (e.g. `"1"`) first and in ascending order.
+struct JsObjectOrder;
+
+impl JsObjectOrder {
+    fn sort(options: &mut PluralOrSelectOptions) {
+        options.0.sort_by_key(|(selector, _)| match selector.parse::<u32>() {
+            Ok(index) if index != u32::MAX && index.to_string() == *selector => (0, index),
+            _ => (1, 0),
+        });
+    }
+}
+
+impl<'s> VisitorMut<'s> for JsObjectOrder {
+    fn visit_select_mut(
+        &mut self,
+        _value: &mut Cow<'s, str>,
+        options: &mut PluralOrSelectOptions<'s>,
+        _span: &mut Span,
+    ) {
+        JsObjectOrder::sort(options);
+        walk_options_mut(self, options)
+    }
+
+    fn visit_plural_mut(
+        &mut self,
+        _value: &mut Cow<'s, str>,
+        _plural_type: &mut PluralType,
+        _offset: &mut i64,
+        options: &mut PluralOrSelectOptions<'s>,
+        _span: &mut Span,
+    ) {
+        JsObjectOrder::sort(options);
+        walk_options_mut(self, options)
+    }
+}
+
+/// Clears the spans of the selectors, which the formatjs AST does not have.
+struct StripSelectorSpans;
+
+impl<'s> VisitorMut<'s> for StripSelectorSpans {
+    fn visit_option_mut(
+        &mut self,
+        _selector: &mut Cow<'s, str>,
+        option: &mut PluralOrSelectOption<'s>,
+    ) {
+        option.selector_span = Span::default();
+        walk_option_mut(self, option)
+    }
+}
+
+/// The formatjs output deserializes to the AST that this parser returns for the message, and
+/// serializes back to the same JSON.
+#[test]
+fn deserialize_formatjs_output() {
+    for (name, case) in corpus() {
+        if let Some(json) = &case.ast {
+            let ast: Ast = serde_json::from_str(json.get())
+                .unwrap_or_else(|err| panic!("{}: failed to deserialize: {}", name, err));
+            let expected: Value = serde_json::from_str(json.get()).unwrap();
+            assert_eq!(serde_json::to_value(&ast).unwrap(), expected, "{}", name);
+            let options = (&case.options).into();
+            let mut parsed = Parser::new(&case.message, Some(&options))
+                .parse()
+                .unwrap_or_else(|err| panic!("{}: failed to parse: {}", name, err));
+            JsObjectOrder.visit_ast_mut(&mut parsed);
+            StripSelectorSpans.visit_ast_mut(&mut parsed);
+            assert_eq!(ast, parsed, "{}", name);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 mod utils;
 
-use intl_messageformat_parser_rs::Parser;
+use intl_messageformat_parser_rs::{OffsetEncoding, Parser, ParserOptions};
 use wasm_bindgen::prelude::*;
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
@@ -21,7 +21,9 @@ static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
 #[wasm_bindgen]
 pub fn parse(message: String) -> Result<JsValue, JsValue> {
-    let mut parser = Parser::new(message.as_str(), None);
+    // JavaScript strings are indexed by UTF-16 code units.
+    let options = ParserOptions { offset_encoding: OffsetEncoding::Utf16, ..Default::default() };
+    let mut parser = Parser::new(message.as_str(), Some(&options));
     let parse_result = parser.parse().map_err(|_| JsValue::from_str("Invalid message"))?;
     Ok(JsValue::from_serde(&parse_result).unwrap())
 }
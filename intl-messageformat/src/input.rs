@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// Where the messages to process come from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Input {
+    /// A single message.
+    Message { name: String, message: String },
+    /// A JSON object of message ids to messages, sorted by id.
+    Catalog { name: String, messages: BTreeMap<String, String> },
+}
+
+impl Input {
+    /// Read the message passed on the command line, or the file at the path, or stdin if the
+    /// path is `-`. Files with the `.json` extension are read as catalogs, and so is stdin if
+    /// `is_catalog` is true.
+    pub fn read(
+        message: Option<&str>,
+        path: Option<&str>,
+        is_catalog: bool,
+    ) -> Result<Input, InputError> {
+        if let Some(message) = message {
+            return Ok(Input::Message {
+                name: "<message>".to_string(),
+                message: message.to_string(),
+            });
+        }
+
+        let (name, contents) = match path {
+            None | Some("-") => {
+                let mut contents = String::new();
+                io::stdin()
+                    .read_to_string(&mut contents)
+                    .map_err(|error| InputError::Io { name: "<stdin>".to_string(), error })?;
+                ("<stdin>".to_string(), contents)
+            }
+            Some(path) => {
+                let contents = fs::read_to_string(path)
+                    .map_err(|error| InputError::Io { name: path.to_string(), error })?;
+                (path.to_string(), contents)
+            }
+        };
+        let is_catalog = is_catalog
+            || path.is_some_and(|path| {
+                Path::new(path).extension().is_some_and(|extension| extension == "json")
+            });
+
+        if is_catalog {
+            let messages = serde_json::from_str(&contents)
+                .map_err(|error| InputError::Json { name: name.clone(), error })?;
+            Ok(Input::Catalog { name, messages })
+        } else {
+            // Editors end files with a line break that is not part of the message.
+            let message = contents.strip_suffix('\n').unwrap_or(&contents);
+            let message = message.strip_suffix('\r').unwrap_or(message);
+            Ok(Input::Message { name, message: message.to_string() })
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Input::Message { name, .. } | Input::Catalog { name, .. } => name,
+        }
+    }
+
+    /// The messages with their ids, which only messages of catalogs have.
+    pub fn messages(&self) -> Vec<(Option<&str>, &str)> {
+        match self {
+            Input::Message { message, .. } => vec![(None, message.as_str())],
+            Input::Catalog { messages, .. } => {
+                messages.iter().map(|(id, message)| (Some(id.as_str()), message.as_str())).collect()
+            }
+        }
+    }
+}
+
+/// Expand the directories among the paths into the `.json` files that they contain, sorted.
+pub fn expand_catalog_paths<'a>(
+    paths: impl IntoIterator<Item = &'a str>,
+) -> Result<Vec<String>, InputError> {
+    let mut expanded = vec![];
+    for path in paths {
+        if !Path::new(path).is_dir() {
+            expanded.push(path.to_string());
+            continue;
+        }
+        let entries =
+            fs::read_dir(path).map_err(|error| InputError::Io { name: path.to_string(), error })?;
+        let mut catalogs = vec![];
+        for entry in entries {
+            let entry = entry.map_err(|error| InputError::Io { name: path.to_string(), error })?;
+            let entry_path = entry.path();
+            if entry_path.extension().is_some_and(|extension| extension == "json") {
+                catalogs.push(entry_path.to_string_lossy().into_owned());
+            }
+        }
+        catalogs.sort();
+        expanded.extend(catalogs);
+    }
+    Ok(expanded)
+}
+
+/// The locale of the catalog at the path, e.g. `fr` for `locales/fr.json`.
+pub fn catalog_locale(path: &str) -> String {
+    Path::new(path)
+        .file_stem()
+        .map_or_else(|| path.to_string(), |stem| stem.to_string_lossy().into_owned())
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Io {
+        name: String,
+        error: io::Error,
+    },
+    /// The catalog is not a JSON object of message ids to messages.
+    Json {
+        name: String,
+        error: serde_json::Error,
+    },
+    /// None of the catalogs is of the source locale.
+    MissingSourceCatalog {
+        locale: String,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io { name, error } => write!(f, "cannot read {}: {}", name, error),
+            InputError::Json { name, error } => {
+                write!(f, "{} is not a JSON object of message ids to messages: {}", name, error)
+            }
+            InputError::MissingSourceCatalog { locale } => {
+                write!(f, "no catalog of the source locale `{}`", locale)
+            }
+        }
+    }
+}
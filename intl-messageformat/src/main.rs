@@ -0,0 +1,286 @@
+//! Command-line tool to parse, validate and print ICU messages, e.g. in pre-commit hooks:
+//!
+//! ```text
+//! intl-messageformat validate --requires-other locales/en.json
+//! intl-messageformat parse -m '{count, plural, one {# file} other {# files}}'
+//! intl-messageformat print messages/greeting.txt
+//! intl-messageformat check-catalogs --source en --format sarif locales/
+//! ```
+//!
+//! Exits with 1 if a message fails to parse or a catalog has issues, and with 2 if the input
+//! cannot be read.
+
+mod input;
+mod report;
+
+use crate::input::{Input, InputError};
+use crate::report::FileReport;
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use intl_messageformat_parser_rs::{
+    check_catalogs, print_ast, render_diagnostic, Ast, Catalog, DiagnosticOptions, Error,
+    OffsetEncoding, Parser, ParserOptions,
+};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::{self, IsTerminal};
+use std::process;
+
+fn app() -> App<'static, 'static> {
+    let message = Arg::with_name("message")
+        .short("m")
+        .long("message")
+        .value_name("MESSAGE")
+        .help("The message to process instead of a file");
+    let catalog = Arg::with_name("catalog")
+        .long("catalog")
+        .help("Read stdin as a JSON catalog of message ids to messages");
+    let file = Arg::with_name("file").value_name("FILE").conflicts_with("message").help(
+        "A file with a message, or a JSON catalog of message ids to messages if it has the \
+         `.json` extension. Reads stdin if omitted or `-`",
+    );
+
+    App::new("intl-messageformat")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("Parse, validate and print ICU messages")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .setting(AppSettings::VersionlessSubcommands)
+        .arg(
+            Arg::with_name("ignore-tag").long("ignore-tag").global(true).help("Parse tags as text"),
+        )
+        .arg(
+            Arg::with_name("requires-other")
+                .long("requires-other")
+                .global(true)
+                .help("Require an `other` clause in `select`, `plural` and `selectordinal`"),
+        )
+        .arg(
+            Arg::with_name("locale")
+                .long("locale")
+                .value_name("LOCALE")
+                .global(true)
+                .help("Check that plural selectors are plural categories of the locale"),
+        )
+        .subcommand(
+            SubCommand::with_name("parse")
+                .about("Print the AST in the JSON format of formatjs")
+                .arg(
+                    Arg::with_name("capture-location")
+                        .long("capture-location")
+                        .help("Include the location of every element"),
+                )
+                .args(&[message.clone(), catalog.clone(), file.clone()]),
+        )
+        .subcommand(
+            SubCommand::with_name("validate").about("Report every error in the messages").args(&[
+                message.clone(),
+                catalog.clone(),
+                file.clone().multiple(true),
+            ]),
+        )
+        .subcommand(
+            SubCommand::with_name("print")
+                .about("Print the messages in canonical form")
+                .args(&[message, catalog, file]),
+        )
+        .subcommand(
+            SubCommand::with_name("check-catalogs")
+                .about("Check a source catalog and its translations against it")
+                .arg(
+                    Arg::with_name("source")
+                        .long("source")
+                        .value_name("LOCALE")
+                        .default_value("en")
+                        .help("The locale of the source catalog"),
+                )
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .value_name("FORMAT")
+                        .possible_values(&["human", "json", "sarif"])
+                        .default_value("human")
+                        .help("The format of the report"),
+                )
+                .arg(
+                    Arg::with_name("catalogs")
+                        .value_name("PATH")
+                        .required(true)
+                        .multiple(true)
+                        .help(
+                            "JSON catalogs of message ids to messages, or directories of them, \
+                             named after their locale like `locales/fr.json`",
+                        ),
+                ),
+        )
+}
+
+fn main() {
+    let matches = app().get_matches();
+    let (command, matches) = matches.subcommand();
+    let matches = matches.expect("a subcommand is required");
+    let options = parser_options(command, matches);
+
+    let result = match command {
+        "check-catalogs" => check(matches, &options),
+        _ => read_inputs(matches).map(|inputs| match command {
+            "parse" => parse(&inputs[0], &options),
+            "validate" => validate(&inputs, &options),
+            "print" => print(&inputs[0], &options),
+            _ => unreachable!("unknown subcommand {}", command),
+        }),
+    };
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(2);
+        }
+    }
+}
+
+fn parser_options(command: &str, matches: &ArgMatches) -> ParserOptions {
+    ParserOptions {
+        should_ignore_tag: matches.is_present("ignore-tag"),
+        requires_other_clause: matches.is_present("requires-other"),
+        locale: matches.value_of("locale").map(|locale| locale.to_string()),
+        // Match the locations of formatjs, which are indexed by UTF-16 code units.
+        offset_encoding: OffsetEncoding::Utf16,
+        // Like formatjs, `parse` only prints locations on request. The other subcommands need
+        // them to point at the issues.
+        should_omit_location: command == "parse" && !matches.is_present("capture-location"),
+    }
+}
+
+fn read_inputs(matches: &ArgMatches) -> Result<Vec<Input>, InputError> {
+    let message = matches.value_of("message");
+    let is_catalog = matches.is_present("catalog");
+    match matches.values_of("file") {
+        Some(paths) if message.is_none() => {
+            paths.map(|path| Input::read(None, Some(path), is_catalog)).collect()
+        }
+        _ => Ok(vec![Input::read(message, None, is_catalog)?]),
+    }
+}
+
+/// Print the AST of the message, or an object of message ids to ASTs for a catalog.
+fn parse(input: &Input, options: &ParserOptions) -> bool {
+    match parse_messages(input, options) {
+        Some(asts) => {
+            println!("{}", to_json(input, asts));
+            true
+        }
+        None => false,
+    }
+}
+
+/// Report every error in every message of the inputs.
+fn validate(inputs: &[Input], options: &ParserOptions) -> bool {
+    let mut error_count = 0;
+    for input in inputs {
+        for (id, message) in input.messages() {
+            let (_, errors) = Parser::new(message, Some(options)).parse_with_recovery();
+            report_errors(input, id, &errors);
+            error_count += errors.len();
+        }
+    }
+    if error_count > 0 {
+        eprintln!("found {} error{}", error_count, if error_count == 1 { "" } else { "s" });
+    }
+    error_count == 0
+}
+
+/// Print the message in canonical form, or an object of message ids to printed messages for a
+/// catalog.
+fn print(input: &Input, options: &ParserOptions) -> bool {
+    match parse_messages(input, options) {
+        Some(asts) => {
+            let printed: Vec<_> = asts.into_iter().map(|(id, ast)| (id, print_ast(&ast))).collect();
+            match input {
+                Input::Message { .. } => println!("{}", printed[0].1),
+                Input::Catalog { .. } => println!("{}", to_json(input, printed)),
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+/// Check the source catalog and the translated catalogs, and print the report in the format.
+fn check(matches: &ArgMatches, options: &ParserOptions) -> Result<bool, InputError> {
+    let source_locale = matches.value_of("source").unwrap();
+    let paths = input::expand_catalog_paths(matches.values_of("catalogs").unwrap())?;
+    let mut source = None;
+    let mut translations = vec![];
+    for path in paths {
+        let messages = match Input::read(None, Some(&path), true)? {
+            Input::Catalog { messages, .. } => messages,
+            Input::Message { .. } => unreachable!("catalogs are read as catalogs"),
+        };
+        let catalog = Catalog { locale: input::catalog_locale(&path), messages };
+        if catalog.locale == source_locale && source.is_none() {
+            source = Some((path, catalog));
+        } else {
+            translations.push((path, catalog));
+        }
+    }
+    let (source_path, source) = source
+        .ok_or_else(|| InputError::MissingSourceCatalog { locale: source_locale.to_string() })?;
+
+    let catalogs: Vec<Catalog> = translations.iter().map(|(_, catalog)| catalog.clone()).collect();
+    let reports: Vec<FileReport> = check_catalogs(&source, &catalogs, options)
+        .into_iter()
+        .zip(std::iter::once(source_path).chain(translations.into_iter().map(|(path, _)| path)))
+        .map(|(report, path)| FileReport { path, report })
+        .collect();
+    match matches.value_of("format").unwrap() {
+        "json" => println!("{}", serde_json::to_string_pretty(&report::json(&reports)).unwrap()),
+        "sarif" => println!("{}", serde_json::to_string_pretty(&report::sarif(&reports)).unwrap()),
+        _ => print!("{}", report::human(&reports)),
+    }
+    Ok(report::issue_count(&reports) == 0)
+}
+
+/// Parse every message of the input, or report the errors if any message fails to parse.
+fn parse_messages<'s>(
+    input: &'s Input,
+    options: &ParserOptions,
+) -> Option<Vec<(Option<&'s str>, Ast<'s>)>> {
+    let mut asts = vec![];
+    let mut is_valid = true;
+    for (id, message) in input.messages() {
+        match Parser::new(message, Some(options)).parse() {
+            Ok(ast) => asts.push((id, ast)),
+            Err(error) => {
+                report_errors(input, id, &[error]);
+                is_valid = false;
+            }
+        }
+    }
+    if is_valid {
+        Some(asts)
+    } else {
+        None
+    }
+}
+
+/// Serialize the value of the message, or an object of message ids to values for a catalog.
+fn to_json<T: Serialize>(input: &Input, values: Vec<(Option<&str>, T)>) -> String {
+    let json = match input {
+        Input::Message { .. } => serde_json::to_string_pretty(&values[0].1),
+        Input::Catalog { .. } => serde_json::to_string_pretty(
+            &values.into_iter().map(|(id, value)| (id.unwrap(), value)).collect::<BTreeMap<_, _>>(),
+        ),
+    };
+    json.expect("the output should serialize to JSON")
+}
+
+fn report_errors(input: &Input, id: Option<&str>, errors: &[Error]) {
+    let options = DiagnosticOptions { color: io::stderr().is_terminal() };
+    for error in errors {
+        match id {
+            Some(id) => eprintln!("{}: {}", input.name(), id),
+            None => eprintln!("{}", input.name()),
+        }
+        eprintln!("{}", render_diagnostic(error, &options));
+    }
+}
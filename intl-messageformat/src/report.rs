@@ -0,0 +1,175 @@
+use intl_messageformat_parser_rs::{CatalogIssue, CatalogIssueKind, CatalogReport, Span};
+use serde_json::{json, Value};
+use std::fmt::Write;
+
+/// The report of a catalog with the path that it was read from.
+pub struct FileReport {
+    pub path: String,
+    pub report: CatalogReport,
+}
+
+/// The rules that issues are reported under, with their descriptions.
+const RULES: &[(&str, &str)] = &[
+    ("parse-error", "The message fails to parse."),
+    ("missing-message", "The message of the source catalog is not translated."),
+    ("extra-message", "The translated message is not in the source catalog."),
+    ("missing-argument", "An argument of the source message is not in the translation."),
+    ("extra-argument", "An argument of the translation is not in the source message."),
+    ("changed-argument-type", "An argument is formatted from a different kind of value."),
+    ("missing-tag", "A tag of the source message is not in the translation."),
+    ("extra-tag", "A tag of the translation is not in the source message."),
+    ("changed-tag-nesting", "A tag is nested differently than in the source message."),
+    ("missing-select-key", "A `select` clause of the source message is not translated."),
+    ("invalid-plural-category", "A plural selector is not a plural category of the locale."),
+];
+
+fn rule_id(kind: &CatalogIssueKind) -> &'static str {
+    use intl_messageformat_parser_rs::TranslationIssueKind::*;
+    match kind {
+        CatalogIssueKind::Parse(_) => "parse-error",
+        CatalogIssueKind::MissingMessage => "missing-message",
+        CatalogIssueKind::ExtraMessage => "extra-message",
+        CatalogIssueKind::Translation(issue) => match issue.kind {
+            MissingArgument(_) => "missing-argument",
+            ExtraArgument(_) => "extra-argument",
+            ChangedArgumentType { .. } => "changed-argument-type",
+            MissingTag(_) => "missing-tag",
+            ExtraTag(_) => "extra-tag",
+            ChangedTagNesting { .. } => "changed-tag-nesting",
+            MissingSelectKey { .. } => "missing-select-key",
+            InvalidPluralCategory { .. } => "invalid-plural-category",
+        },
+    }
+}
+
+/// The spans of the issue in the message of the reported catalog and in the source message.
+fn spans(issue: &CatalogIssue) -> (Option<Span>, Option<Span>) {
+    match &issue.kind {
+        CatalogIssueKind::Parse(error) => (Some(error.span), None),
+        CatalogIssueKind::MissingMessage | CatalogIssueKind::ExtraMessage => (None, None),
+        CatalogIssueKind::Translation(issue) => (issue.translation_span, issue.source_span),
+    }
+}
+
+pub fn issue_count(reports: &[FileReport]) -> usize {
+    reports.iter().map(|file| file.report.issues.len()).sum()
+}
+
+/// One line per issue, grouped by locale:
+///
+/// ```text
+/// fr (locales/fr.json)
+///   greeting: argument `name` is missing, at line 1, column 1 of the source
+/// ```
+pub fn human(reports: &[FileReport]) -> String {
+    let mut output = String::new();
+    for FileReport { path, report } in reports {
+        if report.issues.is_empty() {
+            continue;
+        }
+        writeln!(output, "{} ({})", report.locale, path).unwrap();
+        for issue in &report.issues {
+            write!(output, "  {}: {}", issue.id, issue.kind).unwrap();
+            match (&issue.kind, spans(issue)) {
+                // Parse errors already tell where they are.
+                (CatalogIssueKind::Parse(_), _) => {}
+                (_, (Some(span), _)) => {
+                    write!(output, ", at line {}, column {}", span.start.line, span.start.column)
+                        .unwrap()
+                }
+                (_, (None, Some(span))) => write!(
+                    output,
+                    ", at line {}, column {} of the source",
+                    span.start.line, span.start.column
+                )
+                .unwrap(),
+                (_, (None, None)) => {}
+            }
+            writeln!(output).unwrap();
+        }
+    }
+
+    let issue_count = issue_count(reports);
+    let locale_count = reports.iter().filter(|file| !file.report.issues.is_empty()).count();
+    let locales = if reports.len() == 1 { "locale" } else { "locales" };
+    if issue_count == 0 {
+        writeln!(output, "no issues in {} {}", reports.len(), locales).unwrap();
+    } else {
+        writeln!(
+            output,
+            "{} issue{} in {} of {} {}",
+            issue_count,
+            if issue_count == 1 { "" } else { "s" },
+            locale_count,
+            reports.len(),
+            locales
+        )
+        .unwrap();
+    }
+    output
+}
+
+/// An array of the reports of every locale.
+pub fn json(reports: &[FileReport]) -> Value {
+    let reports = reports.iter().map(|FileReport { path, report }| {
+        let issues = report.issues.iter().map(|issue| {
+            let (span, source_span) = spans(issue);
+            let mut value = json!({
+                "id": issue.id,
+                "rule": rule_id(&issue.kind),
+                "message": issue.kind.to_string(),
+            });
+            if let Some(span) = span {
+                value["location"] = json!(span);
+            }
+            if let Some(span) = source_span {
+                value["sourceLocation"] = json!(span);
+            }
+            value
+        });
+        json!({
+            "locale": report.locale,
+            "path": path,
+            "issues": issues.collect::<Vec<_>>(),
+        })
+    });
+    Value::Array(reports.collect())
+}
+
+/// A SARIF 2.1.0 log, e.g. for code scanning. Results point to the catalog file and name the
+/// message id as their logical location, since message spans are not positions in the file.
+pub fn sarif(reports: &[FileReport]) -> Value {
+    let rules = RULES.iter().map(|(id, description)| {
+        json!({
+            "id": id,
+            "shortDescription": { "text": description },
+        })
+    });
+    let results = reports.iter().flat_map(|FileReport { path, report }| {
+        report.issues.iter().map(move |issue| {
+            json!({
+                "ruleId": rule_id(&issue.kind),
+                "level": "error",
+                "message": { "text": format!("{}: {}", issue.id, issue.kind) },
+                "locations": [{
+                    "physicalLocation": { "artifactLocation": { "uri": path } },
+                    "logicalLocations": [{ "fullyQualifiedName": issue.id, "kind": "member" }],
+                }],
+            })
+        })
+    });
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "intl-messageformat",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules.collect::<Vec<_>>(),
+                },
+            },
+            "results": results.collect::<Vec<_>>(),
+        }],
+    })
+}
@@ -0,0 +1,269 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use serde_json::{json, Value};
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+struct Output {
+    code: i32,
+    stdout: String,
+    stderr: String,
+}
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut child = Command::new(env!("CARGO_BIN_EXE_intl-messageformat"))
+        .args(args)
+        .current_dir(fixtures)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    Output {
+        code: output.status.code().unwrap(),
+        stdout: String::from_utf8(output.stdout).unwrap(),
+        stderr: String::from_utf8(output.stderr).unwrap(),
+    }
+}
+
+#[test]
+fn parse_message() {
+    let output = run(&["parse", "greeting.txt"], "");
+    assert_eq!(output.code, 0);
+    assert_eq!(
+        output.stdout,
+        indoc! {r#"
+            [
+              {
+                "type": 0,
+                "value": "Hello "
+              },
+              {
+                "type": 1,
+                "value": "name"
+              },
+              {
+                "type": 0,
+                "value": "!"
+              }
+            ]
+        "#}
+    );
+}
+
+#[test]
+fn parse_with_location() {
+    let output = run(&["parse", "--capture-location", "-m", "{n}"], "");
+    assert_eq!(
+        output.stdout,
+        indoc! {r#"
+            [
+              {
+                "type": 1,
+                "value": "n",
+                "location": {
+                  "start": {
+                    "offset": 0,
+                    "line": 1,
+                    "column": 1
+                  },
+                  "end": {
+                    "offset": 3,
+                    "line": 1,
+                    "column": 4
+                  }
+                }
+              }
+            ]
+        "#}
+    );
+}
+
+#[test]
+fn parse_ignore_tag() {
+    let output = run(&["--ignore-tag", "parse", "-"], "<b>x</b>");
+    assert_eq!(
+        output.stdout,
+        indoc! {r#"
+            [
+              {
+                "type": 0,
+                "value": "<b>x</b>"
+              }
+            ]
+        "#}
+    );
+}
+
+#[test]
+fn validate() {
+    let output = run(&["validate", "greeting.txt", "catalog.json"], "");
+    assert_eq!((output.code, output.stderr.as_str()), (0, ""));
+
+    let output = run(&["validate", "--requires-other", "catalog.json", "invalid.json"], "");
+    assert_eq!(output.code, 1);
+    assert_eq!(
+        output.stderr,
+        indoc! {"
+            invalid.json: broken
+            error: missing `other` clause
+             --> 1:1
+              |
+            1 | {a, plural, one {#}} {b
+              | ^^^^^^^^^^^^^^^^^^^^
+              |
+              = help: add an `other {...}` clause
+
+            invalid.json: broken
+            error: expected a closing brace `}` for the argument
+             --> 1:22
+              |
+            1 | {a, plural, one {#}} {b
+              |                      ^^
+              |
+              = help: add `}` to close the argument
+
+            found 2 errors
+        "}
+    );
+}
+
+#[test]
+fn print_catalog() {
+    let output = run(&["print", "--catalog"], r#"{"b": "{n,number,::percent}", "a": "<i/>"}"#);
+    assert_eq!(output.code, 0);
+    assert_eq!(
+        output.stdout,
+        indoc! {r#"
+            {
+              "a": "<i/>",
+              "b": "{n, number, ::percent}"
+            }
+        "#}
+    );
+    assert_eq!(
+        run(&["print", "catalog.json"], "").stdout,
+        indoc! {r#"
+            {
+              "greeting": "Hello <b>{name}</b>!",
+              "inbox": "{count, plural, one {# message} other {# messages}}"
+            }
+        "#}
+    );
+}
+
+#[test]
+fn print_message() {
+    let output = run(&["print"], "{a,select,x{X}other{Y}}\n");
+    assert_eq!((output.code, output.stdout.as_str()), (0, "{a, select, x {X} other {Y}}\n"));
+}
+
+#[test]
+fn input_errors() {
+    let output = run(&["print", "missing.txt"], "");
+    assert_eq!(output.code, 2);
+    assert!(output.stderr.starts_with("error: cannot read missing.txt: "));
+
+    let output = run(&["parse", "--catalog"], "[1, 2]");
+    assert_eq!(output.code, 2);
+    assert!(output
+        .stderr
+        .starts_with("error: <stdin> is not a JSON object of message ids to messages: "));
+
+    let output = run(&["parse", "-m", "{oops"], "");
+    assert_eq!((output.code, output.stdout.as_str()), (1, ""));
+}
+
+#[test]
+fn check_catalogs() {
+    let output = run(&["check-catalogs", "locales"], "");
+    assert_eq!(output.code, 1);
+    assert_eq!(
+        output.stdout,
+        indoc! {"
+            ja (locales/ja.json)
+              files: `one` of argument `count` is not a plural category of the locale, at line 1, column 17
+              greeting: tag `<b>` is missing, at line 1, column 7 of the source
+              greeting: argument `name` is missing, at line 1, column 10 of the source
+              greeting: argument `nom` is not in the source, at line 1, column 7
+              subtitle: expected a closing brace `}` for the argument at line 1, column 1
+              subtitle: message is not in the source
+              title: message is missing
+            7 issues in 1 of 3 locales
+        "}
+    );
+
+    let output = run(&["check-catalogs", "locales/fr.json", "locales/en.json"], "");
+    assert_eq!((output.code, output.stdout.as_str()), (0, "no issues in 2 locales\n"));
+
+    let output = run(&["check-catalogs", "locales/en.json"], "");
+    assert_eq!((output.code, output.stdout.as_str()), (0, "no issues in 1 locale\n"));
+
+    let output = run(&["check-catalogs", "--source", "de", "locales"], "");
+    assert_eq!(
+        (output.code, output.stderr.as_str()),
+        (2, "error: no catalog of the source locale `de`\n")
+    );
+}
+
+#[test]
+fn check_catalogs_json() {
+    let output =
+        run(&["check-catalogs", "--format", "json", "locales/ja.json", "locales/en.json"], "");
+    let reports: Value = serde_json::from_str(&output.stdout).unwrap();
+    assert_eq!(reports[0]["locale"], "en");
+    assert_eq!(reports[0]["issues"], json!([]));
+    assert_eq!(reports[1]["path"], "locales/ja.json");
+    assert_eq!(
+        reports[1]["issues"][0],
+        json!({
+            "id": "files",
+            "rule": "invalid-plural-category",
+            "message": "`one` of argument `count` is not a plural category of the locale",
+            "location": {
+                "start": { "offset": 16, "line": 1, "column": 17 },
+                "end": { "offset": 19, "line": 1, "column": 20 },
+            },
+            "sourceLocation": {
+                "start": { "offset": 0, "line": 1, "column": 1 },
+                "end": { "offset": 45, "line": 1, "column": 46 },
+            },
+        })
+    );
+    assert_eq!(
+        reports[1]["issues"][6],
+        json!({ "id": "title", "rule": "missing-message", "message": "message is missing" })
+    );
+}
+
+#[test]
+fn check_catalogs_sarif() {
+    let output = run(&["check-catalogs", "--format", "sarif", "locales"], "");
+    let log: Value = serde_json::from_str(&output.stdout).unwrap();
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "intl-messageformat");
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 7);
+    assert_eq!(
+        results[4],
+        json!({
+            "ruleId": "parse-error",
+            "level": "error",
+            "message": {
+                "text": "subtitle: expected a closing brace `}` for the argument at line 1, column 1"
+            },
+            "locations": [{
+                "physicalLocation": { "artifactLocation": { "uri": "locales/ja.json" } },
+                "logicalLocations": [{ "fullyQualifiedName": "subtitle", "kind": "member" }],
+            }],
+        })
+    );
+    let rule_ids: Vec<&Value> =
+        run["tool"]["driver"]["rules"].as_array().unwrap().iter().map(|rule| &rule["id"]).collect();
+    assert!(results.iter().all(|result| rule_ids.contains(&&result["ruleId"])));
+}